@@ -83,6 +83,7 @@ fn main() -> Result<()> {
                 threshold: Threshold::ThresholdQuorum {
                     threshold: PercentageThreshold::Majority {},
                     quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+                    quorum_decay: None,
                 },
                 max_voting_period: cw_utils::Duration::Time(432000),
                 allow_revoting: false,
@@ -105,6 +106,7 @@ fn main() -> Result<()> {
                     },
                 },
                 close_proposal_on_execution_failure: false,
+                veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             label: "DAO DAO Proposal Module".to_string(),
@@ -128,7 +130,10 @@ fn main() -> Result<()> {
     orc.instantiate(
         "cw_admin_factory",
         "admin_factory_init",
-        &cw_admin_factory::msg::InstantiateMsg {},
+        &cw_admin_factory::msg::InstantiateMsg {
+            owner: None,
+            fee: None,
+        },
         &key,
         None,
         vec![],
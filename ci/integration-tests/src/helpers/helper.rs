@@ -67,11 +67,13 @@ pub fn create_dao(
                 threshold: Threshold::ThresholdQuorum {
                     threshold: PercentageThreshold::Majority {},
                     quorum: PercentageThreshold::Percent(Decimal::percent(35)),
+                    quorum_decay: None,
                 },
                 max_voting_period: Duration::Time(432000),
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                veto: None,
                 pre_propose_info: PreProposeInfo::ModuleMayPropose {
                     info: ModuleInstantiateInfo {
                         code_id: chain.orc.contract_map.code_id("dao_pre_propose_single")?,
@@ -118,15 +120,16 @@ pub fn create_dao(
         .unwrap();
 
     let ProposalCreationPolicy::Module { addr: pre_propose } = chain
-	.orc
-	.query(
-	    "dao_proposal_single",
-	    &dao_proposal_single::msg::QueryMsg::ProposalCreationPolicy {}
-	).unwrap()
-	.data()
-	.unwrap()
+        .orc
+        .query(
+            "dao_proposal_single",
+            &dao_proposal_single::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap()
+        .data()
+        .unwrap()
     else {
-	panic!("expected pre-propose module")
+        panic!("expected pre-propose module")
     };
     chain
         .orc
@@ -240,6 +243,7 @@ pub fn create_proposal(
                     title: "title".to_string(),
                     description: "desc".to_string(),
                     msgs,
+                    best_effort: false,
                 },
             },
             key,
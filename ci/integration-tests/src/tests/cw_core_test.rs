@@ -223,7 +223,9 @@ fn instantiate_with_no_admin(chain: &mut Chain) {
             description: "A DAO that makes DAO tooling".to_string(),
             image_url: None,
             automatically_add_cw20s: false,
-            automatically_add_cw721s: false
+            automatically_add_cw721s: false,
+            automatically_add_native_denoms: false,
+            pause_guardian: None,
         }
     );
 }
@@ -257,7 +259,9 @@ fn instantiate_with_admin(chain: &mut Chain) {
             description: "A DAO that makes DAO tooling".to_string(),
             image_url: None,
             automatically_add_cw20s: false,
-            automatically_add_cw721s: false
+            automatically_add_cw721s: false,
+            automatically_add_native_denoms: false,
+            pause_guardian: None,
         }
     );
 
@@ -373,6 +377,7 @@ fn instantiate_with_admin(chain: &mut Chain) {
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Majority {},
             quorum: PercentageThreshold::Percent(Decimal::percent(35)),
+            quorum_decay: None,
         }
     );
     assert_eq!(
@@ -1,38 +1,75 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_binary, to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order,
-    Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
+    from_binary, from_slice, to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut,
+    Empty, Env, MessageInfo, Order, Reply, Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_paginate_storage::{paginate_map, paginate_map_keys, paginate_map_values};
-use cw_storage_plus::Map;
-use cw_utils::{parse_reply_instantiate_data, Duration};
+use cw_storage_plus::{Bound, Map};
+use cw_utils::{parse_reply_instantiate_data, Duration, Expiration};
 use dao_interface::{
     msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg},
     query::{
         AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, SubDao,
+        GetItemResponse, GetTypedItemResponse, PauseInfoResponse, ProposalModuleCountResponse,
+        SubDao, SubDaoTreeNode,
     },
     state::{
-        Admin, Config, ModuleInstantiateCallback, ModuleInstantiateInfo, ProposalModule,
-        ProposalModuleStatus,
+        Admin, Config, Grant, ItemValueType, ModuleInstantiateCallback, ModuleInstantiateInfo,
+        ModulePermissions, ModuleSpending, PauseRecord, ProposalModule, ProposalModuleStatus,
+        SubDaoAction, TimelockConfig, TimelockedOperation,
     },
     voting,
 };
+use std::collections::BTreeMap;
 
 use crate::error::ContractError;
 use crate::state::{
-    ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG, CW20_LIST, CW721_LIST, ITEMS, NOMINATED_ADMIN,
-    PAUSED, PROPOSAL_MODULES, SUBDAO_LIST, TOTAL_PROPOSAL_MODULE_COUNT, VOTING_MODULE,
+    ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG, CW20_LIST, CW721_LIST, GRANTS, GRANT_COUNT,
+    GUARDIAN_PAUSE_COOLDOWN, ITEMS, ITEM_PERMISSIONS, ITEM_TYPES, NATIVE_LIST, NOMINATED_ADMIN,
+    PARENT_DAO, PAUSED, PAUSE_HISTORY, PROPOSAL_MODULES, PROPOSAL_MODULE_PERMISSIONS,
+    PROPOSAL_MODULE_SPENDING, STARGATE_ALLOWLIST, STARGATE_ALLOWLIST_ENFORCED, SUBDAO_LIST,
+    TIMELOCK, TIMELOCKED_OPERATIONS, TIMELOCKED_OPERATION_COUNT, TOTAL_PROPOSAL_MODULE_COUNT,
+    VOTING_MODULE,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-dao-core";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The contract name of v1 DAO DAO core contracts, which may be listed
+/// as SubDAOs.
+const V1_CONTRACT_NAME: &str = "crates.io:cw-core";
+
+/// The maximum number of levels walked when querying the DAO tree.
+const MAX_DAO_TREE_DEPTH: u32 = 8;
+
 const PROPOSAL_MODULE_REPLY_ID: u64 = 0;
 const VOTE_MODULE_INSTANTIATE_REPLY_ID: u64 = 1;
 const VOTE_MODULE_UPDATE_REPLY_ID: u64 = 2;
+const SUBDAO_PARENT_REMOVAL_REPLY_ID: u64 = 3;
+
+/// The maximum length of an item's key in bytes.
+const MAX_ITEM_KEY_LENGTH: usize = 256;
+/// The maximum length of an item's value in bytes.
+const MAX_ITEM_VALUE_LENGTH: usize = 8192;
+
+/// The types of messages on the core contract that may be delegated
+/// with a grant. Messages that affect governance may not be delegated,
+/// as they would allow the grantee to take over the DAO.
+const DELEGABLE_CORE_MSG_TYPES: &[&str] = &[
+    "set_item",
+    "set_typed_item",
+    "remove_item",
+    "update_cw20_list",
+    "update_cw721_list",
+    "update_native_list",
+];
+
+/// The longest the pause guardian may pause the DAO for, in blocks
+/// or seconds. Roughly a week.
+const MAX_GUARDIAN_PAUSE_HEIGHT: u64 = 100_800;
+const MAX_GUARDIAN_PAUSE_TIME: u64 = 604_800;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -50,6 +87,8 @@ pub fn instantiate(
         automatically_add_cw20s: msg.automatically_add_cw20s,
         automatically_add_cw721s: msg.automatically_add_cw721s,
         dao_uri: msg.dao_uri,
+        automatically_add_native_denoms: false,
+        pause_guardian: None,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -85,6 +124,7 @@ pub fn instantiate(
                 return Err(ContractError::DuplicateInitialItem { item: key });
             }
             seen.push(key.clone());
+            validate_item(deps.as_ref(), &key, &value, None)?;
             ITEMS.save(deps.storage, key, &value)?;
         }
     }
@@ -106,10 +146,32 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // No actions can be performed while the DAO is paused.
-    if let Some(expiration) = PAUSED.may_load(deps.storage)? {
-        if !expiration.is_expired(&env.block) {
-            return Err(ContractError::Paused {});
+    // No actions other than lifting the pause or removing the pause
+    // guardian can be performed while the DAO is paused.
+    let allowed_while_paused = match &msg {
+        ExecuteMsg::Unpause {} => true,
+        ExecuteMsg::UpdateConfig { config } => {
+            info.sender == env.contract.address && is_pause_guardian_removal(deps.as_ref(), config)
+        }
+        ExecuteMsg::ExecuteProposalHook { msgs } => {
+            !msgs.is_empty()
+                && msgs
+                    .iter()
+                    .all(|msg| is_pause_recovery_msg(deps.as_ref(), &env, msg))
+        }
+        _ => false,
+    };
+    if !allowed_while_paused {
+        if let Some(expiration) = PAUSED.may_load(deps.storage)? {
+            if !expiration.is_expired(&env.block) {
+                return Err(ContractError::Paused {});
+            }
+        }
+    }
+
+    if !info.funds.is_empty() && CONFIG.load(deps.storage)?.automatically_add_native_denoms {
+        for coin in info.funds.iter() {
+            NATIVE_LIST.save(deps.storage, coin.denom.clone(), &Empty {})?;
         }
     }
 
@@ -118,13 +180,44 @@ pub fn execute(
             execute_admin_msgs(deps.as_ref(), info.sender, msgs)
         }
         ExecuteMsg::ExecuteProposalHook { msgs } => {
-            execute_proposal_hook(deps.as_ref(), info.sender, msgs)
+            execute_proposal_hook(deps, env, info.sender, msgs)
         }
+        ExecuteMsg::CreateGrant {
+            grantee,
+            contract,
+            msg_type,
+            max_uses,
+            expiration,
+        } => execute_create_grant(
+            deps,
+            env,
+            info.sender,
+            grantee,
+            contract,
+            msg_type,
+            max_uses,
+            expiration,
+        ),
+        ExecuteMsg::RevokeGrant { grantee, id } => {
+            execute_revoke_grant(deps, env, info.sender, grantee, id)
+        }
+        ExecuteMsg::ExecuteDelegated { msgs } => execute_delegated(deps, env, info.sender, msgs),
         ExecuteMsg::Pause { duration } => execute_pause(deps, env, info.sender, duration),
+        ExecuteMsg::Unpause {} => execute_unpause(deps, env, info.sender),
         ExecuteMsg::Receive(_) => execute_receive_cw20(deps, info.sender),
         ExecuteMsg::ReceiveNft(_) => execute_receive_cw721(deps, info.sender),
         ExecuteMsg::RemoveItem { key } => execute_remove_item(deps, env, info.sender, key),
         ExecuteMsg::SetItem { key, value } => execute_set_item(deps, env, info.sender, key, value),
+        ExecuteMsg::SetTypedItem {
+            key,
+            value,
+            value_type,
+        } => execute_set_typed_item(deps, env, info.sender, key, value, value_type),
+        ExecuteMsg::UpdateItemPermissions {
+            module,
+            to_add,
+            to_remove,
+        } => execute_update_item_permissions(deps, env, info.sender, module, to_add, to_remove),
         ExecuteMsg::UpdateConfig { config } => {
             execute_update_config(deps, env, info.sender, config)
         }
@@ -134,6 +227,9 @@ pub fn execute(
         ExecuteMsg::UpdateCw721List { to_add, to_remove } => {
             execute_update_cw721_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::UpdateNativeList { to_add, to_remove } => {
+            execute_update_native_list(deps, env, info.sender, to_add, to_remove)
+        }
         ExecuteMsg::UpdateVotingModule { module } => {
             execute_update_voting_module(env, info.sender, module)
         }
@@ -150,6 +246,32 @@ pub fn execute(
         ExecuteMsg::UpdateSubDaos { to_add, to_remove } => {
             execute_update_sub_daos_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::UpdateParentDao { parent } => {
+            execute_update_parent_dao(deps, env, info.sender, parent)
+        }
+        ExecuteMsg::ExecuteSubDaoAction { address, action } => {
+            execute_sub_dao_action(deps.as_ref(), env, info.sender, address, action)
+        }
+        ExecuteMsg::UpdateTimelock { delay, guardian } => {
+            execute_update_timelock(deps, env, info.sender, delay, guardian)
+        }
+        ExecuteMsg::ExecuteTimelockedOperation { id } => {
+            execute_timelocked_operation(deps, env, info.sender, id)
+        }
+        ExecuteMsg::CancelTimelockedOperation { id } => {
+            execute_cancel_timelocked_operation(deps, env, info.sender, id)
+        }
+        ExecuteMsg::UpdateStargateAllowlist {
+            to_add,
+            to_remove,
+            enforce,
+        } => execute_update_stargate_allowlist(deps, env, info.sender, to_add, to_remove, enforce),
+        ExecuteMsg::UpdateProposalModulePermissions {
+            address,
+            permissions,
+        } => {
+            execute_update_proposal_module_permissions(deps, env, info.sender, address, permissions)
+        }
     }
 }
 
@@ -159,21 +281,112 @@ pub fn execute_pause(
     sender: Addr,
     pause_duration: Duration,
 ) -> Result<Response, ContractError> {
-    // Only the core contract may call this method.
-    if sender != env.contract.address {
+    // Only the core contract and the pause guardian may call this
+    // method.
+    let config = CONFIG.load(deps.storage)?;
+    if sender != env.contract.address && Some(&sender) != config.pause_guardian.as_ref() {
         return Err(ContractError::Unauthorized {});
     }
 
+    // Pauses by the guardian are limited in length, and the guardian
+    // must wait as long as it paused for before pausing again, so
+    // that the DAO always has time to remove a rogue guardian.
+    if sender != env.contract.address {
+        let max = match pause_duration {
+            Duration::Height(height) if height > MAX_GUARDIAN_PAUSE_HEIGHT => {
+                Some(Duration::Height(MAX_GUARDIAN_PAUSE_HEIGHT))
+            }
+            Duration::Time(time) if time > MAX_GUARDIAN_PAUSE_TIME => {
+                Some(Duration::Time(MAX_GUARDIAN_PAUSE_TIME))
+            }
+            _ => None,
+        };
+        if let Some(max) = max {
+            return Err(ContractError::GuardianPauseTooLong { max });
+        }
+
+        if let Some(until) = GUARDIAN_PAUSE_COOLDOWN.may_load(deps.storage)? {
+            if !until.is_expired(&env.block) {
+                return Err(ContractError::GuardianPauseCooldown { until });
+            }
+        }
+        let cooldown = match pause_duration {
+            Duration::Height(height) => Expiration::AtHeight(env.block.height + 2 * height),
+            Duration::Time(time) => Expiration::AtTime(env.block.time.plus_seconds(2 * time)),
+        };
+        GUARDIAN_PAUSE_COOLDOWN.save(deps.storage, &cooldown)?;
+    }
+
     let until = pause_duration.after(&env.block);
 
     PAUSED.save(deps.storage, &until)?;
 
+    let id = PAUSE_HISTORY
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(1, |id| id + 1);
+    PAUSE_HISTORY.save(
+        deps.storage,
+        id,
+        &PauseRecord {
+            id,
+            paused_by: sender.clone(),
+            paused_at: env.block.height,
+            until,
+            unpaused_by: None,
+        },
+    )?;
+
     Ok(Response::new()
         .add_attribute("action", "execute_pause")
         .add_attribute("sender", sender)
         .add_attribute("until", until.to_string()))
 }
 
+pub fn execute_unpause(deps: DepsMut, env: Env, sender: Addr) -> Result<Response, ContractError> {
+    let admin = ADMIN.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    if sender != admin
+        && sender != env.contract.address
+        && Some(&sender) != config.pause_guardian.as_ref()
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let is_guardian = sender != admin && sender != env.contract.address;
+
+    if let PauseInfoResponse::Unpaused {} = get_pause_info(deps.as_ref(), env)? {
+        return Err(ContractError::NotPaused {});
+    }
+
+    // The current pause is always the most recent record.
+    let current = PAUSE_HISTORY
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+
+    // The guardian may only lift its own pauses, so that it may not
+    // undo a pause started by the DAO.
+    if is_guardian
+        && current
+            .as_ref()
+            .map_or(true, |(_, record)| record.paused_by != sender)
+    {
+        return Err(ContractError::GuardianUnpause {});
+    }
+
+    PAUSED.remove(deps.storage);
+    if let Some((id, mut record)) = current {
+        record.unpaused_by = Some(sender.clone());
+        PAUSE_HISTORY.save(deps.storage, id, &record)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_unpause")
+        .add_attribute("sender", sender))
+}
+
 pub fn execute_admin_msgs(
     deps: Deps,
     sender: Addr,
@@ -186,13 +399,16 @@ pub fn execute_admin_msgs(
         return Err(ContractError::Unauthorized {});
     }
 
+    check_stargate_allowlist(deps, &msgs)?;
+
     Ok(Response::default()
         .add_attribute("action", "execute_admin_msgs")
         .add_messages(msgs))
 }
 
 pub fn execute_proposal_hook(
-    deps: Deps,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<Empty>>,
 ) -> Result<Response, ContractError> {
@@ -205,11 +421,492 @@ pub fn execute_proposal_hook(
         return Err(ContractError::ModuleDisabledCannotExecute { address: sender });
     }
 
+    check_stargate_allowlist(deps.as_ref(), &msgs)?;
+    let spent = check_module_permissions(deps.as_ref(), &sender, &msgs)?;
+
+    // If the DAO has a timelock the messages are queued and may be
+    // executed once the delay has passed. Cancellations of queued
+    // operations and recovery from a pause skip the queue, as
+    // otherwise they could never take effect in time.
+    let skips_timelock = !msgs.is_empty()
+        && msgs.iter().all(|msg| {
+            is_cancel_timelocked_operation(&env, msg)
+                || is_pause_recovery_msg(deps.as_ref(), &env, msg)
+        });
+    if let Some(timelock) = TIMELOCK.may_load(deps.storage)?.filter(|_| !skips_timelock) {
+        let id = TIMELOCKED_OPERATION_COUNT
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            + 1;
+        TIMELOCKED_OPERATION_COUNT.save(deps.storage, &id)?;
+
+        let executable_at = timelock.delay.after(&env.block);
+        TIMELOCKED_OPERATIONS.save(
+            deps.storage,
+            id,
+            &TimelockedOperation {
+                id,
+                proposal_module: sender,
+                msgs,
+                executable_at,
+            },
+        )?;
+
+        // Spending is charged when the operation is executed.
+        return Ok(Response::default()
+            .add_attribute("action", "execute_proposal_hook")
+            .add_attribute("timelocked_operation_id", id.to_string())
+            .add_attribute("executable_at", executable_at.to_string()));
+    }
+
+    charge_module_spending(deps, &env, &sender, spent)?;
+
     Ok(Response::default()
         .add_attribute("action", "execute_proposal_hook")
         .add_messages(msgs))
 }
 
+/// Returns true if `msg` is a `CancelTimelockedOperation` message
+/// sent to this contract.
+fn is_cancel_timelocked_operation(env: &Env, msg: &CosmosMsg<Empty>) -> bool {
+    match msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) if *contract_addr == env.contract.address && funds.is_empty() => matches!(
+            from_binary(msg),
+            Ok(ExecuteMsg::CancelTimelockedOperation { .. })
+        ),
+        _ => false,
+    }
+}
+
+/// Returns true if `msg` is sent to this contract and lifts the
+/// pause or removes the pause guardian. Proposal modules may execute
+/// these messages while the DAO is paused.
+fn is_pause_recovery_msg(deps: Deps, env: &Env, msg: &CosmosMsg<Empty>) -> bool {
+    match msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) if *contract_addr == env.contract.address && funds.is_empty() => {
+            match from_binary(msg) {
+                Ok(ExecuteMsg::Unpause {}) => true,
+                Ok(ExecuteMsg::UpdateConfig { config }) => is_pause_guardian_removal(deps, &config),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if `config` is the current config with the pause
+/// guardian removed.
+fn is_pause_guardian_removal(deps: Deps, config: &Config) -> bool {
+    config.pause_guardian.is_none()
+        && CONFIG.load(deps.storage).map_or(false, |current| {
+            *config
+                == Config {
+                    pause_guardian: None,
+                    ..current
+                }
+        })
+}
+
+/// Errors if any of `msgs` is a Stargate message whose type URL is
+/// not in the DAO's allowlist. If the allowlist is not enforced all
+/// Stargate messages are allowed.
+fn check_stargate_allowlist(deps: Deps, msgs: &[CosmosMsg<Empty>]) -> Result<(), ContractError> {
+    if !STARGATE_ALLOWLIST_ENFORCED
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+    {
+        return Ok(());
+    }
+    for msg in msgs {
+        if let CosmosMsg::Stargate { type_url, .. } = msg {
+            if !STARGATE_ALLOWLIST.has(deps.storage, type_url.clone()) {
+                return Err(ContractError::StargateMsgNotAllowed {
+                    type_url: type_url.clone(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Errors if any of `msgs` falls outside of the permissions of
+/// `module`, returning the tokens they spend. Modules without
+/// permissions may execute any message.
+///
+/// Native tokens are returned by denom and cw20 tokens by the address
+/// of their contract. Spend limits are not checked here, as they
+/// depend on when the messages are executed.
+fn check_module_permissions(
+    deps: Deps,
+    module: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+) -> Result<Vec<Coin>, ContractError> {
+    let permissions = match PROPOSAL_MODULE_PERMISSIONS.may_load(deps.storage, module.clone())? {
+        Some(permissions) => permissions,
+        None => return Ok(vec![]),
+    };
+
+    let mut spent = vec![];
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. }) => spent.extend(amount.iter().cloned()),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) => {
+                if !permissions.allowed_contracts.contains(contract_addr) {
+                    return Err(ContractError::ContractNotPermitted {
+                        module: module.clone(),
+                        contract: contract_addr.clone(),
+                    });
+                }
+                spent.extend(funds.iter().cloned());
+                if let Some(amount) = get_cw20_spend(msg) {
+                    spent.push(Coin {
+                        denom: contract_addr.clone(),
+                        amount,
+                    });
+                }
+            }
+            _ => {
+                return Err(ContractError::MessageNotPermitted {
+                    module: module.clone(),
+                })
+            }
+        };
+    }
+
+    // Tokens without a spend limit may not be spent at all.
+    for coin in spent.iter() {
+        if !permissions
+            .spend_limits
+            .iter()
+            .any(|limit| limit.denom == coin.denom)
+        {
+            return Err(ContractError::SpendLimitExceeded {
+                module: module.clone(),
+                denom: coin.denom.clone(),
+                limit: Uint128::zero(),
+            });
+        }
+    }
+
+    Ok(spent)
+}
+
+/// Gets the amount of tokens a cw20 execute message moves out of the
+/// DAO's balance, if it is a cw20 message that does so.
+fn get_cw20_spend(msg: &Binary) -> Option<Uint128> {
+    match from_binary(msg).ok()? {
+        cw20::Cw20ExecuteMsg::Transfer { amount, .. }
+        | cw20::Cw20ExecuteMsg::Send { amount, .. }
+        | cw20::Cw20ExecuteMsg::IncreaseAllowance { amount, .. }
+        | cw20::Cw20ExecuteMsg::Burn { amount } => Some(amount),
+        _ => None,
+    }
+}
+
+/// Records `spent` against the spend limits of `module`, erroring if
+/// this would exceed them.
+fn charge_module_spending(
+    deps: DepsMut,
+    env: &Env,
+    module: &Addr,
+    spent: Vec<Coin>,
+) -> Result<(), ContractError> {
+    let permissions = match PROPOSAL_MODULE_PERMISSIONS.may_load(deps.storage, module.clone())? {
+        Some(permissions) => permissions,
+        None => return Ok(()),
+    };
+
+    for coin in spent {
+        let limit = permissions
+            .spend_limits
+            .iter()
+            .find(|limit| limit.denom == coin.denom)
+            .ok_or_else(|| ContractError::SpendLimitExceeded {
+                module: module.clone(),
+                denom: coin.denom.clone(),
+                limit: Uint128::zero(),
+            })?;
+
+        // Start a new period if the previous one has ended.
+        let mut spending = PROPOSAL_MODULE_SPENDING
+            .may_load(deps.storage, (module, coin.denom.as_str()))?
+            .filter(|spending| !spending.resets_at.is_expired(&env.block))
+            .unwrap_or_else(|| ModuleSpending {
+                denom: coin.denom.clone(),
+                spent: Uint128::zero(),
+                resets_at: limit.period.after(&env.block),
+            });
+        spending.spent = spending
+            .spent
+            .checked_add(coin.amount)
+            .map_err(StdError::from)?;
+        if spending.spent > limit.amount {
+            return Err(ContractError::SpendLimitExceeded {
+                module: module.clone(),
+                denom: coin.denom,
+                limit: limit.amount,
+            });
+        }
+        PROPOSAL_MODULE_SPENDING.save(deps.storage, (module, coin.denom.as_str()), &spending)?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_create_grant(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    grantee: String,
+    contract: String,
+    msg_type: Option<String>,
+    max_uses: Option<u64>,
+    expiration: Expiration,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    if expiration.is_expired(&env.block) {
+        return Err(ContractError::GrantExpired {});
+    }
+    if max_uses == Some(0) {
+        return Err(ContractError::InvalidGrantUses {});
+    }
+
+    let grantee = deps.api.addr_validate(&grantee)?;
+    let contract = deps.api.addr_validate(&contract)?;
+    if contract == env.contract.address
+        && !msg_type.as_deref().map_or(false, |msg_type| {
+            DELEGABLE_CORE_MSG_TYPES.contains(&msg_type)
+        })
+    {
+        return Err(ContractError::UndelegableMsg {});
+    }
+
+    let id = GRANT_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    GRANT_COUNT.save(deps.storage, &id)?;
+    GRANTS.save(
+        deps.storage,
+        (&grantee, id),
+        &Grant {
+            id,
+            grantee: grantee.clone(),
+            contract,
+            msg_type,
+            remaining_uses: max_uses,
+            expiration,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_create_grant")
+        .add_attribute("grantee", grantee)
+        .add_attribute("id", id.to_string()))
+}
+
+pub fn execute_revoke_grant(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    grantee: String,
+    id: u64,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let grantee = deps.api.addr_validate(&grantee)?;
+    if !GRANTS.has(deps.storage, (&grantee, id)) {
+        return Err(ContractError::GrantNotFound { grantee, id });
+    }
+    GRANTS.remove(deps.storage, (&grantee, id));
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_revoke_grant")
+        .add_attribute("grantee", grantee)
+        .add_attribute("id", id.to_string()))
+}
+
+/// Gets the type of a contract execute message, its top level JSON
+/// key. Returns `None` if the message does not have exactly one top
+/// level key.
+fn get_msg_type(msg: &Binary) -> Option<String> {
+    let msg: BTreeMap<String, cosmwasm_schema::serde::de::IgnoredAny> = from_binary(msg).ok()?;
+    if msg.len() == 1 {
+        msg.into_keys().next()
+    } else {
+        None
+    }
+}
+
+pub fn execute_delegated(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> Result<Response, ContractError> {
+    for msg in msgs.iter() {
+        let (contract_addr, msg) = match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) if funds.is_empty() => (contract_addr, msg),
+            _ => return Err(ContractError::InvalidDelegatedMsg {}),
+        };
+        let msg_type = get_msg_type(msg);
+
+        let grant = GRANTS
+            .prefix(&sender)
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, grant)| grant))
+            .find(|grant| {
+                grant.as_ref().map_or(true, |grant| {
+                    !grant.expiration.is_expired(&env.block)
+                        && grant.remaining_uses != Some(0)
+                        && grant.contract == *contract_addr
+                        && grant
+                            .msg_type
+                            .as_ref()
+                            .map_or(true, |allowed| Some(allowed) == msg_type.as_ref())
+                })
+            })
+            .transpose()?
+            .ok_or_else(|| ContractError::NoMatchingGrant {
+                grantee: sender.clone(),
+                contract: contract_addr.clone(),
+            })?;
+
+        // Grants are removed once they have been used up.
+        match grant.remaining_uses {
+            Some(1) => GRANTS.remove(deps.storage, (&sender, grant.id)),
+            Some(uses) => GRANTS.save(
+                deps.storage,
+                (&sender, grant.id),
+                &Grant {
+                    remaining_uses: Some(uses - 1),
+                    ..grant
+                },
+            )?,
+            None => (),
+        }
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_delegated")
+        .add_attribute("sender", sender)
+        .add_messages(msgs))
+}
+
+pub fn execute_update_timelock(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    delay: Option<Duration>,
+    guardian: Option<String>,
+) -> Result<Response, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let guardian = guardian.map(|h| deps.api.addr_validate(&h)).transpose()?;
+
+    match delay {
+        Some(delay) => TIMELOCK.save(deps.storage, &TimelockConfig { delay, guardian })?,
+        // Operations that are already queued remain executable once
+        // their delay passes.
+        None => TIMELOCK.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_timelock")
+        .add_attribute(
+            "delay",
+            delay
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| "None".to_string()),
+        ))
+}
+
+pub fn execute_timelocked_operation(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let operation = TIMELOCKED_OPERATIONS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::TimelockedOperationNotFound { id })?;
+
+    if !operation.executable_at.is_expired(&env.block) {
+        return Err(ContractError::TimelockNotExpired {
+            id,
+            executable_at: operation.executable_at,
+        });
+    }
+
+    // A proposal module that has been disabled since queueing the
+    // operation may no longer execute messages.
+    let module = PROPOSAL_MODULES.load(deps.storage, operation.proposal_module.clone())?;
+    if module.status != ProposalModuleStatus::Enabled {
+        return Err(ContractError::ModuleDisabledCannotExecute {
+            address: operation.proposal_module,
+        });
+    }
+
+    // The allowlist and the module's permissions may have changed
+    // since the operation was queued.
+    check_stargate_allowlist(deps.as_ref(), &operation.msgs)?;
+    let spent =
+        check_module_permissions(deps.as_ref(), &operation.proposal_module, &operation.msgs)?;
+    charge_module_spending(deps.branch(), &env, &operation.proposal_module, spent)?;
+
+    TIMELOCKED_OPERATIONS.remove(deps.storage, id);
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_timelocked_operation")
+        .add_attribute("sender", sender)
+        .add_attribute("id", id.to_string())
+        .add_messages(operation.msgs))
+}
+
+pub fn execute_cancel_timelocked_operation(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let guardian = TIMELOCK
+        .may_load(deps.storage)?
+        .and_then(|timelock| timelock.guardian);
+    if sender != env.contract.address && Some(&sender) != guardian.as_ref() {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !TIMELOCKED_OPERATIONS.has(deps.storage, id) {
+        return Err(ContractError::TimelockedOperationNotFound { id });
+    }
+    TIMELOCKED_OPERATIONS.remove(deps.storage, id);
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_cancel_timelocked_operation")
+        .add_attribute("sender", sender)
+        .add_attribute("id", id.to_string()))
+}
+
 pub fn execute_nominate_admin(
     deps: DepsMut,
     env: Env,
@@ -295,6 +992,10 @@ pub fn execute_update_config(
         return Err(ContractError::Unauthorized {});
     }
 
+    if let Some(ref pause_guardian) = config.pause_guardian {
+        deps.api.addr_validate(pause_guardian.as_str())?;
+    }
+
     CONFIG.save(deps.storage, &config)?;
     // We incur some gas costs by having the config's fields in the
     // response. This has the benefit that it makes it reasonably
@@ -406,68 +1107,298 @@ fn do_update_addr_list(
         map.remove(deps.storage, addr);
     }
 
-    Ok(())
+    Ok(())
+}
+
+pub fn execute_update_cw20_list(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    do_update_addr_list(deps, CW20_LIST, to_add, to_remove, |addr, deps| {
+        // Perform a balance query here as this is the query performed
+        // by the `Cw20Balances` query.
+        let _info: cw20::BalanceResponse = deps.querier.query_wasm_smart(
+            addr,
+            &cw20::Cw20QueryMsg::Balance {
+                address: env.contract.address.to_string(),
+            },
+        )?;
+        Ok(())
+    })?;
+    Ok(Response::default().add_attribute("action", "update_cw20_list"))
+}
+
+pub fn execute_update_cw721_list(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    do_update_addr_list(deps, CW721_LIST, to_add, to_remove, |addr, deps| {
+        let _info: cw721::ContractInfoResponse = deps
+            .querier
+            .query_wasm_smart(addr, &cw721::Cw721QueryMsg::ContractInfo {})?;
+        Ok(())
+    })?;
+    Ok(Response::default().add_attribute("action", "update_cw721_list"))
+}
+
+pub fn execute_update_stargate_allowlist(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+    enforce: Option<bool>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for type_url in to_add {
+        STARGATE_ALLOWLIST.save(deps.storage, type_url, &Empty {})?;
+    }
+    for type_url in to_remove {
+        STARGATE_ALLOWLIST.remove(deps.storage, type_url);
+    }
+    if let Some(enforce) = enforce {
+        STARGATE_ALLOWLIST_ENFORCED.save(deps.storage, &enforce)?;
+    }
+
+    Ok(Response::default().add_attribute("action", "update_stargate_allowlist"))
+}
+
+pub fn execute_update_proposal_module_permissions(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    address: String,
+    permissions: Option<ModulePermissions>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let module = deps.api.addr_validate(&address)?;
+    if !PROPOSAL_MODULES.has(deps.storage, module.clone()) {
+        return Err(ContractError::ProposalModuleDoesNotExist { address: module });
+    }
+
+    // Spending is tracked from scratch whenever permissions change.
+    let denoms = PROPOSAL_MODULE_SPENDING
+        .prefix(&module)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for denom in denoms {
+        PROPOSAL_MODULE_SPENDING.remove(deps.storage, (&module, denom.as_str()));
+    }
+
+    match permissions {
+        Some(permissions) => {
+            let permissions = ModulePermissions {
+                allowed_contracts: permissions
+                    .allowed_contracts
+                    .into_iter()
+                    .map(|addr| Ok(deps.api.addr_validate(&addr)?.into_string()))
+                    .collect::<StdResult<_>>()?,
+                spend_limits: permissions.spend_limits,
+            };
+            PROPOSAL_MODULE_PERMISSIONS.save(deps.storage, module.clone(), &permissions)?
+        }
+        None => PROPOSAL_MODULE_PERMISSIONS.remove(deps.storage, module.clone()),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "update_proposal_module_permissions")
+        .add_attribute("module", module))
+}
+
+pub fn execute_update_native_list(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    for denom in to_add {
+        NATIVE_LIST.save(deps.storage, denom, &Empty {})?;
+    }
+    for denom in to_remove {
+        NATIVE_LIST.remove(deps.storage, denom);
+    }
+    Ok(Response::default().add_attribute("action", "update_native_list"))
+}
+
+/// Returns true if `key` matches the item permission
+/// `pattern`. Patterns ending in `/*` match every key in that
+/// namespace, other patterns only match themselves.
+fn item_key_matches(pattern: &str, key: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(namespace) if namespace.ends_with('/') => key.starts_with(namespace),
+        _ => pattern == key,
+    }
+}
+
+/// Errors if `sender` may not set or remove the item with `key`. The
+/// core contract may write any item, and enabled proposal modules
+/// may write items matching the patterns they have been given
+/// permission for.
+fn check_item_permission(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    key: &str,
+) -> Result<(), ContractError> {
+    if *sender == env.contract.address {
+        return Ok(());
+    }
+
+    let enabled = PROPOSAL_MODULES
+        .may_load(deps.storage, sender.clone())?
+        .map_or(false, |module| {
+            module.status == ProposalModuleStatus::Enabled
+        });
+    if enabled {
+        let patterns = ITEM_PERMISSIONS
+            .prefix(sender)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<String>>>()?;
+        if patterns
+            .iter()
+            .any(|pattern| item_key_matches(pattern, key))
+        {
+            return Ok(());
+        }
+    }
+
+    Err(ContractError::Unauthorized {})
+}
+
+/// Errors if a namespaced or typed item's key or value is too long,
+/// or if its value is not valid for `value_type`. Untyped items
+/// outside of a namespace are not limited in size, as they were not
+/// before namespaces and types were introduced.
+fn validate_item(
+    deps: Deps,
+    key: &str,
+    value: &str,
+    value_type: Option<&ItemValueType>,
+) -> Result<(), ContractError> {
+    if value_type.is_some() || key.contains('/') {
+        if key.len() > MAX_ITEM_KEY_LENGTH {
+            return Err(ContractError::ItemKeyTooLong {
+                max: MAX_ITEM_KEY_LENGTH,
+            });
+        }
+        if value.len() > MAX_ITEM_VALUE_LENGTH {
+            return Err(ContractError::ItemValueTooLong {
+                max: MAX_ITEM_VALUE_LENGTH,
+            });
+        }
+    }
+
+    let valid = match value_type {
+        None | Some(ItemValueType::String) => true,
+        Some(ItemValueType::Address) => deps.api.addr_validate(value).is_ok(),
+        Some(ItemValueType::Url) => {
+            ["http://", "https://", "ipfs://"]
+                .iter()
+                .any(|scheme| value.starts_with(scheme))
+                && !value.contains(char::is_whitespace)
+        }
+        Some(ItemValueType::Json) => {
+            from_slice::<cosmwasm_schema::serde::de::IgnoredAny>(value.as_bytes()).is_ok()
+        }
+    };
+    if !valid {
+        return Err(ContractError::InvalidItemValue {
+            key: key.to_string(),
+            value_type: value_type.cloned().unwrap_or(ItemValueType::String),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn execute_set_item(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    key: String,
+    value: String,
+) -> Result<Response, ContractError> {
+    check_item_permission(deps.as_ref(), &env, &sender, &key)?;
+
+    // Items set with `SetTypedItem` keep their type.
+    let value_type = ITEM_TYPES.may_load(deps.storage, key.clone())?;
+    validate_item(deps.as_ref(), &key, &value, value_type.as_ref())?;
+
+    ITEMS.save(deps.storage, key.clone(), &value)?;
+    Ok(Response::default()
+        .add_attribute("action", "execute_set_item")
+        .add_attribute("key", key)
+        .add_attribute("addr", value))
 }
 
-pub fn execute_update_cw20_list(
+pub fn execute_set_typed_item(
     deps: DepsMut,
     env: Env,
     sender: Addr,
-    to_add: Vec<String>,
-    to_remove: Vec<String>,
+    key: String,
+    value: String,
+    value_type: ItemValueType,
 ) -> Result<Response, ContractError> {
-    if env.contract.address != sender {
-        return Err(ContractError::Unauthorized {});
-    }
-    do_update_addr_list(deps, CW20_LIST, to_add, to_remove, |addr, deps| {
-        // Perform a balance query here as this is the query performed
-        // by the `Cw20Balances` query.
-        let _info: cw20::BalanceResponse = deps.querier.query_wasm_smart(
-            addr,
-            &cw20::Cw20QueryMsg::Balance {
-                address: env.contract.address.to_string(),
-            },
-        )?;
-        Ok(())
-    })?;
-    Ok(Response::default().add_attribute("action", "update_cw20_list"))
+    check_item_permission(deps.as_ref(), &env, &sender, &key)?;
+    validate_item(deps.as_ref(), &key, &value, Some(&value_type))?;
+
+    ITEMS.save(deps.storage, key.clone(), &value)?;
+    ITEM_TYPES.save(deps.storage, key.clone(), &value_type)?;
+    Ok(Response::default()
+        .add_attribute("action", "execute_set_typed_item")
+        .add_attribute("key", key)
+        .add_attribute("value", value))
 }
 
-pub fn execute_update_cw721_list(
+pub fn execute_update_item_permissions(
     deps: DepsMut,
     env: Env,
     sender: Addr,
+    module: String,
     to_add: Vec<String>,
     to_remove: Vec<String>,
 ) -> Result<Response, ContractError> {
     if env.contract.address != sender {
         return Err(ContractError::Unauthorized {});
     }
-    do_update_addr_list(deps, CW721_LIST, to_add, to_remove, |addr, deps| {
-        let _info: cw721::ContractInfoResponse = deps
-            .querier
-            .query_wasm_smart(addr, &cw721::Cw721QueryMsg::ContractInfo {})?;
-        Ok(())
-    })?;
-    Ok(Response::default().add_attribute("action", "update_cw721_list"))
-}
 
-pub fn execute_set_item(
-    deps: DepsMut,
-    env: Env,
-    sender: Addr,
-    key: String,
-    value: String,
-) -> Result<Response, ContractError> {
-    if env.contract.address != sender {
-        return Err(ContractError::Unauthorized {});
+    let module = deps.api.addr_validate(&module)?;
+    if !PROPOSAL_MODULES.has(deps.storage, module.clone()) {
+        return Err(ContractError::ProposalModuleDoesNotExist { address: module });
+    }
+
+    for pattern in to_add {
+        ITEM_PERMISSIONS.save(deps.storage, (&module, pattern.as_str()), &Empty {})?;
+    }
+    for pattern in to_remove {
+        ITEM_PERMISSIONS.remove(deps.storage, (&module, pattern.as_str()));
     }
 
-    ITEMS.save(deps.storage, key.clone(), &value)?;
     Ok(Response::default()
-        .add_attribute("action", "execute_set_item")
-        .add_attribute("key", key)
-        .add_attribute("addr", value))
+        .add_attribute("action", "execute_update_item_permissions")
+        .add_attribute("module", module))
 }
 
 pub fn execute_remove_item(
@@ -476,12 +1407,11 @@ pub fn execute_remove_item(
     sender: Addr,
     key: String,
 ) -> Result<Response, ContractError> {
-    if env.contract.address != sender {
-        return Err(ContractError::Unauthorized {});
-    }
+    check_item_permission(deps.as_ref(), &env, &sender, &key)?;
 
     if ITEMS.has(deps.storage, key.clone()) {
         ITEMS.remove(deps.storage, key.clone());
+        ITEM_TYPES.remove(deps.storage, key.clone());
         Ok(Response::default()
             .add_attribute("action", "execute_remove_item")
             .add_attribute("key", key))
@@ -501,19 +1431,177 @@ pub fn execute_update_sub_daos_list(
         return Err(ContractError::Unauthorized {});
     }
 
+    let mut msgs = vec![];
+
     for addr in to_remove {
         let addr = deps.api.addr_validate(&addr)?;
         SUBDAO_LIST.remove(deps.storage, &addr);
+        // A SubDAO that is paused, broken or otherwise rejects the
+        // update is still removed, keeping its parent.
+        if let Some(msg) = update_parent_dao_msg(deps.as_ref(), &env, &addr, None).unwrap_or(None) {
+            msgs.push(SubMsg::reply_on_error(msg, SUBDAO_PARENT_REMOVAL_REPLY_ID));
+        }
     }
 
     for subdao in to_add {
         let addr = deps.api.addr_validate(&subdao.addr)?;
+        query_dao_version(deps.as_ref(), &addr)?;
         SUBDAO_LIST.save(deps.storage, &addr, &subdao.charter)?;
+        msgs.extend(
+            update_parent_dao_msg(
+                deps.as_ref(),
+                &env,
+                &addr,
+                Some(env.contract.address.to_string()),
+            )?
+            .map(SubMsg::new),
+        );
     }
 
     Ok(Response::default()
         .add_attribute("action", "execute_update_sub_daos_list")
-        .add_attribute("sender", sender))
+        .add_attribute("sender", sender)
+        .add_submessages(msgs))
+}
+
+/// Returns the contract version of `addr` if it is a DAO DAO core
+/// contract.
+fn query_dao_version(deps: Deps, addr: &Addr) -> Result<ContractVersion, ContractError> {
+    let not_a_dao = || ContractError::NotADao {
+        address: addr.to_string(),
+    };
+    let info: voting::InfoResponse = deps
+        .querier
+        .query_wasm_smart(addr, &QueryMsg::Info {})
+        .map_err(|_| not_a_dao())?;
+    if info.info.contract == CONTRACT_NAME || info.info.contract == V1_CONTRACT_NAME {
+        Ok(info.info)
+    } else {
+        Err(not_a_dao())
+    }
+}
+
+/// Returns a message updating the parent of `sub_dao` if this DAO is
+/// its admin. The parent is only removed if it is this DAO. v1 DAOs do
+/// not track their parent and are skipped.
+fn update_parent_dao_msg(
+    deps: Deps,
+    env: &Env,
+    sub_dao: &Addr,
+    parent: Option<String>,
+) -> StdResult<Option<WasmMsg>> {
+    let info: StdResult<voting::InfoResponse> =
+        deps.querier.query_wasm_smart(sub_dao, &QueryMsg::Info {});
+    let is_current_dao = info.map_or(false, |info| info.info.contract == CONTRACT_NAME);
+    if !is_current_dao {
+        return Ok(None);
+    }
+
+    let admin: Addr = deps
+        .querier
+        .query_wasm_smart(sub_dao, &QueryMsg::Admin {})?;
+    if admin != env.contract.address {
+        return Ok(None);
+    }
+    if parent.is_none() {
+        let current: Option<Addr> = deps
+            .querier
+            .query_wasm_smart(sub_dao, &QueryMsg::ParentDao {})?;
+        if current.as_ref() != Some(&env.contract.address) {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(WasmMsg::Execute {
+        contract_addr: sub_dao.to_string(),
+        msg: to_binary(&ExecuteMsg::UpdateParentDao { parent })?,
+        funds: vec![],
+    }))
+}
+
+pub fn execute_update_parent_dao(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    parent: Option<String>,
+) -> Result<Response, ContractError> {
+    let admin = ADMIN.load(deps.storage)?;
+    if sender != env.contract.address && sender != admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match &parent {
+        Some(parent) => {
+            let parent = deps.api.addr_validate(parent)?;
+            query_dao_version(deps.as_ref(), &parent)?;
+            PARENT_DAO.save(deps.storage, &parent)?;
+        }
+        None => PARENT_DAO.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_parent_dao")
+        .add_attribute("parent", parent.unwrap_or_else(|| "None".to_string())))
+}
+
+pub fn execute_sub_dao_action(
+    deps: Deps,
+    env: Env,
+    sender: Addr,
+    address: String,
+    action: SubDaoAction,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let sub_dao = deps.api.addr_validate(&address)?;
+    if !SUBDAO_LIST.has(deps.storage, &sub_dao) {
+        return Err(ContractError::NotASubDao { address: sub_dao });
+    }
+
+    let msg: CosmosMsg = match action {
+        SubDaoAction::Fund { funds } => BankMsg::Send {
+            to_address: sub_dao.to_string(),
+            amount: funds,
+        }
+        .into(),
+        SubDaoAction::Pause { duration } => {
+            let pause = WasmMsg::Execute {
+                contract_addr: sub_dao.to_string(),
+                msg: to_binary(&ExecuteMsg::Pause { duration })?,
+                funds: vec![],
+            };
+            // A pause guardian may pause the SubDAO directly. An
+            // admin must have the SubDAO pause itself.
+            let config: Config = deps
+                .querier
+                .query_wasm_smart(&sub_dao, &QueryMsg::Config {})?;
+            if config.pause_guardian.as_ref() == Some(&env.contract.address) {
+                pause.into()
+            } else {
+                WasmMsg::Execute {
+                    contract_addr: sub_dao.to_string(),
+                    msg: to_binary(&ExecuteMsg::ExecuteAdminMsgs {
+                        msgs: vec![pause.into()],
+                    })?,
+                    funds: vec![],
+                }
+                .into()
+            }
+        }
+        SubDaoAction::NominateAdmin { admin } => WasmMsg::Execute {
+            contract_addr: sub_dao.to_string(),
+            msg: to_binary(&ExecuteMsg::NominateAdmin { admin })?,
+            funds: vec![],
+        }
+        .into(),
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_sub_dao_action")
+        .add_attribute("sub_dao", sub_dao)
+        .add_message(msg))
 }
 
 pub fn execute_receive_cw20(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
@@ -553,11 +1641,37 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Cw721TokenList { start_after, limit } => {
             query_cw721_list(deps, start_after, limit)
         }
+        QueryMsg::Cw721Holdings {
+            collection,
+            start_after,
+            limit,
+        } => query_cw721_holdings(deps, env, collection, start_after, limit),
+        QueryMsg::NativeTokenList { start_after, limit } => {
+            query_native_list(deps, start_after, limit)
+        }
+        QueryMsg::NativeBalances { start_after, limit } => {
+            query_native_balances(deps, env, start_after, limit)
+        }
         QueryMsg::DumpState {} => query_dump_state(deps, env),
         QueryMsg::GetItem { key } => query_get_item(deps, key),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ListItems { start_after, limit } => query_list_items(deps, start_after, limit),
+        QueryMsg::GetTypedItem { key } => query_get_typed_item(deps, key),
+        QueryMsg::ListGrants {
+            grantee,
+            start_after,
+            limit,
+        } => query_list_grants(deps, env, grantee, start_after, limit),
+        QueryMsg::ListItemsByPrefix {
+            prefix,
+            start_after,
+            limit,
+        } => query_list_items_by_prefix(deps, prefix, start_after, limit),
+        QueryMsg::ItemPermissions { module } => query_item_permissions(deps, module),
         QueryMsg::PauseInfo {} => query_paused(deps, env),
+        QueryMsg::PauseHistory { start_after, limit } => {
+            query_pause_history(deps, start_after, limit)
+        }
         QueryMsg::ProposalModules { start_after, limit } => {
             query_proposal_modules(deps, start_after, limit)
         }
@@ -573,7 +1687,25 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ListSubDaos { start_after, limit } => {
             query_list_sub_daos(deps, start_after, limit)
         }
+        QueryMsg::ParentDao {} => query_parent_dao(deps),
+        QueryMsg::DaoAncestors {} => query_dao_ancestors(deps, env),
+        QueryMsg::SubDaoTree { depth } => query_sub_dao_tree(deps, env, depth),
         QueryMsg::DaoURI {} => query_dao_uri(deps),
+        QueryMsg::TimelockConfig {} => query_timelock_config(deps),
+        QueryMsg::TimelockedOperation { id } => query_timelocked_operation(deps, id),
+        QueryMsg::ListTimelockedOperations { start_after, limit } => {
+            query_list_timelocked_operations(deps, start_after, limit)
+        }
+        QueryMsg::StargateAllowlist { start_after, limit } => {
+            query_stargate_allowlist(deps, start_after, limit)
+        }
+        QueryMsg::StargateAllowlistEnforced {} => query_stargate_allowlist_enforced(deps),
+        QueryMsg::ProposalModulePermissions { address } => {
+            query_proposal_module_permissions(deps, address)
+        }
+        QueryMsg::ProposalModuleSpending { address } => {
+            query_proposal_module_spending(deps, address)
+        }
     }
 }
 
@@ -671,6 +1803,20 @@ pub fn query_paused(deps: Deps, env: Env) -> StdResult<Binary> {
     to_binary(&get_pause_info(deps, env)?)
 }
 
+pub fn query_pause_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_binary(&paginate_map_values(
+        deps,
+        &PAUSE_HISTORY,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Descending,
+    )?)
+}
+
 pub fn query_dump_state(deps: Deps, env: Env) -> StdResult<Binary> {
     let admin = ADMIN.load(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
@@ -721,6 +1867,73 @@ pub fn query_get_item(deps: Deps, item: String) -> StdResult<Binary> {
     to_binary(&GetItemResponse { item })
 }
 
+pub fn query_list_grants(
+    deps: Deps,
+    env: Env,
+    grantee: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let grantee = deps.api.addr_validate(&grantee)?;
+    let grants = GRANTS
+        .prefix(&grantee)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, grant)| grant))
+        .filter(|grant| {
+            grant
+                .as_ref()
+                .map_or(true, |grant| !grant.expiration.is_expired(&env.block))
+        });
+    let grants = match limit {
+        Some(limit) => grants.take(limit as usize).collect::<StdResult<Vec<_>>>()?,
+        None => grants.collect::<StdResult<Vec<_>>>()?,
+    };
+    to_binary(&grants)
+}
+
+pub fn query_get_typed_item(deps: Deps, key: String) -> StdResult<Binary> {
+    let item = ITEMS.may_load(deps.storage, key.clone())?;
+    let value_type = ITEM_TYPES.may_load(deps.storage, key)?;
+    to_binary(&GetTypedItemResponse { item, value_type })
+}
+
+pub fn query_list_items_by_prefix(
+    deps: Deps,
+    prefix: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let min = match start_after {
+        Some(start_after) if start_after >= prefix => Bound::exclusive(start_after),
+        _ => Bound::inclusive(prefix.clone()),
+    };
+    let items = ITEMS
+        .range(deps.storage, Some(min), None, Order::Ascending)
+        .take_while(|item| {
+            item.as_ref()
+                .map_or(true, |(key, _)| key.starts_with(&prefix))
+        });
+    let items = match limit {
+        Some(limit) => items.take(limit as usize).collect::<StdResult<Vec<_>>>()?,
+        None => items.collect::<StdResult<Vec<_>>>()?,
+    };
+    to_binary(&items)
+}
+
+pub fn query_item_permissions(deps: Deps, module: String) -> StdResult<Binary> {
+    let module = deps.api.addr_validate(&module)?;
+    let patterns = ITEM_PERMISSIONS
+        .prefix(&module)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    to_binary(&patterns)
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_binary(&dao_interface::voting::InfoResponse { info })
@@ -805,6 +2018,59 @@ pub fn query_cw20_balances(
     to_binary(&balances)
 }
 
+pub fn query_cw721_holdings(
+    deps: Deps,
+    env: Env,
+    collection: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let collection = deps.api.addr_validate(&collection)?;
+    let tokens: cw721::TokensResponse = deps.querier.query_wasm_smart(
+        collection,
+        &cw721::Cw721QueryMsg::Tokens {
+            owner: env.contract.address.to_string(),
+            start_after,
+            limit,
+        },
+    )?;
+    to_binary(&tokens.tokens)
+}
+
+pub fn query_native_list(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_binary(&paginate_map_keys(
+        deps,
+        &NATIVE_LIST,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Descending,
+    )?)
+}
+
+pub fn query_native_balances(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let denoms = paginate_map_keys(
+        deps,
+        &NATIVE_LIST,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Descending,
+    )?;
+    let balances = denoms
+        .into_iter()
+        .map(|denom| deps.querier.query_balance(&env.contract.address, denom))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_binary(&balances)
+}
+
 pub fn query_list_sub_daos(
     deps: Deps,
     start_after: Option<String>,
@@ -833,6 +2099,88 @@ pub fn query_list_sub_daos(
     to_binary(&subdaos)
 }
 
+pub fn query_parent_dao(deps: Deps) -> StdResult<Binary> {
+    to_binary(&PARENT_DAO.may_load(deps.storage)?)
+}
+
+pub fn query_dao_ancestors(deps: Deps, env: Env) -> StdResult<Binary> {
+    let mut ancestors: Vec<Addr> = vec![];
+    let mut parent = PARENT_DAO.may_load(deps.storage)?;
+
+    while let Some(dao) = parent {
+        // Stop walking if the tree contains a cycle or is too deep.
+        if dao == env.contract.address
+            || ancestors.contains(&dao)
+            || ancestors.len() as u32 >= MAX_DAO_TREE_DEPTH
+        {
+            break;
+        }
+        // v1 DAOs do not track their parent.
+        parent = deps
+            .querier
+            .query_wasm_smart(&dao, &QueryMsg::ParentDao {})
+            .unwrap_or_default();
+        ancestors.push(dao);
+    }
+
+    to_binary(&ancestors)
+}
+
+pub fn query_sub_dao_tree(deps: Deps, env: Env, depth: Option<u32>) -> StdResult<Binary> {
+    let max_depth = depth.unwrap_or(MAX_DAO_TREE_DEPTH).min(MAX_DAO_TREE_DEPTH);
+    if max_depth == 0 {
+        return to_binary(&Vec::<SubDaoTreeNode>::new());
+    }
+
+    let mut nodes = SUBDAO_LIST
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(addr, charter)| SubDaoTreeNode {
+                addr,
+                charter,
+                parent: env.contract.address.clone(),
+                depth: 1,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // Walk the tree breadth first, visiting each DAO at most once.
+    let mut i = 0;
+    while i < nodes.len() {
+        let (parent, depth) = (nodes[i].addr.clone(), nodes[i].depth);
+        i += 1;
+        if depth >= max_depth {
+            continue;
+        }
+
+        // v1 DAOs do not have SubDAOs.
+        let sub_daos: Vec<SubDao> = deps
+            .querier
+            .query_wasm_smart(
+                &parent,
+                &QueryMsg::ListSubDaos {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap_or_default();
+        for sub_dao in sub_daos {
+            let addr = deps.api.addr_validate(&sub_dao.addr)?;
+            if addr == env.contract.address || nodes.iter().any(|node| node.addr == addr) {
+                continue;
+            }
+            nodes.push(SubDaoTreeNode {
+                addr,
+                charter: sub_dao.charter,
+                parent: parent.clone(),
+                depth: depth + 1,
+            });
+        }
+    }
+
+    to_binary(&nodes)
+}
+
 pub fn query_dao_uri(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     to_binary(&DaoURIResponse {
@@ -847,6 +2195,65 @@ pub fn query_proposal_module_count(deps: Deps) -> StdResult<Binary> {
     })
 }
 
+pub fn query_timelock_config(deps: Deps) -> StdResult<Binary> {
+    to_binary(&TIMELOCK.may_load(deps.storage)?)
+}
+
+pub fn query_timelocked_operation(deps: Deps, id: u64) -> StdResult<Binary> {
+    to_binary(&TIMELOCKED_OPERATIONS.load(deps.storage, id)?)
+}
+
+pub fn query_list_timelocked_operations(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_binary(&paginate_map_values(
+        deps,
+        &TIMELOCKED_OPERATIONS,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Ascending,
+    )?)
+}
+
+pub fn query_stargate_allowlist(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_binary(&paginate_map_keys(
+        deps,
+        &STARGATE_ALLOWLIST,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Ascending,
+    )?)
+}
+
+pub fn query_stargate_allowlist_enforced(deps: Deps) -> StdResult<Binary> {
+    to_binary(
+        &STARGATE_ALLOWLIST_ENFORCED
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    )
+}
+
+pub fn query_proposal_module_permissions(deps: Deps, address: String) -> StdResult<Binary> {
+    let module = deps.api.addr_validate(&address)?;
+    to_binary(&PROPOSAL_MODULE_PERMISSIONS.may_load(deps.storage, module)?)
+}
+
+pub fn query_proposal_module_spending(deps: Deps, address: String) -> StdResult<Binary> {
+    let module = deps.api.addr_validate(&address)?;
+    let spending = PROPOSAL_MODULE_SPENDING
+        .prefix(&module)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, spending)| spending))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_binary(&spending)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let ContractVersion { version, .. } = get_contract_version(deps.storage)?;
@@ -898,6 +2305,8 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, Con
                     automatically_add_cw20s: v1_config.automatically_add_cw20s,
                     automatically_add_cw721s: v1_config.automatically_add_cw721s,
                     dao_uri,
+                    automatically_add_native_denoms: false,
+                    pause_guardian: None,
                 },
             )?;
 
@@ -995,6 +2404,12 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 
             Ok(Response::default().add_attribute("voting_module", vote_module_addr))
         }
+        SUBDAO_PARENT_REMOVAL_REPLY_ID => {
+            // The SubDAO has already been removed, so failing to clear
+            // its parent does not revert the removal.
+            let error = msg.result.into_result().err().unwrap_or_default();
+            Ok(Response::default().add_attribute("failed_parent_dao_removal", error))
+        }
         _ => Err(ContractError::UnknownReplyID {}),
     }
 }
@@ -1,5 +1,6 @@
-use cosmwasm_std::{Addr, StdError};
-use cw_utils::ParseReplyError;
+use cosmwasm_std::{Addr, StdError, Uint128};
+use cw_utils::{Duration, Expiration, ParseReplyError};
+use dao_interface::state::ItemValueType;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -16,6 +17,18 @@ pub enum ContractError {
     #[error("The contract is paused.")]
     Paused {},
 
+    #[error("The contract is not paused.")]
+    NotPaused {},
+
+    #[error("The pause guardian may pause for at most ({max}).")]
+    GuardianPauseTooLong { max: Duration },
+
+    #[error("The pause guardian may not pause again until ({until}).")]
+    GuardianPauseCooldown { until: Expiration },
+
+    #[error("The pause guardian may only lift pauses it started.")]
+    GuardianUnpause {},
+
     #[error("No voting module provided.")]
     NoVotingModule {},
 
@@ -34,6 +47,18 @@ pub enum ContractError {
     #[error("Key is missing from storage")]
     KeyMissing {},
 
+    #[error("Item keys may be at most ({max}) bytes long.")]
+    ItemKeyTooLong { max: usize },
+
+    #[error("Item values may be at most ({max}) bytes long.")]
+    ItemValueTooLong { max: usize },
+
+    #[error("Value of item ({key}) is not a valid {value_type:?}.")]
+    InvalidItemValue {
+        key: String,
+        value_type: ItemValueType,
+    },
+
     #[error("No pending admin nomination.")]
     NoAdminNomination {},
 
@@ -56,4 +81,50 @@ pub enum ContractError {
 
     #[error("Can not migrate. Current version is up to date.")]
     AlreadyMigrated {},
+
+    #[error("({address}) is not a DAO DAO core contract.")]
+    NotADao { address: String },
+
+    #[error("({address}) is not a SubDAO of this DAO.")]
+    NotASubDao { address: Addr },
+
+    #[error("No grant with ID ({id}) exists for ({grantee}).")]
+    GrantNotFound { grantee: Addr, id: u64 },
+
+    #[error("Expiration of a grant must be in the future.")]
+    GrantExpired {},
+
+    #[error("No grant allows ({grantee}) to execute this message on ({contract}).")]
+    NoMatchingGrant { grantee: Addr, contract: String },
+
+    #[error("A grant must have at least one use.")]
+    InvalidGrantUses {},
+
+    #[error("Grants on the DAO itself must have a message type that does not affect governance.")]
+    UndelegableMsg {},
+
+    #[error("Delegated messages must be contract executions without funds.")]
+    InvalidDelegatedMsg {},
+
+    #[error("No timelocked operation with ID ({id}) exists.")]
+    TimelockedOperationNotFound { id: u64 },
+
+    #[error("Timelocked operation ({id}) may not be executed until ({executable_at}).")]
+    TimelockNotExpired { id: u64, executable_at: Expiration },
+
+    #[error("Stargate messages with type URL ({type_url}) are not allowed.")]
+    StargateMsgNotAllowed { type_url: String },
+
+    #[error("Proposal module ({module}) is not permitted to execute this type of message.")]
+    MessageNotPermitted { module: Addr },
+
+    #[error("Proposal module ({module}) is not permitted to execute messages on ({contract}).")]
+    ContractNotPermitted { module: Addr, contract: String },
+
+    #[error("Proposal module ({module}) may not spend more than ({limit}{denom}) this period.")]
+    SpendLimitExceeded {
+        module: Addr,
+        denom: String,
+        limit: Uint128,
+    },
 }
@@ -1,7 +1,10 @@
 use cosmwasm_std::{Addr, Empty};
 use cw_storage_plus::{Item, Map};
 use cw_utils::Expiration;
-use dao_interface::state::{Config, ProposalModule};
+use dao_interface::state::{
+    Config, Grant, ItemValueType, ModulePermissions, ModuleSpending, PauseRecord, ProposalModule,
+    TimelockConfig, TimelockedOperation,
+};
 
 /// The admin of the contract. Typically a DAO. The contract admin may
 /// unilaterally execute messages on this contract.
@@ -27,6 +30,13 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 /// the DAO has never been paused.
 pub const PAUSED: Item<Expiration> = Item::new("paused");
 
+/// When the pause guardian may next pause the DAO. Not set if the
+/// guardian has never paused the DAO.
+pub const GUARDIAN_PAUSE_COOLDOWN: Item<Expiration> = Item::new("guardian_pause_cooldown");
+
+/// Every time the DAO has been paused, keyed by record ID.
+pub const PAUSE_HISTORY: Map<u64, PauseRecord> = Map::new("pause_history");
+
 /// The voting module associated with this contract.
 pub const VOTING_MODULE: Item<Addr> = Item::new("voting_module");
 
@@ -35,6 +45,16 @@ pub const VOTING_MODULE: Item<Addr> = Item::new("voting_module");
 /// to create a new namespace for the changed state.
 pub const PROPOSAL_MODULES: Map<Addr, ProposalModule> = Map::new("proposal_modules_v2");
 
+/// Permissions of proposal modules whose authority is limited.
+/// Proposal modules without an entry may execute any message.
+pub const PROPOSAL_MODULE_PERMISSIONS: Map<Addr, ModulePermissions> =
+    Map::new("proposal_module_permissions");
+
+/// How much of each denom a proposal module with permissions has
+/// spent in the current period. Keyed by `(module, denom)`.
+pub const PROPOSAL_MODULE_SPENDING: Map<(&Addr, &str), ModuleSpending> =
+    Map::new("proposal_module_spending");
+
 /// The count of active proposal modules associated with this contract.
 pub const ACTIVE_PROPOSAL_MODULE_COUNT: Item<u32> = Item::new("active_proposal_module_count");
 
@@ -44,12 +64,55 @@ pub const TOTAL_PROPOSAL_MODULE_COUNT: Item<u32> = Item::new("total_proposal_mod
 // General purpose KV store for DAO associated state.
 pub const ITEMS: Map<String, String> = Map::new("items");
 
+/// The types of items set with `SetTypedItem`. Items without an entry
+/// are untyped strings.
+pub const ITEM_TYPES: Map<String, ItemValueType> = Map::new("item_types");
+
+/// Item key patterns proposal modules may set and remove items
+/// for. Keyed by `(module, pattern)`.
+pub const ITEM_PERMISSIONS: Map<(&Addr, &str), Empty> = Map::new("item_permissions");
+
 /// Set of cw20 tokens that have been registered with this contract's
 /// treasury.
 pub const CW20_LIST: Map<Addr, Empty> = Map::new("cw20s");
 /// Set of cw721 tokens that have been registered with this contract's
 /// treasury.
 pub const CW721_LIST: Map<Addr, Empty> = Map::new("cw721s");
+/// Set of native denoms that have been registered with this
+/// contract's treasury.
+pub const NATIVE_LIST: Map<String, Empty> = Map::new("native_denoms");
 
 /// List of SubDAOs associated to this DAO. Each SubDAO has an optional charter.
 pub const SUBDAO_LIST: Map<&Addr, Option<String>> = Map::new("sub_daos");
+
+/// The DAO that this DAO is a SubDAO of, if any.
+pub const PARENT_DAO: Item<Addr> = Item::new("parent_dao");
+
+/// Grants allowing addresses to execute messages on behalf of the
+/// DAO. Keyed by `(grantee, id)`.
+pub const GRANTS: Map<(&Addr, u64), Grant> = Map::new("grants");
+
+/// The number of grants that have ever been created. Used to assign
+/// IDs to new grants.
+pub const GRANT_COUNT: Item<u64> = Item::new("grant_count");
+
+/// The timelock applied to messages executed by proposal modules. Not
+/// set if the DAO has no timelock.
+pub const TIMELOCK: Item<TimelockConfig> = Item::new("timelock");
+
+/// Operations queued by proposal modules that are waiting for the
+/// timelock delay to pass.
+pub const TIMELOCKED_OPERATIONS: Map<u64, TimelockedOperation> = Map::new("timelocked_operations");
+
+/// The number of operations that have ever been queued. Used to
+/// assign IDs to new operations.
+pub const TIMELOCKED_OPERATION_COUNT: Item<u64> = Item::new("timelocked_operation_count");
+
+/// Set of Stargate message type URLs that proposal modules and the
+/// admin may execute while `STARGATE_ALLOWLIST_ENFORCED` is set.
+pub const STARGATE_ALLOWLIST: Map<String, Empty> = Map::new("stargate_allowlist");
+
+/// Whether Stargate messages are restricted to `STARGATE_ALLOWLIST`.
+/// If unset or false, any Stargate message may be executed. If set,
+/// an empty allowlist blocks all Stargate messages.
+pub const STARGATE_ALLOWLIST_ENFORCED: Item<bool> = Item::new("stargate_allowlist_enforced");
@@ -1,20 +1,25 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    from_slice,
+    coin, coins, from_slice,
     testing::{mock_dependencies, mock_env},
-    to_binary, Addr, CosmosMsg, Empty, Storage, Uint128, WasmMsg,
+    to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Empty, Storage, Uint128, WasmMsg,
 };
 use cw2::{set_contract_version, ContractVersion};
-use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
 use cw_storage_plus::{Item, Map};
 use cw_utils::{Duration, Expiration};
 use dao_interface::{
     msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg},
     query::{
         AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, SubDao,
+        GetItemResponse, GetTypedItemResponse, PauseInfoResponse, ProposalModuleCountResponse,
+        SubDao, SubDaoTreeNode,
+    },
+    state::{
+        Admin, Config, Grant, ItemValueType, ModuleInstantiateInfo, ModulePermissions,
+        ModuleSpending, PauseRecord, ProposalModule, ProposalModuleStatus, SpendLimit,
+        SubDaoAction, TimelockConfig, TimelockedOperation,
     },
-    state::{Admin, Config, ModuleInstantiateInfo, ProposalModule, ProposalModuleStatus},
     voting::{InfoResponse, VotingPowerAtHeightResponse},
 };
 
@@ -147,6 +152,8 @@ fn test_instantiate_with_n_gov_modules(n: usize) {
             image_url: None,
             automatically_add_cw20s: true,
             automatically_add_cw721s: true,
+            automatically_add_native_denoms: false,
+            pause_guardian: None,
         }
     );
 
@@ -294,6 +301,8 @@ fn test_update_config() {
         automatically_add_cw20s: false,
         automatically_add_cw721s: true,
         dao_uri: Some("https://daostar.one/EIP".to_string()),
+        automatically_add_native_denoms: false,
+        pause_guardian: None,
     };
 
     app.execute_contract(
@@ -939,6 +948,8 @@ fn test_permissions() {
                 image_url: None,
                 automatically_add_cw20s: true,
                 automatically_add_cw721s: true,
+                automatically_add_native_denoms: false,
+                pause_guardian: None,
             },
         },
     );
@@ -946,6 +957,11 @@ fn test_permissions() {
 
 fn do_standard_instantiate(auto_add: bool, admin: Option<String>) -> (Addr, App) {
     let mut app = App::default();
+    let gov_addr = instantiate_standard_dao(&mut app, auto_add, admin);
+    (gov_addr, app)
+}
+
+fn instantiate_standard_dao(app: &mut App, auto_add: bool, admin: Option<String>) -> Addr {
     let govmod_id = app.store_code(sudo_proposal_contract());
     let voting_id = app.store_code(cw20_balances_voting());
     let gov_id = app.store_code(cw_core_contract());
@@ -992,18 +1008,15 @@ fn do_standard_instantiate(auto_add: bool, admin: Option<String>) -> (Addr, App)
         initial_items: None,
     };
 
-    let gov_addr = app
-        .instantiate_contract(
-            gov_id,
-            Addr::unchecked(CREATOR_ADDR),
-            &gov_instantiate,
-            &[],
-            "cw-governance",
-            None,
-        )
-        .unwrap();
-
-    (gov_addr, app)
+    app.instantiate_contract(
+        gov_id,
+        Addr::unchecked(CREATOR_ADDR),
+        &gov_instantiate,
+        &[],
+        "cw-governance",
+        None,
+    )
+    .unwrap()
 }
 
 #[test]
@@ -1575,6 +1588,279 @@ fn test_remove_missing_key() {
     remove_item(&mut app, gov_addr, "b".to_string())
 }
 
+#[test]
+fn test_typed_items() {
+    let (gov_addr, mut app) = do_standard_instantiate(true, None);
+
+    let set_typed_item = |app: &mut App, key: &str, value: &str, value_type: ItemValueType| {
+        app.execute_contract(
+            gov_addr.clone(),
+            gov_addr.clone(),
+            &ExecuteMsg::SetTypedItem {
+                key: key.to_string(),
+                value: value.to_string(),
+                value_type,
+            },
+            &[],
+        )
+    };
+
+    set_typed_item(
+        &mut app,
+        "profile/treasurer",
+        "ekez",
+        ItemValueType::Address,
+    )
+    .unwrap();
+    set_typed_item(
+        &mut app,
+        "profile/website",
+        "https://daodao.zone",
+        ItemValueType::Url,
+    )
+    .unwrap();
+    set_typed_item(
+        &mut app,
+        "widgets/vesting",
+        r#"{"factories":["a","b"]}"#,
+        ItemValueType::Json,
+    )
+    .unwrap();
+
+    let err: ContractError =
+        set_typed_item(&mut app, "profile/website", "daodao", ItemValueType::Url)
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(
+        err,
+        ContractError::InvalidItemValue {
+            key: "profile/website".to_string(),
+            value_type: ItemValueType::Url,
+        }
+    );
+    let err: ContractError = set_typed_item(&mut app, "widgets/vesting", "{", ItemValueType::Json)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::InvalidItemValue {
+            key: "widgets/vesting".to_string(),
+            value_type: ItemValueType::Json,
+        }
+    );
+    let err: ContractError = set_typed_item(&mut app, &"k".repeat(257), "v", ItemValueType::String)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ItemKeyTooLong { max: 256 });
+
+    // Namespaced items are limited in size, while untyped items
+    // outside of a namespace are not.
+    let err: ContractError = app
+        .execute_contract(
+            gov_addr.clone(),
+            gov_addr.clone(),
+            &ExecuteMsg::SetItem {
+                key: "profile/bio".to_string(),
+                value: "v".repeat(8193),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ItemValueTooLong { max: 8192 });
+    set_item(
+        &mut app,
+        gov_addr.clone(),
+        "k".repeat(257),
+        "v".repeat(8193),
+    );
+
+    // Typed items keep their type when updated with `SetItem`.
+    let err: ContractError = app
+        .execute_contract(
+            gov_addr.clone(),
+            gov_addr.clone(),
+            &ExecuteMsg::SetItem {
+                key: "profile/website".to_string(),
+                value: "not a url".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::InvalidItemValue {
+            key: "profile/website".to_string(),
+            value_type: ItemValueType::Url,
+        }
+    );
+    set_item(
+        &mut app,
+        gov_addr.clone(),
+        "profile/website".to_string(),
+        "ipfs://website".to_string(),
+    );
+
+    let item: GetTypedItemResponse = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::GetTypedItem {
+                key: "profile/website".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        item,
+        GetTypedItemResponse {
+            item: Some("ipfs://website".to_string()),
+            value_type: Some(ItemValueType::Url),
+        }
+    );
+
+    // `GetItem` still works for typed items.
+    let item = get_item(&mut app, gov_addr.clone(), "profile/treasurer".to_string());
+    assert_eq!(
+        item,
+        GetItemResponse {
+            item: Some("ekez".to_string())
+        }
+    );
+
+    set_item(
+        &mut app,
+        gov_addr.clone(),
+        "profiles".to_string(),
+        "x".to_string(),
+    );
+    let items: Vec<(String, String)> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::ListItemsByPrefix {
+                prefix: "profile/".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            ("profile/treasurer".to_string(), "ekez".to_string()),
+            ("profile/website".to_string(), "ipfs://website".to_string()),
+        ]
+    );
+    let items: Vec<(String, String)> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::ListItemsByPrefix {
+                prefix: "profile/".to_string(),
+                start_after: Some("profile/treasurer".to_string()),
+                limit: Some(5),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        items,
+        vec![("profile/website".to_string(), "ipfs://website".to_string())]
+    );
+
+    // Proposal modules may be given permission to write items.
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let module = proposal_modules.into_iter().next().unwrap().address;
+
+    test_unauthorized(
+        &mut app,
+        gov_addr.clone(),
+        ExecuteMsg::UpdateItemPermissions {
+            module: module.to_string(),
+            to_add: vec!["widgets/*".to_string()],
+            to_remove: vec![],
+        },
+    );
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::UpdateItemPermissions {
+            module: module.to_string(),
+            to_add: vec!["widgets/*".to_string(), "profile/website".to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let permissions: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::ItemPermissions {
+                module: module.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        permissions,
+        vec!["profile/website".to_string(), "widgets/*".to_string()]
+    );
+
+    for key in ["widgets/press", "profile/website"] {
+        app.execute_contract(
+            module.clone(),
+            gov_addr.clone(),
+            &ExecuteMsg::SetItem {
+                key: key.to_string(),
+                value: "https://daodao.zone".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.execute_contract(
+        module.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::RemoveItem {
+            key: "widgets/press".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    for key in ["profile/treasurer", "widgets", "profiles"] {
+        let err: ContractError = app
+            .execute_contract(
+                module.clone(),
+                gov_addr.clone(),
+                &ExecuteMsg::SetItem {
+                    key: key.to_string(),
+                    value: "v".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+}
+
 #[test]
 fn test_list_items() {
     let mut app = App::default();
@@ -2323,6 +2609,8 @@ fn test_pause() {
                 image_url: None,
                 automatically_add_cw20s: true,
                 automatically_add_cw721s: true,
+                automatically_add_native_denoms: false,
+                pause_guardian: None,
             },
         },
         &[],
@@ -2399,6 +2687,8 @@ fn test_pause() {
                     image_url: None,
                     automatically_add_cw20s: true,
                     automatically_add_cw721s: true,
+                    automatically_add_native_denoms: false,
+                    pause_guardian: None,
                 },
             },
             &[],
@@ -2965,21 +3255,50 @@ fn test_add_remove_subdaos() {
         },
     );
 
+    // Only DAO DAO core contracts may be added as SubDAOs.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateSubDaos {
+                to_add: vec![SubDao {
+                    addr: "subdao001".to_string(),
+                    charter: None,
+                }],
+                to_remove: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NotADao {
+            address: "subdao001".to_string()
+        }
+    );
+
+    let mut subdaos: Vec<Addr> = (0..4)
+        .map(|_| instantiate_standard_dao(&mut app, false, None))
+        .collect();
+    subdaos.sort();
+
     let to_add: Vec<SubDao> = vec![
         SubDao {
-            addr: "subdao001".to_string(),
+            addr: subdaos[0].to_string(),
             charter: None,
         },
         SubDao {
-            addr: "subdao002".to_string(),
+            addr: subdaos[1].to_string(),
             charter: Some("cool charter bro".to_string()),
         },
         SubDao {
-            addr: "subdao005".to_string(),
+            addr: subdaos[2].to_string(),
             charter: None,
         },
         SubDao {
-            addr: "subdao007".to_string(),
+            addr: subdaos[3].to_string(),
             charter: None,
         },
     ];
@@ -3006,7 +3325,7 @@ fn test_add_remove_subdaos() {
 
     assert_eq!(res.len(), 4);
 
-    let to_remove: Vec<String> = vec!["subdao005".to_string()];
+    let to_remove: Vec<String> = vec![subdaos[2].to_string()];
 
     app.execute_contract(
         Addr::unchecked(core_addr.clone()),
@@ -3033,7 +3352,7 @@ fn test_add_remove_subdaos() {
     assert_eq!(res.len(), 3);
 
     let test_res: SubDao = SubDao {
-        addr: "subdao002".to_string(),
+        addr: subdaos[1].to_string(),
         charter: Some("cool charter bro".to_string()),
     };
 
@@ -3041,15 +3360,15 @@ fn test_add_remove_subdaos() {
 
     let full_result_set: Vec<SubDao> = vec![
         SubDao {
-            addr: "subdao001".to_string(),
+            addr: subdaos[0].to_string(),
             charter: None,
         },
         SubDao {
-            addr: "subdao002".to_string(),
+            addr: subdaos[1].to_string(),
             charter: Some("cool charter bro".to_string()),
         },
         SubDao {
-            addr: "subdao007".to_string(),
+            addr: subdaos[3].to_string(),
             charter: None,
         },
     ];
@@ -3058,29 +3377,1960 @@ fn test_add_remove_subdaos() {
 }
 
 #[test]
-pub fn test_migrate_update_version() {
-    let mut deps = mock_dependencies();
-    cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
-    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
-    let version = cw2::get_contract_version(&deps.storage).unwrap();
-    assert_eq!(version.version, CONTRACT_VERSION);
-    assert_eq!(version.contract, CONTRACT_NAME);
-}
+fn test_sub_dao_hierarchy() {
+    let (parent, mut app) = do_standard_instantiate(false, None);
+    let child = instantiate_standard_dao(&mut app, false, Some(parent.to_string()));
+    let grandchild = instantiate_standard_dao(&mut app, false, Some(child.to_string()));
 
-#[test]
-fn test_query_info() {
-    let (core_addr, app) = do_standard_instantiate(true, None);
-    let res: InfoResponse = app
+    let add_sub_dao = |app: &mut App, dao: &Addr, sub_dao: &Addr| {
+        app.execute_contract(
+            dao.clone(),
+            dao.clone(),
+            &ExecuteMsg::UpdateSubDaos {
+                to_add: vec![SubDao {
+                    addr: sub_dao.to_string(),
+                    charter: None,
+                }],
+                to_remove: vec![],
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    let query_parent = |app: &App, dao: &Addr| -> Option<Addr> {
+        app.wrap()
+            .query_wasm_smart(dao, &QueryMsg::ParentDao {})
+            .unwrap()
+    };
+
+    // Adding a SubDAO records the parent on the SubDAO if the parent
+    // is its admin.
+    add_sub_dao(&mut app, &parent, &child);
+    add_sub_dao(&mut app, &child, &grandchild);
+    assert_eq!(query_parent(&app, &parent), None);
+    assert_eq!(query_parent(&app, &child), Some(parent.clone()));
+    assert_eq!(query_parent(&app, &grandchild), Some(child.clone()));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            child.clone(),
+            &ExecuteMsg::UpdateParentDao { parent: None },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let ancestors: Vec<Addr> = app
         .wrap()
-        .query_wasm_smart(core_addr, &QueryMsg::Info {})
+        .query_wasm_smart(&grandchild, &QueryMsg::DaoAncestors {})
+        .unwrap();
+    assert_eq!(ancestors, vec![child.clone(), parent.clone()]);
+
+    let tree: Vec<SubDaoTreeNode> = app
+        .wrap()
+        .query_wasm_smart(&parent, &QueryMsg::SubDaoTree { depth: None })
         .unwrap();
     assert_eq!(
-        res,
-        InfoResponse {
-            info: ContractVersion {
-                contract: CONTRACT_NAME.to_string(),
-                version: CONTRACT_VERSION.to_string()
-            }
-        }
-    )
+        tree,
+        vec![
+            SubDaoTreeNode {
+                addr: child.clone(),
+                charter: None,
+                parent: parent.clone(),
+                depth: 1,
+            },
+            SubDaoTreeNode {
+                addr: grandchild.clone(),
+                charter: None,
+                parent: child.clone(),
+                depth: 2,
+            },
+        ]
+    );
+    let tree: Vec<SubDaoTreeNode> = app
+        .wrap()
+        .query_wasm_smart(&parent, &QueryMsg::SubDaoTree { depth: Some(1) })
+        .unwrap();
+    assert_eq!(tree.len(), 1);
+
+    // Parent initiated actions.
+    test_unauthorized(
+        &mut app,
+        parent.clone(),
+        ExecuteMsg::ExecuteSubDaoAction {
+            address: child.to_string(),
+            action: SubDaoAction::NominateAdmin { admin: None },
+        },
+    );
+    let err: ContractError = app
+        .execute_contract(
+            parent.clone(),
+            parent.clone(),
+            &ExecuteMsg::ExecuteSubDaoAction {
+                address: grandchild.to_string(),
+                action: SubDaoAction::NominateAdmin { admin: None },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NotASubDao {
+            address: grandchild.clone()
+        }
+    );
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: parent.to_string(),
+        amount: coins(100, "ujuno"),
+    }))
+    .unwrap();
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::ExecuteSubDaoAction {
+            address: child.to_string(),
+            action: SubDaoAction::Fund {
+                funds: coins(40, "ujuno"),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let balance = app.wrap().query_balance(&child, "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(40));
+
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::ExecuteSubDaoAction {
+            address: child.to_string(),
+            action: SubDaoAction::Pause {
+                duration: Duration::Height(10),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(&child, &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(
+        paused,
+        PauseInfoResponse::Paused {
+            expiration: Expiration::AtHeight(app.block_info().height + 10)
+        }
+    );
+    app.execute_contract(parent.clone(), child.clone(), &ExecuteMsg::Unpause {}, &[])
+        .unwrap();
+
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::ExecuteSubDaoAction {
+            address: child.to_string(),
+            action: SubDaoAction::NominateAdmin {
+                admin: Some("ekez".to_string()),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let nomination: AdminNominationResponse = app
+        .wrap()
+        .query_wasm_smart(&child, &QueryMsg::AdminNomination {})
+        .unwrap();
+    assert_eq!(nomination.nomination, Some(Addr::unchecked("ekez")));
+
+    // Removing a SubDAO clears its parent.
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::UpdateSubDaos {
+            to_add: vec![],
+            to_remove: vec![child.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_parent(&app, &child), None);
+    let ancestors: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(&grandchild, &QueryMsg::DaoAncestors {})
+        .unwrap();
+    assert_eq!(ancestors, vec![child]);
+}
+
+#[test]
+fn test_remove_paused_sub_dao() {
+    let (parent, mut app) = do_standard_instantiate(false, None);
+    let child = instantiate_standard_dao(&mut app, false, Some(parent.to_string()));
+
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::UpdateSubDaos {
+            to_add: vec![SubDao {
+                addr: child.to_string(),
+                charter: None,
+            }],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::ExecuteSubDaoAction {
+            address: child.to_string(),
+            action: SubDaoAction::Pause {
+                duration: Duration::Height(10),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The paused SubDAO rejects the update of its parent, but is
+    // removed regardless.
+    app.execute_contract(
+        parent.clone(),
+        parent.clone(),
+        &ExecuteMsg::UpdateSubDaos {
+            to_add: vec![],
+            to_remove: vec![child.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    let sub_daos: Vec<SubDao> = app
+        .wrap()
+        .query_wasm_smart(
+            &parent,
+            &QueryMsg::ListSubDaos {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(sub_daos.is_empty());
+    let child_parent: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(&child, &QueryMsg::ParentDao {})
+        .unwrap();
+    assert_eq!(child_parent, Some(parent));
+}
+
+#[test]
+pub fn test_migrate_update_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    let version = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(version.version, CONTRACT_VERSION);
+    assert_eq!(version.contract, CONTRACT_NAME);
+}
+
+#[test]
+fn test_query_info() {
+    let (core_addr, app) = do_standard_instantiate(true, None);
+    let res: InfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr, &QueryMsg::Info {})
+        .unwrap();
+    assert_eq!(
+        res,
+        InfoResponse {
+            info: ContractVersion {
+                contract: CONTRACT_NAME.to_string(),
+                version: CONTRACT_VERSION.to_string()
+            }
+        }
+    )
+}
+
+#[test]
+fn test_timelock() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let start_height = app.block_info().height;
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::UpdateTimelock {
+            delay: Some(Duration::Height(10)),
+            guardian: None,
+        },
+    );
+
+    // No timelock is set so this executes immediately.
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&ExecuteMsg::UpdateTimelock {
+                    delay: Some(Duration::Height(10)),
+                    guardian: Some("guardian".to_string()),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let timelock: Option<TimelockConfig> = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::TimelockConfig {})
+        .unwrap();
+    assert_eq!(
+        timelock,
+        Some(TimelockConfig {
+            delay: Duration::Height(10),
+            guardian: Some(Addr::unchecked("guardian")),
+        })
+    );
+
+    let new_config = Config {
+        dao_uri: None,
+        name: "Timelocked".to_string(),
+        description: "A DAO with a timelock.".to_string(),
+        image_url: None,
+        automatically_add_cw20s: false,
+        automatically_add_cw721s: false,
+        automatically_add_native_denoms: false,
+        pause_guardian: None,
+    };
+    let update_config: CosmosMsg = WasmMsg::Execute {
+        contract_addr: core_addr.to_string(),
+        msg: to_binary(&ExecuteMsg::UpdateConfig {
+            config: new_config.clone(),
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into();
+
+    // Proposal module messages are now queued.
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![update_config.clone()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.name, "DAO DAO");
+
+    let operations: Vec<TimelockedOperation> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ListTimelockedOperations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let expected = TimelockedOperation {
+        id: 1,
+        proposal_module: proposal_module.address.clone(),
+        msgs: vec![update_config.clone()],
+        executable_at: Expiration::AtHeight(start_height + 10),
+    };
+    assert_eq!(operations, vec![expected.clone()]);
+
+    let operation: TimelockedOperation = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::TimelockedOperation { id: 1 })
+        .unwrap();
+    assert_eq!(operation, expected);
+
+    // Can not be executed before the delay has passed.
+    app.update_block(|block| block.height += 9);
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteTimelockedOperation { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::TimelockNotExpired {
+            id: 1,
+            executable_at: Expiration::AtHeight(start_height + 10),
+        }
+    );
+
+    // Anyone may execute the operation once the delay has passed.
+    app.update_block(|block| block.height += 1);
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteTimelockedOperation { id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config, new_config);
+
+    // Operations may only be executed once.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteTimelockedOperation { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::TimelockedOperationNotFound { id: 1 });
+
+    // Queue another operation and have the guardian cancel it.
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![update_config.clone()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::CancelTimelockedOperation { id: 2 },
+    );
+
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        core_addr.clone(),
+        &ExecuteMsg::CancelTimelockedOperation { id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    let operations: Vec<TimelockedOperation> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ListTimelockedOperations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(operations.is_empty());
+
+    app.update_block(|block| block.height += 10);
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteTimelockedOperation { id: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::TimelockedOperationNotFound { id: 2 });
+
+    // A proposal cancelling a queued operation is not itself queued.
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![update_config],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        proposal_module.address,
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&ExecuteMsg::CancelTimelockedOperation { id: 3 }).unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let operations: Vec<TimelockedOperation> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &QueryMsg::ListTimelockedOperations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(operations.is_empty());
+}
+
+#[test]
+fn test_stargate_allowlist() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::UpdateStargateAllowlist {
+            to_add: vec!["/osmosis.tokenfactory.v1beta1.MsgMint".to_string()],
+            to_remove: vec![],
+            enforce: Some(true),
+        },
+    );
+
+    let mint: CosmosMsg = CosmosMsg::Stargate {
+        type_url: "/osmosis.tokenfactory.v1beta1.MsgMint".to_string(),
+        value: Binary::default(),
+    };
+
+    // Without an enforced allowlist Stargate messages are passed
+    // through to the chain. cw-multi-test does not support executing
+    // them, so this errors outside of the core contract.
+    let err = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![mint.clone()],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.downcast_ref::<ContractError>().is_none());
+
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&ExecuteMsg::UpdateStargateAllowlist {
+                    to_add: vec![
+                        "/osmosis.tokenfactory.v1beta1.MsgMint".to_string(),
+                        "/osmosis.tokenfactory.v1beta1.MsgBurn".to_string(),
+                    ],
+                    to_remove: vec![],
+                    enforce: Some(true),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let allowlist: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::StargateAllowlist {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        allowlist,
+        vec![
+            "/osmosis.tokenfactory.v1beta1.MsgBurn".to_string(),
+            "/osmosis.tokenfactory.v1beta1.MsgMint".to_string(),
+        ]
+    );
+
+    let enforced: bool = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::StargateAllowlistEnforced {})
+        .unwrap();
+    assert!(enforced);
+
+    // Messages with other type URLs are still rejected, even when
+    // sent alongside allowlisted ones.
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![
+                    mint.clone(),
+                    CosmosMsg::Stargate {
+                        type_url: "/cosmos.gov.v1beta1.MsgVote".to_string(),
+                        value: Binary::default(),
+                    },
+                ],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::StargateMsgNotAllowed {
+            type_url: "/cosmos.gov.v1beta1.MsgVote".to_string()
+        }
+    );
+
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_binary(&ExecuteMsg::UpdateStargateAllowlist {
+                    to_add: vec![],
+                    to_remove: vec!["/osmosis.tokenfactory.v1beta1.MsgBurn".to_string()],
+                    enforce: None,
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let allowlist: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::StargateAllowlist {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        allowlist,
+        vec!["/osmosis.tokenfactory.v1beta1.MsgMint".to_string()]
+    );
+
+    // The allowlist also applies to the admin.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteAdminMsgs {
+                msgs: vec![CosmosMsg::Stargate {
+                    type_url: "/osmosis.tokenfactory.v1beta1.MsgBurn".to_string(),
+                    value: Binary::default(),
+                }],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::StargateMsgNotAllowed {
+            type_url: "/osmosis.tokenfactory.v1beta1.MsgBurn".to_string()
+        }
+    );
+
+    // An enforced allowlist with no entries blocks all Stargate
+    // messages.
+    let update_allowlist = |app: &mut App, to_remove: Vec<String>, enforce: Option<bool>| {
+        app.execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: core_addr.to_string(),
+                    msg: to_binary(&ExecuteMsg::UpdateStargateAllowlist {
+                        to_add: vec![],
+                        to_remove,
+                        enforce,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    update_allowlist(
+        &mut app,
+        vec!["/osmosis.tokenfactory.v1beta1.MsgMint".to_string()],
+        None,
+    );
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![mint.clone()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::StargateMsgNotAllowed {
+            type_url: "/osmosis.tokenfactory.v1beta1.MsgMint".to_string()
+        }
+    );
+
+    // Once no longer enforced, Stargate messages are passed through
+    // again.
+    update_allowlist(&mut app, vec![], Some(false));
+    let err = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook { msgs: vec![mint] },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.downcast_ref::<ContractError>().is_none());
+}
+
+#[test]
+fn test_proposal_module_permissions() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(1000, "ujuno"),
+    }))
+    .unwrap();
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let permissions = ModulePermissions {
+        allowed_contracts: vec![],
+        spend_limits: vec![SpendLimit {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(100),
+            period: Duration::Height(10),
+        }],
+    };
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::UpdateProposalModulePermissions {
+            address: proposal_module.address.to_string(),
+            permissions: Some(permissions.clone()),
+        },
+    );
+
+    // Only registered proposal modules may have permissions.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateProposalModulePermissions {
+                address: "notamodule".to_string(),
+                permissions: Some(permissions.clone()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ProposalModuleDoesNotExist {
+            address: Addr::unchecked("notamodule")
+        }
+    );
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModulePermissions {
+            address: proposal_module.address.to_string(),
+            permissions: Some(permissions.clone()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: Option<ModulePermissions> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModulePermissions {
+                address: proposal_module.address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Some(permissions));
+
+    let send = |amount: u128, denom: &str| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: coins(amount, denom),
+        }
+        .into()
+    };
+    let execute_proposal_hook = |app: &mut App, msgs: Vec<CosmosMsg>| {
+        app.execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook { msgs },
+            &[],
+        )
+    };
+
+    // Spending within the limit is allowed.
+    execute_proposal_hook(&mut app, vec![send(60, "ujuno")]).unwrap();
+    let balance = app.wrap().query_balance("recipient", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(60));
+
+    let spending: Vec<ModuleSpending> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModuleSpending {
+                address: proposal_module.address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        spending,
+        vec![ModuleSpending {
+            denom: "ujuno".to_string(),
+            spent: Uint128::new(60),
+            resets_at: Expiration::AtHeight(app.block_info().height + 10),
+        }]
+    );
+
+    // Spending over the limit is not.
+    let err: ContractError = execute_proposal_hook(&mut app, vec![send(41, "ujuno")])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            module: proposal_module.address.clone(),
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(100),
+        }
+    );
+
+    // Denoms without a limit may not be spent.
+    let err: ContractError = execute_proposal_hook(&mut app, vec![send(1, "uatom")])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            module: proposal_module.address.clone(),
+            denom: "uatom".to_string(),
+            limit: Uint128::zero(),
+        }
+    );
+
+    // Contracts not in the module's allowlist may not be called.
+    let err: ContractError = execute_proposal_hook(
+        &mut app,
+        vec![WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_binary(&ExecuteMsg::UpdateProposalModulePermissions {
+                address: proposal_module.address.to_string(),
+                permissions: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ContractNotPermitted {
+            module: proposal_module.address.clone(),
+            contract: core_addr.to_string(),
+        }
+    );
+
+    // Other message types are not permitted.
+    let err: ContractError = execute_proposal_hook(
+        &mut app,
+        vec![BankMsg::Burn {
+            amount: coins(1, "ujuno"),
+        }
+        .into()],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::MessageNotPermitted {
+            module: proposal_module.address.clone(),
+        }
+    );
+
+    // The limit resets once the period ends.
+    app.update_block(|block| block.height += 10);
+    execute_proposal_hook(&mut app, vec![send(100, "ujuno")]).unwrap();
+    let balance = app.wrap().query_balance("recipient", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(160));
+
+    // Removing the module's permissions gives it unlimited authority.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateProposalModulePermissions {
+            address: proposal_module.address.to_string(),
+            permissions: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let spending: Vec<ModuleSpending> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModuleSpending {
+                address: proposal_module.address.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(spending.is_empty());
+
+    execute_proposal_hook(&mut app, vec![send(500, "ujuno")]).unwrap();
+    let balance = app.wrap().query_balance("recipient", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(660));
+}
+
+#[test]
+fn test_proposal_module_permissions_timelock() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(1000, "ujuno"),
+    }))
+    .unwrap();
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let set_permissions = |app: &mut App, permissions: ModulePermissions| {
+        app.execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateProposalModulePermissions {
+                address: proposal_module.address.to_string(),
+                permissions: Some(permissions),
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    let query_spending = |app: &App| -> Vec<ModuleSpending> {
+        app.wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::ProposalModuleSpending {
+                    address: proposal_module.address.to_string(),
+                },
+            )
+            .unwrap()
+    };
+    let execute_proposal_hook = |app: &mut App, msgs: Vec<CosmosMsg>| {
+        app.execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook { msgs },
+            &[],
+        )
+    };
+
+    set_permissions(
+        &mut app,
+        ModulePermissions {
+            allowed_contracts: vec!["token".to_string()],
+            spend_limits: vec![SpendLimit {
+                denom: "ujuno".to_string(),
+                amount: Uint128::new(100),
+                period: Duration::Height(100),
+            }],
+        },
+    );
+
+    // cw20 transfers count towards spend limits, keyed by the token's
+    // address.
+    let err: ContractError = execute_proposal_hook(
+        &mut app,
+        vec![WasmMsg::Execute {
+            contract_addr: "token".to_string(),
+            msg: to_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                recipient: "recipient".to_string(),
+                amount: Uint128::new(1),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            module: proposal_module.address.clone(),
+            denom: "token".to_string(),
+            limit: Uint128::zero(),
+        }
+    );
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateTimelock {
+            delay: Some(Duration::Height(10)),
+            guardian: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let send: CosmosMsg = BankMsg::Send {
+        to_address: "recipient".to_string(),
+        amount: coins(60, "ujuno"),
+    }
+    .into();
+
+    // Spending is not charged when an operation is queued, so
+    // cancelled operations do not use up the limit.
+    execute_proposal_hook(&mut app, vec![send.clone()]).unwrap();
+    assert!(query_spending(&app).is_empty());
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::CancelTimelockedOperation { id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    execute_proposal_hook(&mut app, vec![send.clone()]).unwrap();
+    execute_proposal_hook(&mut app, vec![send]).unwrap();
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteTimelockedOperation { id: 2 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_spending(&app),
+        vec![ModuleSpending {
+            denom: "ujuno".to_string(),
+            spent: Uint128::new(60),
+            resets_at: Expiration::AtHeight(app.block_info().height + 100),
+        }]
+    );
+
+    // Permissions are checked again when the operation is executed.
+    set_permissions(
+        &mut app,
+        ModulePermissions {
+            allowed_contracts: vec![],
+            spend_limits: vec![],
+        },
+    );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteTimelockedOperation { id: 3 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SpendLimitExceeded {
+            module: proposal_module.address,
+            denom: "ujuno".to_string(),
+            limit: Uint128::zero(),
+        }
+    );
+    let balance = app.wrap().query_balance("recipient", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(60));
+}
+
+#[test]
+fn test_native_treasury() {
+    let (gov_addr, mut app) = do_standard_instantiate(false, None);
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: CREATOR_ADDR.to_string(),
+        amount: coins(100, "ujuno"),
+    }))
+    .unwrap();
+
+    // Funds sent with an execute message are not added to the
+    // treasury unless the DAO has opted in.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        gov_addr.clone(),
+        &ExecuteMsg::ReceiveNft(cw721::Cw721ReceiveMsg {
+            sender: CREATOR_ADDR.to_string(),
+            token_id: "ekez".to_string(),
+            msg: to_binary("").unwrap(),
+        }),
+        &coins(50, "ujuno"),
+    )
+    .unwrap();
+
+    let native_list: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::NativeTokenList {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(native_list.is_empty());
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(gov_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::UpdateConfig {
+            config: Config {
+                automatically_add_native_denoms: true,
+                ..config
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Now funds sent with an execute message are added to the
+    // treasury.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        gov_addr.clone(),
+        &ExecuteMsg::ReceiveNft(cw721::Cw721ReceiveMsg {
+            sender: CREATOR_ADDR.to_string(),
+            token_id: "ekez".to_string(),
+            msg: to_binary("").unwrap(),
+        }),
+        &coins(50, "ujuno"),
+    )
+    .unwrap();
+
+    let native_list: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::NativeTokenList {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(native_list, vec!["ujuno".to_string()]);
+
+    test_unauthorized(
+        &mut app,
+        gov_addr.clone(),
+        ExecuteMsg::UpdateNativeList {
+            to_add: vec!["uatom".to_string()],
+            to_remove: vec![],
+        },
+    );
+
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::UpdateNativeList {
+            to_add: vec!["uatom".to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balances: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::NativeBalances {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(balances, vec![coin(100, "ujuno"), coin(0, "uatom")]);
+
+    app.execute_contract(
+        gov_addr.clone(),
+        gov_addr.clone(),
+        &ExecuteMsg::UpdateNativeList {
+            to_add: vec![],
+            to_remove: vec!["ujuno".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balances: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr,
+            &QueryMsg::NativeBalances {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(balances, vec![coin(0, "uatom")]);
+}
+
+#[test]
+fn test_cw721_holdings() {
+    let (gov_addr, mut app) = do_standard_instantiate(false, None);
+
+    let cw721_id = app.store_code(cw721_contract());
+    let cw721_addr = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw721_base::msg::InstantiateMsg {
+                name: "ekez".to_string(),
+                symbol: "ekez".to_string(),
+                minter: CREATOR_ADDR.to_string(),
+            },
+            &[],
+            "cw721",
+            None,
+        )
+        .unwrap();
+
+    for (token_id, owner) in [
+        ("1", gov_addr.as_str()),
+        ("2", CREATOR_ADDR),
+        ("3", gov_addr.as_str()),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            cw721_addr.clone(),
+            &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::Mint(cw721_base::msg::MintMsg::<
+                Option<Empty>,
+            > {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: None,
+            }),
+            &[],
+        )
+        .unwrap();
+    }
+
+    let holdings: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr.clone(),
+            &QueryMsg::Cw721Holdings {
+                collection: cw721_addr.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(holdings, vec!["1".to_string(), "3".to_string()]);
+
+    let holdings: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            gov_addr,
+            &QueryMsg::Cw721Holdings {
+                collection: cw721_addr.to_string(),
+                start_after: Some("1".to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(holdings, vec!["3".to_string()]);
+}
+
+#[test]
+fn test_pause_guardian() {
+    let (core_addr, mut app) = do_standard_instantiate(false, Some("admin".to_string()));
+    let start_height = app.block_info().height;
+
+    let config = Config {
+        dao_uri: None,
+        name: "Guarded".to_string(),
+        description: "A DAO with a pause guardian.".to_string(),
+        image_url: None,
+        automatically_add_cw20s: false,
+        automatically_add_cw721s: false,
+        automatically_add_native_denoms: false,
+        pause_guardian: Some(Addr::unchecked("guardian")),
+    };
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateConfig {
+            config: config.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Can't unpause a DAO that isn't paused.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("guardian"),
+            core_addr.clone(),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotPaused {});
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::Pause {
+            duration: Duration::Height(10),
+        },
+    );
+
+    // The guardian may pause without a proposal.
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(10),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(
+        paused,
+        PauseInfoResponse::Paused {
+            expiration: Expiration::AtHeight(start_height + 10)
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::UpdateConfig {
+                config: config.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Paused {});
+
+    test_unauthorized(&mut app, core_addr.clone(), ExecuteMsg::Unpause {});
+
+    // The admin may lift the pause early.
+    app.execute_contract(
+        Addr::unchecked("admin"),
+        core_addr.clone(),
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateConfig {
+            config: config.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // As may the guardian, once its cooldown has passed.
+    app.update_block(|block| block.height += 20);
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(5),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        core_addr.clone(),
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+
+    let history: Vec<PauseRecord> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::PauseHistory {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        history,
+        vec![
+            PauseRecord {
+                id: 2,
+                paused_by: Addr::unchecked("guardian"),
+                paused_at: start_height + 20,
+                until: Expiration::AtHeight(start_height + 25),
+                unpaused_by: Some(Addr::unchecked("guardian")),
+            },
+            PauseRecord {
+                id: 1,
+                paused_by: Addr::unchecked("guardian"),
+                paused_at: start_height,
+                until: Expiration::AtHeight(start_height + 10),
+                unpaused_by: Some(Addr::unchecked("admin")),
+            },
+        ]
+    );
+
+    let history: Vec<PauseRecord> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::PauseHistory {
+                start_after: Some(2),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].id, 1);
+
+    // The guardian may not lift a pause started by the DAO.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(10),
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("guardian"),
+            core_addr.clone(),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::GuardianUnpause {});
+    app.execute_contract(
+        Addr::unchecked("admin"),
+        core_addr,
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_pause_guardian_recovery() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let start_height = app.block_info().height;
+
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_module = proposal_modules.into_iter().next().unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    let guarded = Config {
+        pause_guardian: Some(Addr::unchecked("guardian")),
+        ..config.clone()
+    };
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateConfig {
+            config: guarded.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The guardian's pauses are limited in length.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("guardian"),
+            core_addr.clone(),
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(100_801),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::GuardianPauseTooLong {
+            max: Duration::Height(100_800)
+        }
+    );
+
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        core_addr.clone(),
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(100_800),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let self_msg = |msg: &ExecuteMsg| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: core_addr.to_string(),
+            msg: to_binary(msg).unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+
+    // Other proposals may not be executed while paused.
+    let err: ContractError = app
+        .execute_contract(
+            proposal_module.address.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![self_msg(&ExecuteMsg::UpdateConfig {
+                    config: Config {
+                        pause_guardian: Some(Addr::unchecked("other")),
+                        ..config.clone()
+                    },
+                })],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Paused {});
+
+    // The DAO may remove the guardian and lift the pause.
+    app.execute_contract(
+        proposal_module.address.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![
+                self_msg(&ExecuteMsg::UpdateConfig {
+                    config: config.clone(),
+                }),
+                self_msg(&ExecuteMsg::Unpause {}),
+            ],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let paused: PauseInfoResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::PauseInfo {})
+        .unwrap();
+    assert_eq!(paused, PauseInfoResponse::Unpaused {});
+    let current: Config = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(current, config);
+
+    // A guardian must wait out its cooldown before pausing again.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::UpdateConfig { config: guarded },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("guardian"),
+            core_addr.clone(),
+            &ExecuteMsg::Pause {
+                duration: Duration::Height(10),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::GuardianPauseCooldown {
+            until: Expiration::AtHeight(start_height + 201_600)
+        }
+    );
+
+    app.update_block(|block| block.height += 201_600);
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        core_addr,
+        &ExecuteMsg::Pause {
+            duration: Duration::Height(10),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_delegated_execution() {
+    let (core_addr, mut app) = do_standard_instantiate(false, None);
+    let start_height = app.block_info().height;
+
+    let create_grant = ExecuteMsg::CreateGrant {
+        grantee: "grantee".to_string(),
+        contract: core_addr.to_string(),
+        msg_type: Some("set_item".to_string()),
+        max_uses: Some(2),
+        expiration: Expiration::AtHeight(start_height + 10),
+    };
+    test_unauthorized(&mut app, core_addr.clone(), create_grant.clone());
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::CreateGrant {
+                grantee: "grantee".to_string(),
+                contract: core_addr.to_string(),
+                msg_type: None,
+                max_uses: None,
+                expiration: Expiration::AtHeight(start_height),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::GrantExpired {});
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::CreateGrant {
+                grantee: "grantee".to_string(),
+                contract: "payroll".to_string(),
+                msg_type: None,
+                max_uses: Some(0),
+                expiration: Expiration::AtHeight(start_height + 10),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidGrantUses {});
+
+    // Governance on the DAO itself may not be delegated.
+    for msg_type in [None, Some("update_config".to_string())] {
+        let err: ContractError = app
+            .execute_contract(
+                core_addr.clone(),
+                core_addr.clone(),
+                &ExecuteMsg::CreateGrant {
+                    grantee: "grantee".to_string(),
+                    contract: core_addr.to_string(),
+                    msg_type,
+                    max_uses: None,
+                    expiration: Expiration::AtHeight(start_height + 10),
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::UndelegableMsg {});
+    }
+
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &create_grant, &[])
+        .unwrap();
+
+    let list_grants = |app: &App| -> Vec<Grant> {
+        app.wrap()
+            .query_wasm_smart(
+                core_addr.clone(),
+                &QueryMsg::ListGrants {
+                    grantee: "grantee".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    };
+    assert_eq!(
+        list_grants(&app),
+        vec![Grant {
+            id: 1,
+            grantee: Addr::unchecked("grantee"),
+            contract: core_addr.clone(),
+            msg_type: Some("set_item".to_string()),
+            remaining_uses: Some(2),
+            expiration: Expiration::AtHeight(start_height + 10),
+        }]
+    );
+
+    let delegate = |app: &mut App, msg: &ExecuteMsg, funds: Vec<Coin>| {
+        app.execute_contract(
+            Addr::unchecked("grantee"),
+            core_addr.clone(),
+            &ExecuteMsg::ExecuteDelegated {
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: core_addr.to_string(),
+                    msg: to_binary(msg).unwrap(),
+                    funds,
+                }
+                .into()],
+            },
+            &[],
+        )
+    };
+    let set_item = ExecuteMsg::SetItem {
+        key: "payroll".to_string(),
+        value: "topped up".to_string(),
+    };
+
+    // Messages of other types are not covered by the grant.
+    let err: ContractError = delegate(
+        &mut app,
+        &ExecuteMsg::RemoveItem {
+            key: "payroll".to_string(),
+        },
+        vec![],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NoMatchingGrant {
+            grantee: Addr::unchecked("grantee"),
+            contract: core_addr.to_string(),
+        }
+    );
+
+    // Delegated messages may not send funds.
+    let err: ContractError = delegate(&mut app, &set_item, coins(1, "ujuno"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidDelegatedMsg {});
+
+    delegate(&mut app, &set_item, vec![]).unwrap();
+    let item = get_item(&mut app, core_addr.clone(), "payroll".to_string());
+    assert_eq!(item.item, Some("topped up".to_string()));
+    assert_eq!(list_grants(&app)[0].remaining_uses, Some(1));
+
+    // The grant is removed once used up.
+    delegate(&mut app, &set_item, vec![]).unwrap();
+    assert!(list_grants(&app).is_empty());
+    let err: ContractError = delegate(&mut app, &set_item, vec![])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NoMatchingGrant { .. }));
+
+    // Grants may be revoked by governance.
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::CreateGrant {
+            grantee: "grantee".to_string(),
+            contract: core_addr.to_string(),
+            msg_type: None,
+            max_uses: None,
+            expiration: Expiration::AtHeight(start_height + 10),
+        },
+        &[],
+    )
+    .unwrap();
+    delegate(&mut app, &set_item, vec![]).unwrap();
+
+    test_unauthorized(
+        &mut app,
+        core_addr.clone(),
+        ExecuteMsg::RevokeGrant {
+            grantee: "grantee".to_string(),
+            id: 2,
+        },
+    );
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &ExecuteMsg::RevokeGrant {
+            grantee: "grantee".to_string(),
+            id: 2,
+        },
+        &[],
+    )
+    .unwrap();
+    assert!(list_grants(&app).is_empty());
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            core_addr.clone(),
+            &ExecuteMsg::RevokeGrant {
+                grantee: "grantee".to_string(),
+                id: 2,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::GrantNotFound {
+            grantee: Addr::unchecked("grantee"),
+            id: 2
+        }
+    );
+
+    // Expired grants may not be used.
+    app.execute_contract(core_addr.clone(), core_addr.clone(), &create_grant, &[])
+        .unwrap();
+    app.update_block(|block| block.height += 10);
+    assert!(list_grants(&app).is_empty());
+    let err: ContractError = delegate(&mut app, &set_item, vec![])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NoMatchingGrant { .. }));
 }
@@ -1,27 +1,42 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, SubMsg, WasmMsg,
+    to_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Reply,
+    Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 use cw_utils::parse_reply_instantiate_data;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{
+    ExecuteMsg, FeeInfo, InstantiateMsg, MigrateMsg, QueryMsg, SubDaoInstantiateInfo,
+};
+use crate::state::{
+    created_contracts, CreatedContract, InstantiationFee, PendingInstantiation, FEE,
+    PENDING_INSTANTIATIONS, TMP_SUB_DAOS,
+};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-admin-factory";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const INSTANTIATE_CONTRACT_REPLY_ID: u64 = 0;
+pub const INSTANTIATE_PARENT_DAO_REPLY_ID: u64 = 1;
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 50;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    _msg: InstantiateMsg,
+    msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    cw_ownable::initialize_owner(deps.storage, deps.api, msg.owner.as_deref())?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    if let Some(fee) = msg.fee {
+        FEE.save(deps.storage, &validate_fee(deps.as_ref(), fee)?)?;
+    }
     Ok(Response::new()
         .add_attribute("method", "instantiate")
         .add_attribute("creator", info.sender))
@@ -29,53 +44,288 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    _deps: DepsMut,
+    deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // Replies are matched to instantiations in the order they were
+    // dispatched, which reentering the factory would break.
+    if !PENDING_INSTANTIATIONS.is_empty(deps.storage)? {
+        return Err(ContractError::Reentrancy);
+    }
+
     match msg {
         ExecuteMsg::InstantiateContractWithSelfAdmin {
             instantiate_msg: msg,
             code_id,
             label,
-        } => instantiate_contract(env, info, msg, code_id, label),
+        } => instantiate_contract(deps, env, info, msg, code_id, label),
+        ExecuteMsg::InstantiateDaoWithSubDaos {
+            instantiate_msg,
+            code_id,
+            label,
+            sub_daos,
+        } => instantiate_dao_with_sub_daos(
+            deps,
+            env,
+            info,
+            instantiate_msg,
+            code_id,
+            label,
+            sub_daos,
+        ),
+        ExecuteMsg::UpdateFee { fee } => execute_update_fee(deps, info, fee),
+        ExecuteMsg::UpdateOwnership(action) => execute_update_owner(deps, info, env, action),
     }
 }
 
 pub fn instantiate_contract(
+    deps: DepsMut,
     env: Env,
     info: MessageInfo,
     instantiate_msg: Binary,
     code_id: u64,
     label: String,
 ) -> Result<Response, ContractError> {
+    let (fee_msg, funds) = take_fee(deps.as_ref(), info.funds, 1)?;
+    let msg = dispatch_instantiation(
+        deps,
+        &env,
+        info.sender,
+        instantiate_msg,
+        code_id,
+        label,
+        funds,
+        INSTANTIATE_CONTRACT_REPLY_ID,
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate_cw_core")
+        .add_messages(fee_msg)
+        .add_submessage(msg))
+}
+
+pub fn instantiate_dao_with_sub_daos(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    instantiate_msg: Binary,
+    code_id: u64,
+    label: String,
+    sub_daos: Vec<SubDaoInstantiateInfo>,
+) -> Result<Response, ContractError> {
+    let (fee_msg, funds) = take_fee(deps.as_ref(), info.funds, sub_daos.len() as u64 + 1)?;
+
+    // The SubDAOs are instantiated once the parent's address is known.
+    TMP_SUB_DAOS.save(deps.storage, &sub_daos)?;
+
+    let msg = dispatch_instantiation(
+        deps,
+        &env,
+        info.sender,
+        instantiate_msg,
+        code_id,
+        label,
+        funds,
+        INSTANTIATE_PARENT_DAO_REPLY_ID,
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate_dao_with_sub_daos")
+        .add_attribute("sub_dao_count", sub_daos.len().to_string())
+        .add_messages(fee_msg)
+        .add_submessage(msg))
+}
+
+/// Returns a submessage instantiating the specified contract with
+/// the factory as the admin, and records the instantiation so that it
+/// may be registered when its reply is handled.
+#[allow(clippy::too_many_arguments)]
+fn dispatch_instantiation(
+    deps: DepsMut,
+    env: &Env,
+    creator: Addr,
+    instantiate_msg: Binary,
+    code_id: u64,
+    label: String,
+    funds: Vec<Coin>,
+    reply_id: u64,
+) -> Result<SubMsg, ContractError> {
+    PENDING_INSTANTIATIONS.push_back(
+        deps.storage,
+        &PendingInstantiation {
+            creator,
+            code_id,
+            label: label.clone(),
+        },
+    )?;
+
     // Instantiate the specified contract with factory as the admin.
     let instantiate = WasmMsg::Instantiate {
         admin: Some(env.contract.address.to_string()),
         code_id,
         msg: instantiate_msg,
-        funds: info.funds,
+        funds,
         label,
     };
 
-    let msg = SubMsg::reply_on_success(instantiate, INSTANTIATE_CONTRACT_REPLY_ID);
-    Ok(Response::default()
-        .add_attribute("action", "instantiate_cw_core")
-        .add_submessage(msg))
+    Ok(SubMsg::reply_on_success(instantiate, reply_id))
+}
+
+/// Takes the fee for instantiating `count` contracts out of `funds`,
+/// returning a message sending the fee to the treasury and the
+/// remaining funds.
+fn take_fee(
+    deps: Deps,
+    funds: Vec<Coin>,
+    count: u64,
+) -> Result<(Option<BankMsg>, Vec<Coin>), ContractError> {
+    let fee = match FEE.may_load(deps.storage)? {
+        Some(fee) => fee,
+        None => return Ok((None, funds)),
+    };
+
+    let expected = Coin {
+        denom: fee.amount.denom,
+        amount: fee
+            .amount
+            .amount
+            .checked_mul(Uint128::from(count))
+            .map_err(StdError::from)?,
+    };
+    let paid = funds
+        .iter()
+        .find(|coin| coin.denom == expected.denom)
+        .map_or(Uint128::zero(), |coin| coin.amount);
+    if paid < expected.amount {
+        return Err(ContractError::InsufficientFee {
+            paid: Coin {
+                denom: expected.denom,
+                amount: paid,
+            },
+            expected,
+        });
+    }
+
+    let remaining = funds
+        .into_iter()
+        .filter_map(|mut coin| {
+            if coin.denom == expected.denom {
+                coin.amount -= expected.amount;
+            }
+            (!coin.amount.is_zero()).then_some(coin)
+        })
+        .collect();
+
+    Ok((
+        Some(BankMsg::Send {
+            to_address: fee.treasury.into_string(),
+            amount: vec![expected],
+        }),
+        remaining,
+    ))
+}
+
+fn validate_fee(deps: Deps, fee: FeeInfo) -> Result<InstantiationFee, ContractError> {
+    if fee.amount.amount.is_zero() {
+        return Err(ContractError::ZeroFee {});
+    }
+    Ok(InstantiationFee {
+        amount: fee.amount,
+        treasury: deps.api.addr_validate(&fee.treasury)?,
+    })
+}
+
+pub fn execute_update_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    fee: Option<FeeInfo>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    match fee {
+        Some(fee) => FEE.save(deps.storage, &validate_fee(deps.as_ref(), fee)?)?,
+        None => FEE.remove(deps.storage),
+    }
+    Ok(Response::default().add_attribute("action", "update_fee"))
+}
+
+pub fn execute_update_owner(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    action: cw_ownable::Action,
+) -> Result<Response, ContractError> {
+    let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
+    Ok(Response::default().add_attributes(ownership.into_attributes()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(_deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {}
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ListContracts { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start = start_after.as_deref().map(Bound::exclusive);
+
+            let res: Vec<CreatedContract> = created_contracts()
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|c| c.map(|(_, c)| c))
+                .collect::<StdResult<_>>()?;
+
+            to_binary(&res)
+        }
+        QueryMsg::ListContractsByCreator {
+            creator,
+            start_after,
+            limit,
+        } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start = start_after.map(Bound::<String>::exclusive);
+
+            // Validate creator address
+            deps.api.addr_validate(&creator)?;
+
+            let res: Vec<CreatedContract> = created_contracts()
+                .idx
+                .creator
+                .prefix(creator)
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|c| c.map(|(_, c)| c))
+                .collect::<StdResult<_>>()?;
+
+            to_binary(&res)
+        }
+        QueryMsg::ListContractsByCodeId {
+            code_id,
+            start_after,
+            limit,
+        } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start = start_after.map(Bound::<String>::exclusive);
+
+            let res: Vec<CreatedContract> = created_contracts()
+                .idx
+                .code_id
+                .prefix(code_id)
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|c| c.map(|(_, c)| c))
+                .collect::<StdResult<_>>()?;
+
+            to_binary(&res)
+        }
+        QueryMsg::Fee {} => to_binary(&FEE.may_load(deps.storage)?),
+        QueryMsg::Ownership {} => to_binary(&cw_ownable::get_ownership(deps.storage)?),
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(mut deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
         INSTANTIATE_CONTRACT_REPLY_ID => {
-            let res = parse_reply_instantiate_data(msg)?;
-            let contract_addr = deps.api.addr_validate(&res.contract_address)?;
+            let (contract_addr, _) = register_instantiation(deps, msg)?;
             // Make the contract its own admin.
             let msg = WasmMsg::UpdateAdmin {
                 contract_addr: contract_addr.to_string(),
@@ -86,13 +336,75 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                 .add_attribute("set contract admin as itself", contract_addr)
                 .add_message(msg))
         }
+        INSTANTIATE_PARENT_DAO_REPLY_ID => {
+            let (parent, creator) = register_instantiation(deps.branch(), msg)?;
+            let sub_daos = TMP_SUB_DAOS.load(deps.storage)?;
+
+            // Instantiate each SubDAO with the parent as its admin.
+            let mut msgs = vec![];
+            for sub_dao in sub_daos {
+                let instantiate_msg = dao_interface::msg::InstantiateMsg {
+                    admin: Some(parent.to_string()),
+                    ..sub_dao.instantiate_msg
+                };
+                msgs.push(dispatch_instantiation(
+                    deps.branch(),
+                    &env,
+                    creator.clone(),
+                    to_binary(&instantiate_msg)?,
+                    sub_dao.code_id,
+                    sub_dao.label,
+                    vec![],
+                    INSTANTIATE_CONTRACT_REPLY_ID,
+                )?);
+            }
+            TMP_SUB_DAOS.remove(deps.storage);
+
+            Ok(Response::default()
+                .add_attribute("set contract admin as itself", parent.clone())
+                .add_message(WasmMsg::UpdateAdmin {
+                    contract_addr: parent.to_string(),
+                    admin: parent.to_string(),
+                })
+                .add_submessages(msgs))
+        }
         _ => Err(ContractError::UnknownReplyID {}),
     }
 }
 
+/// Registers the contract instantiated by the oldest pending
+/// instantiation, returning its address and creator.
+fn register_instantiation(deps: DepsMut, msg: Reply) -> Result<(Addr, Addr), ContractError> {
+    let res = parse_reply_instantiate_data(msg)?;
+    let contract_addr = deps.api.addr_validate(&res.contract_address)?;
+    let pending = PENDING_INSTANTIATIONS
+        .pop_front(deps.storage)?
+        .ok_or_else(|| StdError::not_found("pending instantiation"))?;
+
+    created_contracts().save(
+        deps.storage,
+        contract_addr.as_str(),
+        &CreatedContract {
+            contract: contract_addr.to_string(),
+            creator: pending.creator.to_string(),
+            code_id: pending.code_id,
+            label: pending.label,
+        },
+    )?;
+
+    Ok((contract_addr, pending.creator))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     // Set contract to version to latest
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Factories instantiated before ownership was introduced have no
+    // owner, so one is set here.
+    cw_ownable::initialize_owner(deps.storage, deps.api, msg.owner.as_deref())?;
+    match msg.fee {
+        Some(fee) => FEE.save(deps.storage, &validate_fee(deps.as_ref(), fee)?)?,
+        None => FEE.remove(deps.storage),
+    }
     Ok(Response::default())
 }
@@ -1,12 +1,16 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Coin, StdError};
+use cw_ownable::OwnershipError;
 use cw_utils::ParseReplyError;
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Ownable(#[from] OwnershipError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -15,4 +19,13 @@ pub enum ContractError {
 
     #[error("An unknown reply ID was received.")]
     UnknownReplyID {},
+
+    #[error("reentered factory during contract instantiation")]
+    Reentrancy,
+
+    #[error("instantiation fee must be non-zero")]
+    ZeroFee {},
+
+    #[error("insufficient instantiation fee. got ({paid}) expected ({expected})")]
+    InsufficientFee { paid: Coin, expected: Coin },
 }
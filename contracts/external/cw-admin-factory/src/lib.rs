@@ -3,6 +3,7 @@
 pub mod contract;
 mod error;
 pub mod msg;
+pub mod state;
 
 #[cfg(test)]
 mod tests;
@@ -1,9 +1,37 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Binary, Coin};
+use cw_ownable::cw_ownable_execute;
 
 #[cw_serde]
-pub struct InstantiateMsg {}
+pub struct InstantiateMsg {
+    /// The owner of the factory, who may update the instantiation
+    /// fee.
+    pub owner: Option<String>,
+    /// An optional fee charged for each contract the factory
+    /// instantiates.
+    pub fee: Option<FeeInfo>,
+}
 
+/// A fee charged for each contract instantiated by the factory.
+#[cw_serde]
+pub struct FeeInfo {
+    /// The fee charged per contract.
+    pub amount: Coin,
+    /// The address fees are sent to.
+    pub treasury: String,
+}
+
+/// Information about a SubDAO to instantiate along with its parent.
+#[cw_serde]
+pub struct SubDaoInstantiateInfo {
+    pub code_id: u64,
+    /// The SubDAO's instantiate message. Its `admin` is set to the
+    /// parent DAO by the factory.
+    pub instantiate_msg: dao_interface::msg::InstantiateMsg,
+    pub label: String,
+}
+
+#[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Instantiates the target contract with the provided instantiate message and code id and
@@ -13,11 +41,58 @@ pub enum ExecuteMsg {
         code_id: u64,
         label: String,
     },
+    /// Instantiates a DAO and then each of `sub_daos` with the DAO as
+    /// their admin. Every contract is made its own wasm admin. If any
+    /// instantiation fails, none of the contracts are created.
+    InstantiateDaoWithSubDaos {
+        instantiate_msg: Binary,
+        code_id: u64,
+        label: String,
+        sub_daos: Vec<SubDaoInstantiateInfo>,
+    },
+    /// Callable only by the current owner. Updates the fee charged
+    /// for each instantiated contract. If `fee` is `None` no fee is
+    /// charged.
+    UpdateFee { fee: Option<FeeInfo> },
 }
 
 #[cw_serde]
 #[derive(QueryResponses)]
-pub enum QueryMsg {}
+pub enum QueryMsg {
+    /// Returns list of all contracts instantiated by the factory
+    #[returns(Vec<crate::state::CreatedContract>)]
+    ListContracts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns list of all contracts instantiated by the factory for `creator`
+    #[returns(Vec<crate::state::CreatedContract>)]
+    ListContractsByCreator {
+        creator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns list of all contracts instantiated by the factory with `code_id`
+    #[returns(Vec<crate::state::CreatedContract>)]
+    ListContractsByCodeId {
+        code_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the fee charged for each instantiated contract, if any.
+    #[returns(Option<crate::state::InstantiationFee>)]
+    Fee {},
+    /// Returns info about the contract ownership, if set
+    #[returns(::cw_ownable::Ownership<::cosmwasm_std::Addr>)]
+    Ownership {},
+}
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// The owner of the factory, who may update the instantiation
+    /// fee. Replaces the current owner, if any.
+    pub owner: Option<String>,
+    /// An optional fee charged for each contract the factory
+    /// instantiates. Replaces the current fee, if any.
+    pub fee: Option<FeeInfo>,
+}
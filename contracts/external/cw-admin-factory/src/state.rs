@@ -0,0 +1,70 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin};
+use cw_storage_plus::{Deque, Index, IndexList, IndexedMap, Item, MultiIndex};
+
+use crate::msg::SubDaoInstantiateInfo;
+
+/// A fee charged for each contract instantiated by the factory.
+#[cw_serde]
+pub struct InstantiationFee {
+    /// The fee charged per contract.
+    pub amount: Coin,
+    /// The address fees are sent to.
+    pub treasury: Addr,
+}
+
+/// A contract instantiated by the factory.
+#[cw_serde]
+pub struct CreatedContract {
+    pub contract: String,
+    pub creator: String,
+    pub code_id: u64,
+    pub label: String,
+}
+
+/// An instantiation that has been dispatched but whose reply has not
+/// yet been handled.
+#[cw_serde]
+pub struct PendingInstantiation {
+    pub creator: Addr,
+    pub code_id: u64,
+    pub label: String,
+}
+
+pub const FEE: Item<InstantiationFee> = Item::new("fee");
+
+/// Instantiations awaiting a reply, in the order they were
+/// dispatched.
+pub const PENDING_INSTANTIATIONS: Deque<PendingInstantiation> = Deque::new("pending");
+
+/// Temporarily holds the SubDAOs to instantiate once their parent DAO
+/// has been instantiated.
+pub const TMP_SUB_DAOS: Item<Vec<SubDaoInstantiateInfo>> = Item::new("tmp_sub_daos");
+
+pub struct ContractIndexes<'a> {
+    pub creator: MultiIndex<'a, String, CreatedContract, String>,
+    pub code_id: MultiIndex<'a, u64, CreatedContract, String>,
+}
+
+impl<'a> IndexList<CreatedContract> for ContractIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<CreatedContract>> + '_> {
+        let v: Vec<&dyn Index<CreatedContract>> = vec![&self.creator, &self.code_id];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn created_contracts<'a>() -> IndexedMap<'a, &'a str, CreatedContract, ContractIndexes<'a>> {
+    let indexes = ContractIndexes {
+        creator: MultiIndex::new(
+            |_pk: &[u8], d: &CreatedContract| d.creator.clone(),
+            "created_contracts",
+            "created_contracts__creator",
+        ),
+        code_id: MultiIndex::new(
+            |_pk: &[u8], d: &CreatedContract| d.code_id,
+            "created_contracts",
+            "created_contracts__code_id",
+        ),
+    };
+    IndexedMap::new("created_contracts", indexes)
+}
@@ -2,16 +2,19 @@ use std::vec;
 
 use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
-    to_binary, Addr, Binary, Empty, Reply, SubMsg, SubMsgResponse, SubMsgResult, WasmMsg,
+    to_binary, Addr, Binary, Coin, Empty, Reply, SubMsg, SubMsgResponse, SubMsgResult, Uint128,
+    WasmMsg,
 };
 
-use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use cw_multi_test::{App, AppResponse, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
 
 use crate::{
     contract::instantiate,
     contract::{migrate, reply, CONTRACT_NAME, CONTRACT_VERSION, INSTANTIATE_CONTRACT_REPLY_ID},
-    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg},
+    msg::{ExecuteMsg, FeeInfo, InstantiateMsg, MigrateMsg, QueryMsg, SubDaoInstantiateInfo},
+    state::{CreatedContract, InstantiationFee, PendingInstantiation, FEE, PENDING_INSTANTIATIONS},
+    ContractError,
 };
 
 fn factory_contract() -> Box<dyn Contract<Empty>> {
@@ -44,11 +47,7 @@ fn cw_core_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
-#[test]
-pub fn test_set_admin() {
-    let mut app = App::default();
-    let code_id = app.store_code(factory_contract());
-    let cw20_code_id = app.store_code(cw20_contract());
+fn core_instantiate_msg(cw20_code_id: u64) -> dao_interface::msg::InstantiateMsg {
     let cw20_instantiate = cw20_base::msg::InstantiateMsg {
         name: "DAO".to_string(),
         symbol: "DAO".to_string(),
@@ -58,21 +57,7 @@ pub fn test_set_admin() {
         marketing: None,
     };
 
-    let instantiate = InstantiateMsg {};
-    let factory_addr = app
-        .instantiate_contract(
-            code_id,
-            Addr::unchecked("CREATOR"),
-            &instantiate,
-            &[],
-            "cw-admin-factory",
-            None,
-        )
-        .unwrap();
-
-    // Instantiate core contract using factory.
-    let cw_core_code_id = app.store_code(cw_core_contract());
-    let instantiate_core = dao_interface::msg::InstantiateMsg {
+    dao_interface::msg::InstantiateMsg {
         dao_uri: None,
         admin: None,
         name: "DAO DAO".to_string(),
@@ -101,7 +86,33 @@ pub fn test_set_admin() {
             },
         ],
         initial_items: None,
+    }
+}
+
+#[test]
+pub fn test_set_admin() {
+    let mut app = App::default();
+    let code_id = app.store_code(factory_contract());
+    let cw20_code_id = app.store_code(cw20_contract());
+
+    let instantiate = InstantiateMsg {
+        owner: None,
+        fee: None,
     };
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("CREATOR"),
+            &instantiate,
+            &[],
+            "cw-admin-factory",
+            None,
+        )
+        .unwrap();
+
+    // Instantiate core contract using factory.
+    let cw_core_code_id = app.store_code(cw_core_contract());
+    let instantiate_core = core_instantiate_msg(cw20_code_id);
 
     let res: AppResponse = app
         .execute_contract(
@@ -130,10 +141,23 @@ pub fn test_set_admin() {
 pub fn test_set_admin_mock() {
     let mut deps = mock_dependencies();
     // Instantiate factory contract
-    let instantiate_msg = InstantiateMsg {};
+    let instantiate_msg = InstantiateMsg {
+        owner: None,
+        fee: None,
+    };
     let info = mock_info("creator", &[]);
     let env = mock_env();
     instantiate(deps.as_mut(), env.clone(), info, instantiate_msg).unwrap();
+    PENDING_INSTANTIATIONS
+        .push_back(
+            deps.as_mut().storage,
+            &PendingInstantiation {
+                creator: Addr::unchecked("creator"),
+                code_id: 1,
+                label: "my contract".to_string(),
+            },
+        )
+        .unwrap();
     let bytes = vec![10, 9, 99, 111, 110, 116, 114, 97, 99, 116, 50];
     let reply_msg: Reply = Reply {
         id: INSTANTIATE_CONTRACT_REPLY_ID,
@@ -158,8 +182,352 @@ pub fn test_set_admin_mock() {
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
     cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
-    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    migrate(
+        deps.as_mut(),
+        mock_env(),
+        MigrateMsg {
+            owner: Some("owner".to_string()),
+            fee: Some(FeeInfo {
+                amount: Coin::new(10, "ujuno"),
+                treasury: "treasury".to_string(),
+            }),
+        },
+    )
+    .unwrap();
     let version = cw2::get_contract_version(&deps.storage).unwrap();
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
+
+    // Migrating sets the owner and fee of factories instantiated
+    // before they were introduced.
+    let ownership = cw_ownable::get_ownership(&deps.storage).unwrap();
+    assert_eq!(ownership.owner, Some(Addr::unchecked("owner")));
+    assert_eq!(
+        FEE.load(&deps.storage).unwrap(),
+        InstantiationFee {
+            amount: Coin::new(10, "ujuno"),
+            treasury: Addr::unchecked("treasury"),
+        }
+    );
+}
+
+fn list_contracts(app: &App, factory_addr: &Addr, msg: QueryMsg) -> Vec<CreatedContract> {
+    app.wrap().query_wasm_smart(factory_addr, &msg).unwrap()
+}
+
+#[test]
+pub fn test_instantiation_fee_and_registry() {
+    let mut app = App::default();
+    let code_id = app.store_code(factory_contract());
+    let cw20_code_id = app.store_code(cw20_contract());
+    let cw_core_code_id = app.store_code(cw_core_contract());
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: "CREATOR".to_string(),
+        amount: vec![Coin::new(100, "ujuno")],
+    }))
+    .unwrap();
+
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("CREATOR"),
+            &InstantiateMsg {
+                owner: Some("owner".to_string()),
+                fee: Some(FeeInfo {
+                    amount: Coin::new(10, "ujuno"),
+                    treasury: "treasury".to_string(),
+                }),
+            },
+            &[],
+            "cw-admin-factory",
+            None,
+        )
+        .unwrap();
+
+    let fee: Option<InstantiationFee> = app
+        .wrap()
+        .query_wasm_smart(&factory_addr, &QueryMsg::Fee {})
+        .unwrap();
+    assert_eq!(
+        fee,
+        Some(InstantiationFee {
+            amount: Coin::new(10, "ujuno"),
+            treasury: Addr::unchecked("treasury"),
+        })
+    );
+
+    let instantiate = ExecuteMsg::InstantiateContractWithSelfAdmin {
+        instantiate_msg: to_binary(&core_instantiate_msg(cw20_code_id)).unwrap(),
+        code_id: cw_core_code_id,
+        label: "my contract".to_string(),
+    };
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("CREATOR"),
+            factory_addr.clone(),
+            &instantiate,
+            &[Coin::new(5, "ujuno")],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::InsufficientFee {
+            paid: Coin::new(5, "ujuno"),
+            expected: Coin::new(10, "ujuno"),
+        }
+    );
+
+    // Funds in excess of the fee are sent to the new contract.
+    app.execute_contract(
+        Addr::unchecked("CREATOR"),
+        factory_addr.clone(),
+        &instantiate,
+        &[Coin::new(15, "ujuno")],
+    )
+    .unwrap();
+    let treasury = app.wrap().query_balance("treasury", "ujuno").unwrap();
+    assert_eq!(treasury.amount, Uint128::new(10));
+
+    let contracts = list_contracts(
+        &app,
+        &factory_addr,
+        QueryMsg::ListContracts {
+            start_after: None,
+            limit: None,
+        },
+    );
+    assert_eq!(contracts.len(), 1);
+    assert_eq!(contracts[0].creator, "CREATOR");
+    assert_eq!(contracts[0].code_id, cw_core_code_id);
+    assert_eq!(contracts[0].label, "my contract");
+    let core = app
+        .wrap()
+        .query_balance(&contracts[0].contract, "ujuno")
+        .unwrap();
+    assert_eq!(core.amount, Uint128::new(5));
+
+    // Only the owner may update the fee.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("CREATOR"),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateFee { fee: None },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("owner"),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateFee {
+                fee: Some(FeeInfo {
+                    amount: Coin::new(0, "ujuno"),
+                    treasury: "treasury".to_string(),
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroFee {});
+    app.execute_contract(
+        Addr::unchecked("owner"),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateFee { fee: None },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("other"),
+        factory_addr.clone(),
+        &instantiate,
+        &[],
+    )
+    .unwrap();
+
+    let contracts = list_contracts(
+        &app,
+        &factory_addr,
+        QueryMsg::ListContracts {
+            start_after: None,
+            limit: None,
+        },
+    );
+    assert_eq!(contracts.len(), 2);
+    let contracts = list_contracts(
+        &app,
+        &factory_addr,
+        QueryMsg::ListContractsByCreator {
+            creator: "other".to_string(),
+            start_after: None,
+            limit: None,
+        },
+    );
+    assert_eq!(contracts.len(), 1);
+    assert_eq!(contracts[0].creator, "other");
+    let contracts = list_contracts(
+        &app,
+        &factory_addr,
+        QueryMsg::ListContractsByCodeId {
+            code_id: cw_core_code_id,
+            start_after: None,
+            limit: Some(1),
+        },
+    );
+    assert_eq!(contracts.len(), 1);
+    let contracts = list_contracts(
+        &app,
+        &factory_addr,
+        QueryMsg::ListContractsByCodeId {
+            code_id: cw_core_code_id,
+            start_after: Some(contracts[0].contract.clone()),
+            limit: None,
+        },
+    );
+    assert_eq!(contracts.len(), 1);
+    let contracts = list_contracts(
+        &app,
+        &factory_addr,
+        QueryMsg::ListContractsByCodeId {
+            code_id: cw20_code_id,
+            start_after: None,
+            limit: None,
+        },
+    );
+    assert!(contracts.is_empty());
+}
+
+#[test]
+pub fn test_instantiate_dao_with_sub_daos() {
+    let mut app = App::default();
+    let code_id = app.store_code(factory_contract());
+    let cw20_code_id = app.store_code(cw20_contract());
+    let cw_core_code_id = app.store_code(cw_core_contract());
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: "CREATOR".to_string(),
+        amount: vec![Coin::new(100, "ujuno")],
+    }))
+    .unwrap();
+
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("CREATOR"),
+            &InstantiateMsg {
+                owner: None,
+                fee: Some(FeeInfo {
+                    amount: Coin::new(10, "ujuno"),
+                    treasury: "treasury".to_string(),
+                }),
+            },
+            &[],
+            "cw-admin-factory",
+            None,
+        )
+        .unwrap();
+
+    let sub_dao = |label: &str, code_id: u64| SubDaoInstantiateInfo {
+        code_id,
+        instantiate_msg: core_instantiate_msg(cw20_code_id),
+        label: label.to_string(),
+    };
+    let instantiate =
+        |sub_daos: Vec<SubDaoInstantiateInfo>| ExecuteMsg::InstantiateDaoWithSubDaos {
+            instantiate_msg: to_binary(&core_instantiate_msg(cw20_code_id)).unwrap(),
+            code_id: cw_core_code_id,
+            label: "parent".to_string(),
+            sub_daos,
+        };
+
+    // A fee is charged for each contract.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("CREATOR"),
+            factory_addr.clone(),
+            &instantiate(vec![
+                sub_dao("child 1", cw_core_code_id),
+                sub_dao("child 2", cw_core_code_id),
+            ]),
+            &[Coin::new(20, "ujuno")],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::InsufficientFee {
+            paid: Coin::new(20, "ujuno"),
+            expected: Coin::new(30, "ujuno"),
+        }
+    );
+
+    // If any SubDAO fails to instantiate, nothing is created.
+    app.execute_contract(
+        Addr::unchecked("CREATOR"),
+        factory_addr.clone(),
+        &instantiate(vec![
+            sub_dao("child 1", cw_core_code_id),
+            sub_dao("child 2", cw20_code_id),
+        ]),
+        &[Coin::new(30, "ujuno")],
+    )
+    .unwrap_err();
+    let all = QueryMsg::ListContracts {
+        start_after: None,
+        limit: None,
+    };
+    assert!(list_contracts(&app, &factory_addr, all.clone()).is_empty());
+
+    app.execute_contract(
+        Addr::unchecked("CREATOR"),
+        factory_addr.clone(),
+        &instantiate(vec![
+            sub_dao("child 1", cw_core_code_id),
+            sub_dao("child 2", cw_core_code_id),
+        ]),
+        &[Coin::new(30, "ujuno")],
+    )
+    .unwrap();
+    let treasury = app.wrap().query_balance("treasury", "ujuno").unwrap();
+    assert_eq!(treasury.amount, Uint128::new(30));
+
+    let contracts = list_contracts(&app, &factory_addr, all);
+    assert_eq!(contracts.len(), 3);
+    let parent = contracts
+        .iter()
+        .find(|c| c.label == "parent")
+        .unwrap()
+        .contract
+        .clone();
+
+    for contract in contracts {
+        assert_eq!(contract.creator, "CREATOR");
+
+        // Every contract is its own wasm admin.
+        let info = app
+            .wrap()
+            .query_wasm_contract_info(&contract.contract)
+            .unwrap();
+        assert_eq!(info.admin, Some(contract.contract.clone()));
+
+        // The parent is the admin of each SubDAO.
+        let admin: Addr = app
+            .wrap()
+            .query_wasm_smart(&contract.contract, &dao_interface::msg::QueryMsg::Admin {})
+            .unwrap();
+        assert_eq!(admin, parent);
+    }
 }
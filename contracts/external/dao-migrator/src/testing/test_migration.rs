@@ -7,8 +7,8 @@ use dao_interface::{query::SubDao, state::ProposalModuleStatus};
 use crate::{
     testing::{
         helpers::ExecuteParams,
-        helpers::VotingType,
-        setup::{execute_migration, execute_migration_from_core, setup_dao_v1},
+        helpers::{VotingType, SENDER_ADDR},
+        setup::{execute_migration, execute_migration_from_core, init_v1, setup_dao_v1},
         state_helpers::{
             query_state_v1_cw20, query_state_v1_cw4, query_state_v2_cw20, query_state_v2_cw4,
         },
@@ -331,8 +331,14 @@ fn test_dont_migrate_cw20() {
 #[test]
 fn test_sub_daos() {
     let (mut app, module_addrs, v1_code_ids) = setup_dao_v1(VotingType::Cw20);
+    // SubDAOs must be DAO DAO core contracts.
+    let (sub_dao_addr, _) = init_v1(
+        app.borrow_mut(),
+        Addr::unchecked(SENDER_ADDR),
+        VotingType::Cw4,
+    );
     let sub_dao = SubDao {
-        addr: "sub_dao_1".to_string(),
+        addr: sub_dao_addr.to_string(),
         charter: None,
     };
 
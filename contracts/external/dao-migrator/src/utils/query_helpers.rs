@@ -30,6 +30,7 @@ pub(crate) fn v1_threshold_to_v2(v1: voting_v1::Threshold) -> Threshold {
         voting_v1::Threshold::ThresholdQuorum { threshold, quorum } => Threshold::ThresholdQuorum {
             threshold: v1_percentage_threshold_to_v2(threshold),
             quorum: v1_percentage_threshold_to_v2(quorum),
+            quorum_decay: None,
         },
         voting_v1::Threshold::AbsoluteCount { threshold } => Threshold::AbsoluteCount { threshold },
     }
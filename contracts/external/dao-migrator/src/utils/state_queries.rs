@@ -83,6 +83,11 @@ pub fn query_proposal_v1(
                 status: v1_status_to_v2(proposal.status),
                 votes: v1_votes_to_v2(proposal.votes),
                 allow_revoting: proposal.allow_revoting,
+                veto: None,
+                best_effort: false,
+                execution_error: None,
+                failed_message_index: None,
+                message_results: vec![],
             })
         })
         .collect::<Result<Vec<dao_proposal_single::proposal::SingleChoiceProposal>, ContractError>>(
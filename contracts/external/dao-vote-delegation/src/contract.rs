@@ -0,0 +1,375 @@
+use std::collections::BTreeSet;
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult,
+    Storage, Uint128,
+};
+use cw2::set_contract_version;
+use cw_paginate_storage::paginate_snapshot_map_keys;
+use cw_storage_plus::{Bound, PrefixBound};
+use dao_interface::voting::{
+    InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+use dao_voting::voting::{get_total_power, get_voting_power};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{DAO, DELEGATES, DELEGATIONS, DELEGATORS, REMOVED_DELEGATORS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-vote-delegation";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 50;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    DAO.save(deps.storage, &info.sender)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Register {} => execute_register(deps, env, info),
+        ExecuteMsg::Unregister {} => execute_unregister(deps, env, info),
+        ExecuteMsg::Delegate { delegate } => execute_delegate(deps, env, info, delegate),
+        ExecuteMsg::Undelegate {} => execute_undelegate(deps, env, info),
+    }
+}
+
+pub fn execute_register(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if DELEGATES.has(deps.storage, &info.sender) {
+        return Err(ContractError::AlreadyDelegate {});
+    }
+    // Delegation is a single hop. Delegates may not delegate their
+    // own voting power.
+    if DELEGATIONS.has(deps.storage, &info.sender) {
+        return Err(ContractError::DelegatorCannotRegister {});
+    }
+    DELEGATES.save(deps.storage, &info.sender, &Empty {}, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "register")
+        .add_attribute("delegate", info.sender))
+}
+
+pub fn execute_unregister(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if !DELEGATES.has(deps.storage, &info.sender) {
+        return Err(ContractError::NotDelegate {
+            address: info.sender.into_string(),
+        });
+    }
+    DELEGATES.remove(deps.storage, &info.sender, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "unregister")
+        .add_attribute("delegate", info.sender))
+}
+
+pub fn execute_delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    if DELEGATES.has(deps.storage, &info.sender) {
+        return Err(ContractError::DelegateCannotDelegate {});
+    }
+    if !DELEGATES.has(deps.storage, &delegate) {
+        return Err(ContractError::NotDelegate {
+            address: delegate.into_string(),
+        });
+    }
+    let previous = DELEGATIONS.may_load(deps.storage, &info.sender)?;
+    if previous.as_ref() == Some(&delegate) {
+        return Err(ContractError::AlreadyDelegated {
+            delegate: delegate.into_string(),
+        });
+    }
+    // Every delegator is checked when a delegate's voting power is
+    // computed, so addresses that add nothing to it may not delegate.
+    let dao = DAO.load(deps.storage)?;
+    if get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?.is_zero() {
+        return Err(ContractError::ZeroVotingPower {});
+    }
+
+    if let Some(previous) = previous {
+        remove_delegator(deps.storage, &previous, &info.sender, env.block.height)?;
+    }
+    DELEGATIONS.save(deps.storage, &info.sender, &delegate, env.block.height)?;
+    DELEGATORS.save(deps.storage, (&delegate, &info.sender), &Empty {})?;
+
+    Ok(Response::default()
+        .add_attribute("action", "delegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+pub fn execute_undelegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let delegate = DELEGATIONS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NoDelegation {})?;
+    DELEGATIONS.remove(deps.storage, &info.sender, env.block.height)?;
+    remove_delegator(deps.storage, &delegate, &info.sender, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "undelegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+/// Removes `delegator` from the current delegators of `delegate`,
+/// recording the removal so that the delegation is still found when
+/// listing the delegate's delegators at earlier heights.
+fn remove_delegator(
+    storage: &mut dyn Storage,
+    delegate: &Addr,
+    delegator: &Addr,
+    height: u64,
+) -> StdResult<()> {
+    DELEGATORS.remove(storage, (delegate, delegator));
+    REMOVED_DELEGATORS.save(storage, (delegate, height, delegator), &Empty {})
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::VotingPowerAtHeight { address, height } => {
+            query_voting_power_at_height(deps, env, address, height)
+        }
+        QueryMsg::TotalPowerAtHeight { height } => query_total_power_at_height(deps, env, height),
+        QueryMsg::Dao {} => to_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::Delegation { delegator, height } => {
+            query_delegation(deps, env, delegator, height)
+        }
+        QueryMsg::Delegators {
+            delegate,
+            height,
+            start_after,
+            limit,
+        } => query_delegators(deps, env, delegate, height, start_after, limit),
+        QueryMsg::Delegates { start_after, limit } => query_delegates(deps, start_after, limit),
+    }
+}
+
+/// Loads the delegate that `delegator` had delegated to at `height`,
+/// if the delegate was registered at that height.
+fn active_delegate_at_height(
+    storage: &dyn Storage,
+    delegator: &Addr,
+    height: u64,
+) -> StdResult<Option<Addr>> {
+    match DELEGATIONS.may_load_at_height(storage, delegator, height)? {
+        Some(delegate)
+            if DELEGATES
+                .may_load_at_height(storage, &delegate, height)?
+                .is_some() =>
+        {
+            Ok(Some(delegate))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Lists the delegators of `delegate` at `height`.
+fn delegators_at_height(
+    storage: &dyn Storage,
+    delegate: &Addr,
+    height: u64,
+    start_after: Option<&Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    if DELEGATES
+        .may_load_at_height(storage, delegate, height)?
+        .is_none()
+    {
+        return Ok(vec![]);
+    }
+    // Delegators removed at or after `height` may still have been
+    // delegating at it. Only removals since `height` are read, so
+    // this set stays small for recent heights.
+    let mut removed = REMOVED_DELEGATORS
+        .prefix_range(
+            storage,
+            Some(PrefixBound::inclusive((delegate, height))),
+            Some(PrefixBound::inclusive((delegate, u64::MAX))),
+            Order::Ascending,
+        )
+        .map(|item| item.map(|((_, _, delegator), _)| delegator))
+        .filter(|delegator| match (delegator, start_after) {
+            (Ok(delegator), Some(start_after)) => delegator > start_after,
+            _ => true,
+        })
+        .collect::<StdResult<BTreeSet<Addr>>>()?
+        .into_iter()
+        .peekable();
+    let mut current = DELEGATORS.prefix(delegate).keys(
+        storage,
+        start_after.map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    );
+
+    // Merge current and removed delegators in order, keeping those
+    // that were delegating to `delegate` at `height`.
+    let mut next_current = current.next().transpose()?;
+    let mut delegators = vec![];
+    while limit.map_or(true, |limit| delegators.len() < limit as usize) {
+        let next_removed = removed.peek().cloned();
+        let candidate = match (next_current.take(), next_removed) {
+            (Some(c), Some(r)) if r < c => {
+                next_current = Some(c);
+                removed.next();
+                r
+            }
+            (Some(c), r) => {
+                if r.as_ref() == Some(&c) {
+                    removed.next();
+                }
+                next_current = current.next().transpose()?;
+                c
+            }
+            (None, Some(r)) => {
+                removed.next();
+                r
+            }
+            (None, None) => break,
+        };
+        if DELEGATIONS
+            .may_load_at_height(storage, &candidate, height)?
+            .as_ref()
+            == Some(delegate)
+        {
+            delegators.push(candidate);
+        }
+    }
+    Ok(delegators)
+}
+
+pub fn query_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let height = height.unwrap_or(env.block.height);
+    let dao = DAO.load(deps.storage)?;
+
+    let mut power = if active_delegate_at_height(deps.storage, &address, height)?.is_some() {
+        Uint128::zero()
+    } else {
+        get_voting_power(deps, address.clone(), &dao, Some(height))?
+    };
+    for delegator in delegators_at_height(deps.storage, &address, height, None, None)? {
+        power = power.checked_add(get_voting_power(deps, delegator, &dao, Some(height))?)?;
+    }
+
+    to_binary(&VotingPowerAtHeightResponse { power, height })
+}
+
+pub fn query_total_power_at_height(deps: Deps, env: Env, height: Option<u64>) -> StdResult<Binary> {
+    let height = height.unwrap_or(env.block.height);
+    let dao = DAO.load(deps.storage)?;
+    let power = get_total_power(deps, &dao, Some(height))?;
+    to_binary(&TotalPowerAtHeightResponse { power, height })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_binary(&InfoResponse { info })
+}
+
+pub fn query_delegation(
+    deps: Deps,
+    env: Env,
+    delegator: String,
+    height: Option<u64>,
+) -> StdResult<Binary> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    let height = height.unwrap_or(env.block.height);
+    to_binary(&active_delegate_at_height(
+        deps.storage,
+        &delegator,
+        height,
+    )?)
+}
+
+pub fn query_delegators(
+    deps: Deps,
+    env: Env,
+    delegate: String,
+    height: Option<u64>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let height = height.unwrap_or(env.block.height);
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    to_binary(&delegators_at_height(
+        deps.storage,
+        &delegate,
+        height,
+        start_after.as_ref(),
+        Some(limit),
+    )?)
+}
+
+pub fn query_delegates(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let delegates: Vec<Addr> = paginate_snapshot_map_keys(
+        deps,
+        &DELEGATES,
+        start_after.as_ref(),
+        Some(limit),
+        Order::Ascending,
+    )?;
+    to_binary(&delegates)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
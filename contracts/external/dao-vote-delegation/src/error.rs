@@ -0,0 +1,31 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("address is already a registered delegate")]
+    AlreadyDelegate {},
+
+    #[error("({address}) is not a registered delegate")]
+    NotDelegate { address: String },
+
+    #[error("registered delegates may not delegate their voting power")]
+    DelegateCannotDelegate {},
+
+    #[error(
+        "addresses with an active delegation must undelegate before registering as a delegate"
+    )]
+    DelegatorCannotRegister {},
+
+    #[error("voting power is already delegated to ({delegate})")]
+    AlreadyDelegated { delegate: String },
+
+    #[error("no voting power is delegated")]
+    NoDelegation {},
+
+    #[error("addresses without voting power may not delegate")]
+    ZeroVotingPower {},
+}
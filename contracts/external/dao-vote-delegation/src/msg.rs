@@ -0,0 +1,56 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use dao_dao_macros::voting_module_query;
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Registers the sender as a delegate that other addresses may
+    /// delegate their voting power to.
+    Register {},
+    /// Unregisters the sender as a delegate. Delegations to the
+    /// sender are inactive until they register again.
+    Unregister {},
+    /// Delegates all of the sender's voting power to a registered
+    /// delegate, replacing any existing delegation.
+    Delegate { delegate: String },
+    /// Removes the sender's delegation.
+    Undelegate {},
+}
+
+/// `VotingPowerAtHeight` returns an address' voting power including
+/// the voting power delegated to it, and excluding voting power it
+/// has delegated away. `TotalPowerAtHeight` returns the DAO's total
+/// voting power as delegation does not change it.
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns the registered delegate that `delegator` had
+    /// delegated their voting power to at `height`, if any. A height
+    /// of None will query for the current block height.
+    #[returns(Option<cosmwasm_std::Addr>)]
+    Delegation {
+        delegator: String,
+        height: Option<u64>,
+    },
+    /// Lists the addresses that had delegated their voting power to
+    /// `delegate` at `height`.
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    Delegators {
+        delegate: String,
+        height: Option<u64>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists the currently registered delegates.
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    Delegates {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
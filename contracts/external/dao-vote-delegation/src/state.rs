@@ -0,0 +1,33 @@
+use cosmwasm_std::{Addr, Empty};
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy};
+
+/// The DAO whose voting power may be delegated.
+pub const DAO: Item<Addr> = Item::new("dao");
+
+/// The set of registered delegates.
+pub const DELEGATES: SnapshotMap<&Addr, Empty> = SnapshotMap::new(
+    "delegates",
+    "delegates__checkpoints",
+    "delegates__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Maps delegators to the delegate they have delegated to. A
+/// delegation is only active while its delegate is registered.
+pub const DELEGATIONS: SnapshotMap<&Addr, Addr> = SnapshotMap::new(
+    "delegations",
+    "delegations__checkpoints",
+    "delegations__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The current (delegate, delegator) pairs. Entries are removed when
+/// the delegator undelegates or delegates elsewhere.
+pub const DELEGATORS: Map<(&Addr, &Addr), Empty> = Map::new("delegators");
+
+/// (delegate, height, delegator) for each delegation removed at
+/// `height`. A delegator removed at or after a height may still have
+/// been delegating at it, so these are checked along with
+/// `DELEGATORS` when listing a delegate's delegators at a past
+/// height. Only removals since that height are read.
+pub const REMOVED_DELEGATORS: Map<(&Addr, u64, &Addr), Empty> = Map::new("removed_delegators");
@@ -0,0 +1,397 @@
+use cosmwasm_std::{to_binary, Addr, Empty, Uint128};
+use cw_multi_test::{next_block, App, AppResponse, Contract, ContractWrapper, Executor};
+use dao_interface::{
+    state::{Admin, ModuleInstantiateInfo},
+    voting::VotingPowerAtHeightResponse,
+};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    ContractError,
+};
+
+const CREATOR_ADDR: &str = "creator";
+
+fn delegation_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn cw4_group_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw4_group::contract::execute,
+        cw4_group::contract::instantiate,
+        cw4_group::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn cw4_voting_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_voting_cw4::contract::execute,
+        dao_voting_cw4::contract::instantiate,
+        dao_voting_cw4::contract::query,
+    )
+    .with_reply(dao_voting_cw4::contract::reply);
+    Box::new(contract)
+}
+
+fn sudo_proposal_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_sudo::contract::execute,
+        dao_proposal_sudo::contract::instantiate,
+        dao_proposal_sudo::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn cw_core_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_dao_core::contract::execute,
+        dao_dao_core::contract::instantiate,
+        dao_dao_core::contract::query,
+    )
+    .with_reply(dao_dao_core::contract::reply);
+    Box::new(contract)
+}
+
+/// Instantiates a DAO with a cw4 voting module and a delegation
+/// contract for it. Returns the address of the delegation contract.
+fn setup_test(app: &mut App, members: Vec<(&str, u64)>) -> Addr {
+    let cw4_id = app.store_code(cw4_group_contract());
+    let voting_id = app.store_code(cw4_voting_contract());
+    let proposal_id = app.store_code(sudo_proposal_contract());
+    let core_id = app.store_code(cw_core_contract());
+    let delegation_id = app.store_code(delegation_contract());
+
+    let dao = app
+        .instantiate_contract(
+            core_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &dao_interface::msg::InstantiateMsg {
+                admin: None,
+                name: "DAO DAO".to_string(),
+                description: "A DAO that builds DAOs.".to_string(),
+                image_url: None,
+                dao_uri: None,
+                automatically_add_cw20s: true,
+                automatically_add_cw721s: true,
+                voting_module_instantiate_info: ModuleInstantiateInfo {
+                    code_id: voting_id,
+                    msg: to_binary(&dao_voting_cw4::msg::InstantiateMsg {
+                        cw4_group_code_id: cw4_id,
+                        initial_members: members
+                            .into_iter()
+                            .map(|(addr, weight)| cw4::Member {
+                                addr: addr.to_string(),
+                                weight,
+                            })
+                            .collect(),
+                    })
+                    .unwrap(),
+                    admin: Some(Admin::CoreModule {}),
+                    label: "voting module".to_string(),
+                },
+                proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+                    code_id: proposal_id,
+                    msg: to_binary(&dao_proposal_sudo::msg::InstantiateMsg {
+                        root: CREATOR_ADDR.to_string(),
+                    })
+                    .unwrap(),
+                    admin: Some(Admin::CoreModule {}),
+                    label: "proposal module".to_string(),
+                }],
+                initial_items: None,
+            },
+            &[],
+            "dao",
+            None,
+        )
+        .unwrap();
+
+    let delegation = app
+        .instantiate_contract(
+            delegation_id,
+            dao,
+            &InstantiateMsg {},
+            &[],
+            "vote delegation",
+            None,
+        )
+        .unwrap();
+    app.update_block(next_block);
+    delegation
+}
+
+fn execute(
+    app: &mut App,
+    delegation: &Addr,
+    sender: &str,
+    msg: ExecuteMsg,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(Addr::unchecked(sender), delegation.clone(), &msg, &[])
+        .map_err(|e| e.downcast().unwrap())
+}
+
+fn query_voting_power(app: &App, delegation: &Addr, address: &str, height: Option<u64>) -> u128 {
+    let res: VotingPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(
+            delegation,
+            &QueryMsg::VotingPowerAtHeight {
+                address: address.to_string(),
+                height,
+            },
+        )
+        .unwrap();
+    res.power.u128()
+}
+
+fn query_delegation(
+    app: &App,
+    delegation: &Addr,
+    delegator: &str,
+    height: Option<u64>,
+) -> Option<Addr> {
+    app.wrap()
+        .query_wasm_smart(
+            delegation,
+            &QueryMsg::Delegation {
+                delegator: delegator.to_string(),
+                height,
+            },
+        )
+        .unwrap()
+}
+
+fn query_delegators(
+    app: &App,
+    delegation: &Addr,
+    delegate: &str,
+    height: Option<u64>,
+) -> Vec<Addr> {
+    app.wrap()
+        .query_wasm_smart(
+            delegation,
+            &QueryMsg::Delegators {
+                delegate: delegate.to_string(),
+                height,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_delegation() {
+    let mut app = App::default();
+    let delegation = setup_test(&mut app, vec![("alice", 10), ("bob", 20), ("carol", 30)]);
+
+    // Voting power may only be delegated to registered delegates.
+    let err = execute(
+        &mut app,
+        &delegation,
+        "alice",
+        ExecuteMsg::Delegate {
+            delegate: "bob".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotDelegate {
+            address: "bob".to_string()
+        }
+    );
+
+    execute(&mut app, &delegation, "bob", ExecuteMsg::Register {}).unwrap();
+    let err = execute(&mut app, &delegation, "bob", ExecuteMsg::Register {}).unwrap_err();
+    assert_eq!(err, ContractError::AlreadyDelegate {});
+
+    execute(
+        &mut app,
+        &delegation,
+        "alice",
+        ExecuteMsg::Delegate {
+            delegate: "bob".to_string(),
+        },
+    )
+    .unwrap();
+    execute(
+        &mut app,
+        &delegation,
+        "carol",
+        ExecuteMsg::Delegate {
+            delegate: "bob".to_string(),
+        },
+    )
+    .unwrap();
+
+    // Delegation is a single hop.
+    let err = execute(
+        &mut app,
+        &delegation,
+        "bob",
+        ExecuteMsg::Delegate {
+            delegate: "bob".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::DelegateCannotDelegate {});
+    let err = execute(&mut app, &delegation, "alice", ExecuteMsg::Register {}).unwrap_err();
+    assert_eq!(err, ContractError::DelegatorCannotRegister {});
+
+    let err = execute(
+        &mut app,
+        &delegation,
+        "alice",
+        ExecuteMsg::Delegate {
+            delegate: "bob".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AlreadyDelegated {
+            delegate: "bob".to_string()
+        }
+    );
+
+    // Delegation takes effect at the next block.
+    assert_eq!(query_voting_power(&app, &delegation, "bob", None), 20);
+    app.update_block(next_block);
+    let delegated_height = app.block_info().height;
+
+    assert_eq!(query_voting_power(&app, &delegation, "bob", None), 60);
+    assert_eq!(query_voting_power(&app, &delegation, "alice", None), 0);
+    assert_eq!(
+        query_delegation(&app, &delegation, "alice", None),
+        Some(Addr::unchecked("bob"))
+    );
+    assert_eq!(
+        query_delegators(&app, &delegation, "bob", None),
+        vec![Addr::unchecked("alice"), Addr::unchecked("carol")]
+    );
+    let delegates: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            &delegation,
+            &QueryMsg::Delegates {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(delegates, vec![Addr::unchecked("bob")]);
+
+    // Total power is unchanged by delegation.
+    let total: dao_interface::voting::TotalPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(&delegation, &QueryMsg::TotalPowerAtHeight { height: None })
+        .unwrap();
+    assert_eq!(total.power, Uint128::new(60));
+
+    execute(&mut app, &delegation, "alice", ExecuteMsg::Undelegate {}).unwrap();
+    let err = execute(&mut app, &delegation, "alice", ExecuteMsg::Undelegate {}).unwrap_err();
+    assert_eq!(err, ContractError::NoDelegation {});
+    app.update_block(next_block);
+
+    assert_eq!(query_voting_power(&app, &delegation, "bob", None), 50);
+    assert_eq!(query_voting_power(&app, &delegation, "alice", None), 10);
+    assert_eq!(
+        query_delegators(&app, &delegation, "bob", None),
+        vec![Addr::unchecked("carol")]
+    );
+
+    // Delegations are snapshotted by height.
+    assert_eq!(
+        query_voting_power(&app, &delegation, "bob", Some(delegated_height)),
+        60
+    );
+    assert_eq!(
+        query_delegation(&app, &delegation, "alice", Some(delegated_height)),
+        Some(Addr::unchecked("bob"))
+    );
+    assert_eq!(
+        query_delegators(&app, &delegation, "bob", Some(delegated_height)),
+        vec![Addr::unchecked("alice"), Addr::unchecked("carol")]
+    );
+
+    // Delegations to an unregistered delegate are inactive.
+    execute(&mut app, &delegation, "bob", ExecuteMsg::Unregister {}).unwrap();
+    app.update_block(next_block);
+    assert_eq!(query_voting_power(&app, &delegation, "bob", None), 20);
+    assert_eq!(query_voting_power(&app, &delegation, "carol", None), 30);
+    assert_eq!(query_delegation(&app, &delegation, "carol", None), None);
+    assert!(query_delegators(&app, &delegation, "bob", None).is_empty());
+}
+
+#[test]
+fn test_delegator_entries() {
+    let mut app = App::default();
+    let delegation = setup_test(&mut app, vec![("alice", 10), ("bob", 20), ("carol", 30)]);
+    execute(&mut app, &delegation, "bob", ExecuteMsg::Register {}).unwrap();
+    execute(&mut app, &delegation, "carol", ExecuteMsg::Register {}).unwrap();
+
+    // Addresses without voting power may not delegate.
+    let err = execute(
+        &mut app,
+        &delegation,
+        "mallory",
+        ExecuteMsg::Delegate {
+            delegate: "bob".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ZeroVotingPower {});
+
+    execute(
+        &mut app,
+        &delegation,
+        "alice",
+        ExecuteMsg::Delegate {
+            delegate: "bob".to_string(),
+        },
+    )
+    .unwrap();
+    app.update_block(next_block);
+    let delegated_height = app.block_info().height;
+
+    // Redelegating removes the delegator from its old delegate.
+    execute(
+        &mut app,
+        &delegation,
+        "alice",
+        ExecuteMsg::Delegate {
+            delegate: "carol".to_string(),
+        },
+    )
+    .unwrap();
+    app.update_block(next_block);
+    assert!(query_delegators(&app, &delegation, "bob", None).is_empty());
+    assert_eq!(
+        query_delegators(&app, &delegation, "carol", None),
+        vec![Addr::unchecked("alice")]
+    );
+    assert_eq!(query_voting_power(&app, &delegation, "bob", None), 20);
+    assert_eq!(query_voting_power(&app, &delegation, "carol", None), 40);
+
+    // The removed delegation is still counted at earlier heights.
+    assert_eq!(
+        query_delegators(&app, &delegation, "bob", Some(delegated_height)),
+        vec![Addr::unchecked("alice")]
+    );
+    assert_eq!(
+        query_voting_power(&app, &delegation, "bob", Some(delegated_height)),
+        30
+    );
+    assert_eq!(
+        query_voting_power(&app, &delegation, "carol", Some(delegated_height)),
+        30
+    );
+}
@@ -1,22 +1,31 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult, SubMsg,
-    WasmMsg,
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult,
+    SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw4::Cw4Contract;
 use cw_paginate_storage::paginate_map_values;
+use cw_storage_plus::Bound;
+use cw_utils::Duration;
 use dao_pre_propose_base::{
-    error::PreProposeError, msg::ExecuteMsg as ExecuteBase, state::PreProposeContract,
+    error::PreProposeError,
+    msg::{ExecuteMsg as ExecuteBase, ProposalMessages},
+    state::PreProposeContract,
 };
 use dao_voting::deposit::DepositRefundPolicy;
 use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
 
 use crate::msg::{
     ApproverProposeMessage, ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage,
-    ProposeMessageInternal, QueryExt, QueryMsg,
+    ProposeMessageInternal, QueryExt, QueryMsg, UncheckedApprovalCommittee,
+};
+use crate::state::{
+    advance_approval_id, ApprovalCommittee, ApproverVote, Comment, CompletedProposal,
+    PendingProposal, PendingProposalOutcome, APPROVAL_COMMITTEE, APPROVER, COMMENTS,
+    COMPLETED_PROPOSALS, MAX_COMMENT_LENGTH, PENDING_PROPOSALS, PENDING_PROPOSAL_TIMEOUT,
 };
-use crate::state::{advance_approval_id, PendingProposal, APPROVER, PENDING_PROPOSALS};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approval-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -56,9 +65,21 @@ pub fn execute(
         }
 
         ExecuteMsg::Extension { msg } => match msg {
-            ExecuteExt::Approve { id } => execute_approve(deps, info, id),
-            ExecuteExt::Reject { id } => execute_reject(deps, info, id),
+            ExecuteExt::Approve { id, reason } => execute_approve(deps, env, info, id, reason),
+            ExecuteExt::Reject { id, reason } => execute_reject(deps, env, info, id, reason),
+            ExecuteExt::RejectExpired { id } => execute_reject_expired(deps, env, info, id),
+            ExecuteExt::UpdatePendingProposal { id, msg } => {
+                execute_update_pending_proposal(deps, env, info, id, msg)
+            }
+            ExecuteExt::WithdrawPendingProposal { id } => {
+                execute_withdraw_pending_proposal(deps, info, id)
+            }
+            ExecuteExt::Comment { id, comment } => execute_comment(deps, env, info, id, comment),
             ExecuteExt::UpdateApprover { address } => execute_update_approver(deps, info, address),
+            ExecuteExt::UpdateApprovalConfig {
+                committee,
+                pending_proposal_timeout,
+            } => execute_update_approval_config(deps, info, committee, pending_proposal_timeout),
         },
         // Default pre-propose-base behavior for all other messages
         _ => PrePropose::default().execute(deps, env, info, msg),
@@ -72,32 +93,17 @@ pub fn execute_propose(
     msg: ProposeMessage,
 ) -> Result<Response, PreProposeError> {
     let pre_propose_base = PrePropose::default();
-    let config = pre_propose_base.config.load(deps.storage)?;
 
     pre_propose_base.check_can_submit(deps.as_ref(), info.sender.clone())?;
+    pre_propose_base.record_submission(deps.storage, &env.block, &info.sender)?;
 
     // Take deposit, if configured.
-    let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
-        deposit_info.check_native_deposit_paid(&info)?;
-        deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
-    } else {
-        vec![]
-    };
+    let (deposit, deposit_messages) =
+        pre_propose_base.take_deposit(deps.as_ref(), &env, &info, &msg.cosmos_msgs())?;
 
     let approval_id = advance_approval_id(deps.storage)?;
 
-    let propose_msg_internal = match msg {
-        ProposeMessage::Propose {
-            title,
-            description,
-            msgs,
-        } => ProposeMsg {
-            title,
-            description,
-            msgs,
-            proposer: Some(info.sender.to_string()),
-        },
-    };
+    let propose_msg_internal = into_propose_msg(msg, &info.sender);
 
     // Prepare proposal submitted hooks msg to notify approver.  Make
     // a proposal on the approver DAO to approve this pre-proposal
@@ -127,7 +133,11 @@ pub fn execute_propose(
             approval_id,
             proposer: info.sender,
             msg: propose_msg_internal,
-            deposit: config.deposit_info,
+            deposit,
+            votes: vec![],
+            expiration: PENDING_PROPOSAL_TIMEOUT
+                .may_load(deps.storage)?
+                .map(|timeout| timeout.after(&env.block)),
         },
     )?;
 
@@ -138,81 +148,258 @@ pub fn execute_propose(
         .add_attribute("id", approval_id.to_string()))
 }
 
+/// Returns the weight `who` approves or rejects proposals with, and
+/// the weight needed to approve a proposal.
+fn approver_weight(deps: Deps, who: &Addr) -> Result<(u64, u64), PreProposeError> {
+    Ok(match APPROVAL_COMMITTEE.may_load(deps.storage)? {
+        Some(ApprovalCommittee::Members { members, threshold }) => {
+            (u64::from(members.contains(who)), threshold)
+        }
+        Some(ApprovalCommittee::Cw4Group { group, threshold }) => {
+            let weight = Cw4Contract::new(group)
+                .is_member(&deps.querier, who, None)?
+                .unwrap_or_default();
+            (weight, threshold)
+        }
+        None => (u64::from(APPROVER.load(deps.storage)? == *who), 1),
+    })
+}
+
+/// Returns the total weight of the approvers.
+fn total_approver_weight(deps: Deps) -> Result<u64, PreProposeError> {
+    Ok(match APPROVAL_COMMITTEE.may_load(deps.storage)? {
+        Some(ApprovalCommittee::Members { members, .. }) => members.len() as u64,
+        Some(ApprovalCommittee::Cw4Group { group, .. }) => {
+            Cw4Contract::new(group).total_weight(&deps.querier)?
+        }
+        None => 1,
+    })
+}
+
+/// Records `info.sender`'s approval or rejection of a pending
+/// proposal, returning the proposal with the vote added.
+fn cast_approver_vote(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    id: u64,
+    approve: bool,
+    reason: Option<String>,
+) -> Result<(PendingProposal, u64), PreProposeError> {
+    // Check sender is an approver
+    let (weight, threshold) = approver_weight(deps, &info.sender)?;
+    if weight == 0 {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    let mut proposal = load_unexpired_pending_proposal(deps, env, id)?;
+    if proposal.votes.iter().any(|v| v.approver == info.sender) {
+        return Err(PreProposeError::AlreadyVoted {});
+    }
+
+    proposal.votes.push(ApproverVote {
+        approver: info.sender.clone(),
+        approve,
+        weight,
+        reason,
+    });
+    Ok((proposal, threshold))
+}
+
+/// Loads a pending proposal, erroring if it has expired.
+fn load_unexpired_pending_proposal(
+    deps: Deps,
+    env: &Env,
+    id: u64,
+) -> Result<PendingProposal, PreProposeError> {
+    let proposal = PENDING_PROPOSALS
+        .may_load(deps.storage, id)?
+        .ok_or(PreProposeError::ProposalNotFound {})?;
+    if proposal
+        .expiration
+        .map_or(false, |expiration| expiration.is_expired(&env.block))
+    {
+        return Err(PreProposeError::PendingProposalExpired {});
+    }
+    Ok(proposal)
+}
+
+/// Converts a propose message into the message used to create the
+/// proposal in the proposal module.
+fn into_propose_msg(msg: ProposeMessage, proposer: &Addr) -> ProposeMsg {
+    match msg {
+        ProposeMessage::Propose {
+            title,
+            description,
+            msgs,
+            best_effort,
+        } => ProposeMsg {
+            title,
+            description,
+            msgs,
+            proposer: Some(proposer.to_string()),
+            best_effort,
+        },
+    }
+}
+
+/// Sums the weight of the approvals or rejections on a proposal.
+/// Votes are weighted by their approvers' current weights, so votes
+/// cast by addresses that are no longer approvers do not count.
+fn tally(deps: Deps, proposal: &PendingProposal, approve: bool) -> Result<u64, PreProposeError> {
+    proposal
+        .votes
+        .iter()
+        .filter(|v| v.approve == approve)
+        .try_fold(0, |total, v| {
+            Ok(total + approver_weight(deps, &v.approver)?.0)
+        })
+}
+
 pub fn execute_approve(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     id: u64,
+    reason: Option<String>,
 ) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
-    let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
-        return Err(PreProposeError::Unauthorized {});
+    let (proposal, threshold) =
+        cast_approver_vote(deps.as_ref(), &env, &info, id, true, reason.clone())?;
+
+    if tally(deps.as_ref(), &proposal, true)? < threshold {
+        PENDING_PROPOSALS.save(deps.storage, id, &proposal)?;
+        return Ok(Response::default()
+            .add_attribute("method", "approve")
+            .add_attribute("approval_id", id.to_string())
+            .add_attribute("approver", info.sender));
     }
 
-    // Load proposal and send propose message to the proposal module
-    let proposal = PENDING_PROPOSALS.may_load(deps.storage, id)?;
-    match proposal {
-        Some(proposal) => {
-            let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
-
-            // Snapshot the deposit for the proposal that we're about
-            // to create.
-            let proposal_id = deps.querier.query_wasm_smart(
-                &proposal_module,
-                &dao_interface::proposal::Query::NextProposalId {},
-            )?;
-            PrePropose::default().deposits.save(
-                deps.storage,
-                proposal_id,
-                &(proposal.deposit, proposal.proposer),
-            )?;
+    // Send propose message to the proposal module
+    let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
 
-            let propose_messsage = WasmMsg::Execute {
-                contract_addr: proposal_module.into_string(),
-                msg: to_binary(&ProposeMessageInternal::Propose(proposal.msg))?,
-                funds: vec![],
-            };
-            PENDING_PROPOSALS.remove(deps.storage, id);
-
-            Ok(Response::default()
-                .add_message(propose_messsage)
-                .add_attribute("method", "proposal_approved")
-                .add_attribute("approval_id", id.to_string())
-                .add_attribute("proposal_id", proposal_id.to_string()))
-        }
-        None => Err(PreProposeError::ProposalNotFound {}),
-    }
+    // Snapshot the deposit for the proposal that we're about
+    // to create.
+    let proposal_id = deps.querier.query_wasm_smart(
+        &proposal_module,
+        &dao_interface::proposal::Query::NextProposalId {},
+    )?;
+    PrePropose::default().deposits.save(
+        deps.storage,
+        proposal_id,
+        &(proposal.deposit.clone(), proposal.proposer.clone()),
+    )?;
+
+    let propose_messsage = WasmMsg::Execute {
+        contract_addr: proposal_module.into_string(),
+        msg: to_binary(&ProposeMessageInternal::Propose(proposal.msg.clone()))?,
+        funds: vec![],
+    };
+    PENDING_PROPOSALS.remove(deps.storage, id);
+    COMPLETED_PROPOSALS.save(
+        deps.storage,
+        id,
+        &CompletedProposal {
+            proposal,
+            outcome: PendingProposalOutcome::Approved { proposal_id },
+            completed_by: info.sender,
+            reason,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_message(propose_messsage)
+        .add_attribute("method", "proposal_approved")
+        .add_attribute("approval_id", id.to_string())
+        .add_attribute("proposal_id", proposal_id.to_string()))
 }
 
 pub fn execute_reject(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     id: u64,
+    reason: Option<String>,
 ) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
-    let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
-        return Err(PreProposeError::Unauthorized {});
+    let (proposal, threshold) =
+        cast_approver_vote(deps.as_ref(), &env, &info, id, false, reason.clone())?;
+
+    // The proposal is rejected once the approvers that have not
+    // rejected it no longer have enough weight to approve it.
+    let remaining = total_approver_weight(deps.as_ref())?.saturating_sub(tally(
+        deps.as_ref(),
+        &proposal,
+        false,
+    )?);
+    if remaining >= threshold {
+        PENDING_PROPOSALS.save(deps.storage, id, &proposal)?;
+        return Ok(Response::default()
+            .add_attribute("method", "reject")
+            .add_attribute("approval_id", id.to_string())
+            .add_attribute("approver", info.sender));
     }
 
-    let PendingProposal {
-        deposit, proposer, ..
-    } = PENDING_PROPOSALS
+    reject_pending_proposal(
+        deps,
+        CompletedProposal {
+            proposal,
+            outcome: PendingProposalOutcome::Rejected,
+            completed_by: info.sender,
+            reason,
+        },
+    )
+}
+
+pub fn execute_reject_expired(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, PreProposeError> {
+    let proposal = PENDING_PROPOSALS
         .may_load(deps.storage, id)?
         .ok_or(PreProposeError::ProposalNotFound {})?;
+    if !proposal
+        .expiration
+        .map_or(false, |expiration| expiration.is_expired(&env.block))
+    {
+        return Err(PreProposeError::PendingProposalNotExpired {});
+    }
+
+    reject_pending_proposal(
+        deps,
+        CompletedProposal {
+            proposal,
+            outcome: PendingProposalOutcome::Expired,
+            completed_by: info.sender,
+            reason: None,
+        },
+    )
+}
+
+/// Removes a rejected pending proposal, records it as completed, and
+/// refunds or forfeits its deposit.
+fn reject_pending_proposal(
+    deps: DepsMut,
+    completed: CompletedProposal,
+) -> Result<Response, PreProposeError> {
+    let id = completed.proposal.approval_id;
+    let proposer = completed.proposal.proposer.clone();
+    let deposit = completed.proposal.deposit.clone();
 
     PENDING_PROPOSALS.remove(deps.storage, id);
+    COMPLETED_PROPOSALS.save(deps.storage, id, &completed)?;
+    PrePropose::default().record_completion(deps.storage, &proposer)?;
 
     let messages = if let Some(ref deposit_info) = deposit {
         // Refund can be issued if proposal if deposits are always
-        // refunded. `OnlyPassed` and `Never` refund deposit policies
-        // do not apply here.
+        // refunded. `OnlyPassed`, `OnlyQuorum`, and `Never` refund
+        // deposit policies do not apply here.
         if deposit_info.refund_policy == DepositRefundPolicy::Always {
             deposit_info.get_return_deposit_message(&proposer)?
         } else {
-            // If the proposer doesn't get the deposit, the DAO does.
-            let dao = PrePropose::default().dao.load(deps.storage)?;
-            deposit_info.get_return_deposit_message(&dao)?
+            // Rejected proposals were never voted on, so there are no
+            // voters to forfeit the deposit to.
+            PrePropose::default().get_forfeit_deposit_messages(deps, deposit_info, None)?
         }
     } else {
         vec![]
@@ -225,6 +412,138 @@ pub fn execute_reject(
         .add_messages(messages))
 }
 
+pub fn execute_update_pending_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    msg: ProposeMessage,
+) -> Result<Response, PreProposeError> {
+    let mut proposal = load_unexpired_pending_proposal(deps.as_ref(), &env, id)?;
+    if proposal.proposer != info.sender {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    let pre_propose_base = PrePropose::default();
+    pre_propose_base.check_can_submit(deps.as_ref(), info.sender.clone())?;
+
+    // Hook receivers, such as an approver DAO, were notified of the
+    // proposal's old contents and have no way to learn of new ones,
+    // so an approval given for the old contents would carry over.
+    if !pre_propose_base
+        .proposal_submitted_hooks
+        .query_hooks(deps.as_ref())?
+        .hooks
+        .is_empty()
+    {
+        return Err(PreProposeError::PendingProposalUpdateWithHooks {});
+    }
+
+    // Refund the deposit paid for the proposal and take a deposit
+    // for the updated one, as the updated proposal's deposit may be
+    // priced differently.
+    let refund_messages = match proposal.deposit {
+        Some(ref deposit_info) => deposit_info.get_return_deposit_message(&proposal.proposer)?,
+        None => vec![],
+    };
+    let (deposit, deposit_messages) =
+        pre_propose_base.take_deposit(deps.as_ref(), &env, &info, &msg.cosmos_msgs())?;
+
+    proposal.msg = into_propose_msg(msg, &info.sender);
+    proposal.deposit = deposit;
+    // Approvals and rejections were cast on the old contents.
+    proposal.votes = vec![];
+    PENDING_PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::default()
+        .add_messages(deposit_messages)
+        .add_messages(refund_messages)
+        .add_attribute("method", "update_pending_proposal")
+        .add_attribute("approval_id", id.to_string()))
+}
+
+pub fn execute_withdraw_pending_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, PreProposeError> {
+    let proposal = PENDING_PROPOSALS
+        .may_load(deps.storage, id)?
+        .ok_or(PreProposeError::ProposalNotFound {})?;
+    if proposal.proposer != info.sender {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    // Withdrawn proposals are always refunded, regardless of the
+    // refund policy.
+    let messages = match proposal.deposit {
+        Some(ref deposit_info) => deposit_info.get_return_deposit_message(&proposal.proposer)?,
+        None => vec![],
+    };
+
+    PENDING_PROPOSALS.remove(deps.storage, id);
+    PrePropose::default().record_completion(deps.storage, &proposal.proposer)?;
+    COMPLETED_PROPOSALS.save(
+        deps.storage,
+        id,
+        &CompletedProposal {
+            proposal,
+            outcome: PendingProposalOutcome::Withdrawn,
+            completed_by: info.sender,
+            reason: None,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_messages(messages)
+        .add_attribute("method", "withdraw_pending_proposal")
+        .add_attribute("approval_id", id.to_string()))
+}
+
+pub fn execute_comment(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    comment: String,
+) -> Result<Response, PreProposeError> {
+    let proposal = PENDING_PROPOSALS
+        .may_load(deps.storage, id)?
+        .ok_or(PreProposeError::ProposalNotFound {})?;
+
+    // Check sender is the proposer or an approver
+    if proposal.proposer != info.sender && approver_weight(deps.as_ref(), &info.sender)?.0 == 0 {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    if comment.len() > MAX_COMMENT_LENGTH {
+        return Err(PreProposeError::CommentTooLong {
+            max: MAX_COMMENT_LENGTH,
+        });
+    }
+
+    let index = COMMENTS
+        .prefix(id)
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last| last + 1);
+    COMMENTS.save(
+        deps.storage,
+        (id, index),
+        &Comment {
+            author: info.sender.clone(),
+            comment,
+            time: env.block.time,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "comment")
+        .add_attribute("approval_id", id.to_string())
+        .add_attribute("author", info.sender))
+}
+
 pub fn execute_update_approver(
     deps: DepsMut,
     info: MessageInfo,
@@ -243,6 +562,64 @@ pub fn execute_update_approver(
     Ok(Response::default())
 }
 
+pub fn execute_update_approval_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    committee: Option<UncheckedApprovalCommittee>,
+    pending_proposal_timeout: Option<Duration>,
+) -> Result<Response, PreProposeError> {
+    let dao = PrePropose::default().dao.load(deps.storage)?;
+    let approver = APPROVER.load(deps.storage)?;
+
+    // Only the DAO may install, change, or remove an approval
+    // committee. The approver may only update the timeout, and only
+    // while there is no committee.
+    if info.sender != dao
+        && (info.sender != approver
+            || committee.is_some()
+            || APPROVAL_COMMITTEE.may_load(deps.storage)?.is_some())
+    {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    match committee {
+        Some(UncheckedApprovalCommittee::Members { members, threshold }) => {
+            let mut members = members
+                .into_iter()
+                .map(|m| deps.api.addr_validate(&m))
+                .collect::<StdResult<Vec<_>>>()?;
+            members.sort();
+            members.dedup();
+            if threshold == 0 || threshold > members.len() as u64 {
+                return Err(PreProposeError::InvalidApprovalThreshold {});
+            }
+            APPROVAL_COMMITTEE.save(
+                deps.storage,
+                &ApprovalCommittee::Members { members, threshold },
+            )?;
+        }
+        Some(UncheckedApprovalCommittee::Cw4Group { group, threshold }) => {
+            let group = deps.api.addr_validate(&group)?;
+            let total_weight = Cw4Contract::new(group.clone()).total_weight(&deps.querier)?;
+            if threshold == 0 || threshold > total_weight {
+                return Err(PreProposeError::InvalidApprovalThreshold {});
+            }
+            APPROVAL_COMMITTEE.save(
+                deps.storage,
+                &ApprovalCommittee::Cw4Group { group, threshold },
+            )?;
+        }
+        None => APPROVAL_COMMITTEE.remove(deps.storage),
+    }
+
+    match pending_proposal_timeout {
+        Some(timeout) => PENDING_PROPOSAL_TIMEOUT.save(deps.storage, &timeout)?,
+        None => PENDING_PROPOSAL_TIMEOUT.remove(deps.storage),
+    }
+
+    Ok(Response::default().add_attribute("method", "update_approval_config"))
+}
+
 pub fn execute_add_approver_hook(
     deps: DepsMut,
     info: MessageInfo,
@@ -317,7 +694,52 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 limit,
                 Order::Ascending,
             )?),
+            QueryExt::ApprovalCommittee {} => {
+                to_binary(&APPROVAL_COMMITTEE.may_load(deps.storage)?)
+            }
+            QueryExt::PendingProposalTimeout {} => {
+                to_binary(&PENDING_PROPOSAL_TIMEOUT.may_load(deps.storage)?)
+            }
+            QueryExt::ApproverVotes { id } => {
+                to_binary(&PENDING_PROPOSALS.load(deps.storage, id)?.votes)
+            }
+            QueryExt::CompletedProposal { id } => {
+                to_binary(&COMPLETED_PROPOSALS.load(deps.storage, id)?)
+            }
+            QueryExt::CompletedProposals { start_after, limit } => to_binary(&paginate_map_values(
+                deps,
+                &COMPLETED_PROPOSALS,
+                start_after,
+                limit,
+                Order::Descending,
+            )?),
+            QueryExt::Comments {
+                id,
+                start_after,
+                limit,
+            } => to_binary(&query_comments(deps, id, start_after, limit)?),
         },
         _ => PrePropose::default().query(deps, env, msg),
     }
 }
+
+pub fn query_comments(
+    deps: Deps,
+    id: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Comment>> {
+    let comments = COMMENTS
+        .prefix(id)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|kv| Ok(kv?.1));
+    match limit {
+        Some(limit) => comments.take(limit as usize).collect(),
+        None => comments.collect(),
+    }
+}
@@ -1,7 +1,9 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::Duration;
 use dao_pre_propose_base::msg::{
-    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
+    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, ProposalMessages,
+    QueryMsg as QueryBase,
 };
 use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
 
@@ -20,22 +22,89 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
+        /// If true, each of the proposal's messages is executed on
+        /// its own when the proposal is executed, and messages that
+        /// fail do not prevent the others from executing.
+        #[serde(default)]
+        best_effort: bool,
     },
 }
 
+impl ProposalMessages for ApproverProposeMessage {
+    fn cosmos_msgs(&self) -> Vec<CosmosMsg> {
+        // Proposals to approve a pre-propose execute nothing.
+        vec![]
+    }
+}
+
+impl ProposalMessages for ProposeMessage {
+    fn cosmos_msgs(&self) -> Vec<CosmosMsg> {
+        match self {
+            ProposeMessage::Propose { msgs, .. } => msgs.clone(),
+        }
+    }
+}
+
 #[cw_serde]
 pub struct InstantiateExt {
     pub approver: String,
 }
 
+/// A committee whose approval is required for pending proposals to
+/// be created.
+#[cw_serde]
+pub enum UncheckedApprovalCommittee {
+    /// At least `threshold` of `members` must approve.
+    Members {
+        members: Vec<String>,
+        threshold: u64,
+    },
+    /// Members of the cw4 `group` whose total weight is at least
+    /// `threshold` must approve.
+    Cw4Group { group: String, threshold: u64 },
+}
+
 #[cw_serde]
 pub enum ExecuteExt {
-    /// Approve a proposal, only callable by approver
-    Approve { id: u64 },
-    /// Reject a proposal, only callable by approver
-    Reject { id: u64 },
+    /// Approve a proposal, only callable by approver, or members of
+    /// the approval committee if one is set. The proposal is created
+    /// once the approval threshold is met.
+    Approve { id: u64, reason: Option<String> },
+    /// Reject a proposal, only callable by approver, or members of
+    /// the approval committee if one is set. The proposal is rejected
+    /// once the approval threshold can no longer be met.
+    Reject { id: u64, reason: Option<String> },
+    /// Rejects a pending proposal that has expired. Callable by
+    /// anyone.
+    RejectExpired { id: u64 },
+    /// Replaces the contents of a pending proposal, only callable by
+    /// its proposer. The deposit paid for the proposal is refunded
+    /// and a deposit for the updated proposal is taken. Approvals and
+    /// rejections cast on the proposal are cleared. Fails if the
+    /// proposer may no longer submit proposals, or if proposal
+    /// submitted hooks are registered, as hook receivers are not told
+    /// of the update.
+    UpdatePendingProposal { id: u64, msg: ProposeMessage },
+    /// Withdraws a pending proposal and refunds its deposit, only
+    /// callable by its proposer.
+    WithdrawPendingProposal { id: u64 },
+    /// Leaves a comment on a pending proposal, only callable by its
+    /// proposer and approvers. Comments may be at most
+    /// `MAX_COMMENT_LENGTH` bytes long.
+    Comment { id: u64, comment: String },
     /// Updates the approver, can only be called the current approver
     UpdateApprover { address: String },
+    /// Updates the approval committee and how long pending proposals
+    /// may wait for approval before they may be rejected. If
+    /// `committee` is set, it replaces the approver when approving
+    /// and rejecting proposals. The timeout only applies to proposals
+    /// created after it is updated. Only the DAO may set or remove
+    /// the committee. The approver may update the timeout while there
+    /// is no committee.
+    UpdateApprovalConfig {
+        committee: Option<UncheckedApprovalCommittee>,
+        pending_proposal_timeout: Option<Duration>,
+    },
 }
 
 #[cw_serde]
@@ -58,6 +127,36 @@ pub enum QueryExt {
         start_before: Option<u64>,
         limit: Option<u32>,
     },
+    /// The approval committee, if one is set
+    #[returns(Option<crate::state::ApprovalCommittee>)]
+    ApprovalCommittee {},
+    /// How long pending proposals may wait for approval, if limited
+    #[returns(Option<cw_utils::Duration>)]
+    PendingProposalTimeout {},
+    /// The approvers that have approved or rejected a pending
+    /// proposal
+    #[returns(Vec<crate::state::ApproverVote>)]
+    ApproverVotes { id: u64 },
+    /// A pending proposal that has been approved, rejected, or
+    /// withdrawn
+    #[returns(crate::state::CompletedProposal)]
+    CompletedProposal { id: u64 },
+    /// List of pending proposals that have been approved, rejected,
+    /// or withdrawn
+    #[returns(Vec<crate::state::CompletedProposal>)]
+    CompletedProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Comments left on a proposal, oldest first. `start_after` is
+    /// the position of the last comment returned, with the first
+    /// comment at position zero.
+    #[returns(Vec<crate::state::Comment>)]
+    Comments {
+        id: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
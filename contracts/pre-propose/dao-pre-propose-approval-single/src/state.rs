@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdResult, Storage};
+use cosmwasm_std::{Addr, StdResult, Storage, Timestamp};
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
 
 use dao_voting::deposit::CheckedDepositInfo;
 use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
@@ -17,10 +18,92 @@ pub struct PendingProposal {
     /// Snapshot of the deposit info at the time of proposal
     /// submission.
     pub deposit: Option<CheckedDepositInfo>,
+    /// Approvals and rejections cast on this proposal.
+    #[serde(default)]
+    pub votes: Vec<ApproverVote>,
+    /// When this proposal may be rejected if it has not been
+    /// approved. `None` if it may wait for approval indefinitely.
+    #[serde(default)]
+    pub expiration: Option<Expiration>,
+}
+
+/// A comment left on a pending proposal.
+#[cw_serde]
+pub struct Comment {
+    pub author: Addr,
+    pub comment: String,
+    /// The block time the comment was left at.
+    pub time: Timestamp,
+}
+
+/// An approval or rejection of a pending proposal.
+#[cw_serde]
+pub struct ApproverVote {
+    pub approver: Addr,
+    /// True if the approver approved the proposal, false if they
+    /// rejected it.
+    pub approve: bool,
+    /// The weight of the approver when they voted. Tallies use the
+    /// approver's current weight instead.
+    pub weight: u64,
+    /// The reason given by the approver, if any.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// How a pending proposal was completed.
+#[cw_serde]
+pub enum PendingProposalOutcome {
+    /// The proposal was approved, creating the proposal with ID
+    /// `proposal_id` in the proposal module.
+    Approved { proposal_id: u64 },
+    /// The proposal was rejected by its approvers.
+    Rejected,
+    /// The proposal was rejected after it expired.
+    Expired,
+    /// The proposal was withdrawn by its proposer.
+    Withdrawn,
+}
+
+/// A pending proposal that has been approved, rejected, or withdrawn.
+#[cw_serde]
+pub struct CompletedProposal {
+    /// The pending proposal at the time it was completed.
+    pub proposal: PendingProposal,
+    pub outcome: PendingProposalOutcome,
+    /// The address whose approval, rejection, or withdrawal completed
+    /// the proposal.
+    pub completed_by: Addr,
+    /// The reason given by `completed_by`, if any.
+    pub reason: Option<String>,
+}
+
+/// A committee whose approval is required for pending proposals to
+/// be created.
+#[cw_serde]
+pub enum ApprovalCommittee {
+    /// At least `threshold` of `members` must approve.
+    Members { members: Vec<Addr>, threshold: u64 },
+    /// Members of the cw4 `group` whose total weight is at least
+    /// `threshold` must approve.
+    Cw4Group { group: Addr, threshold: u64 },
 }
 
 pub const APPROVER: Item<Addr> = Item::new("approver");
+/// If set, replaces `APPROVER` when approving and rejecting
+/// proposals.
+pub const APPROVAL_COMMITTEE: Item<ApprovalCommittee> = Item::new("approval_committee");
+/// How long pending proposals may wait for approval before they may
+/// be rejected. If unset, they may wait indefinitely.
+pub const PENDING_PROPOSAL_TIMEOUT: Item<Duration> = Item::new("pending_proposal_timeout");
 pub const PENDING_PROPOSALS: Map<u64, PendingProposal> = Map::new("pending_proposals");
+pub const COMPLETED_PROPOSALS: Map<u64, CompletedProposal> = Map::new("completed_proposals");
+/// Comments left on pending proposals, keyed by approval ID and the
+/// comment's position among the proposal's comments.
+pub const COMMENTS: Map<(u64, u64), Comment> = Map::new("comments");
+
+/// The maximum length, in bytes, of a comment.
+pub const MAX_COMMENT_LENGTH: usize = 1024;
 
 /// Used internally to track the current approval_id.
 const CURRENT_ID: Item<u64> = Item::new("current_id");
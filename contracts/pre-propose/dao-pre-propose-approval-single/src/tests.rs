@@ -6,7 +6,10 @@ use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError, msg::DepositInfoResponse, state::Config,
+    submission::UncheckedSubmissionPolicy,
+};
 use dao_proposal_single::query::ProposalResponse;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
@@ -17,7 +20,14 @@ use dao_voting::{
     voting::Vote,
 };
 
-use crate::{contract::*, msg::*, state::PendingProposal};
+use crate::{
+    contract::*,
+    msg::*,
+    state::{
+        ApprovalCommittee, ApproverVote, Comment, CompletedProposal, PendingProposal,
+        PendingProposalOutcome, MAX_COMMENT_LENGTH,
+    },
+};
 
 fn cw_dao_proposal_single_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -75,6 +85,7 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        veto: None,
     }
 }
 
@@ -182,6 +193,7 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                best_effort: false,
             },
         },
         funds,
@@ -389,7 +401,10 @@ fn approve_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64)
             Addr::unchecked(sender),
             module,
             &ExecuteMsg::Extension {
-                msg: ExecuteExt::Approve { id: proposal_id },
+                msg: ExecuteExt::Approve {
+                    id: proposal_id,
+                    reason: None,
+                },
             },
             &[],
         )
@@ -406,7 +421,10 @@ fn reject_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64)
         Addr::unchecked(sender),
         module,
         &ExecuteMsg::Extension {
-            msg: ExecuteExt::Reject { id: proposal_id },
+            msg: ExecuteExt::Reject {
+                id: proposal_id,
+                reason: None,
+            },
         },
         &[],
     )
@@ -998,6 +1016,7 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    best_effort: false,
                 },
             },
             &[],
@@ -1042,7 +1061,10 @@ fn test_approval_and_rejection_permissions() {
             Addr::unchecked("nonmember"),
             pre_propose.clone(),
             &ExecuteMsg::Extension {
-                msg: ExecuteExt::Approve { id: pre_propose_id },
+                msg: ExecuteExt::Approve {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             },
             &[],
         )
@@ -1057,7 +1079,10 @@ fn test_approval_and_rejection_permissions() {
             Addr::unchecked("nonmember"),
             pre_propose,
             &ExecuteMsg::Extension {
-                msg: ExecuteExt::Reject { id: pre_propose_id },
+                msg: ExecuteExt::Reject {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             },
             &[],
         )
@@ -1146,6 +1171,7 @@ fn test_no_deposit_required_members_submission() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    best_effort: false,
                 },
             },
             &[],
@@ -1204,6 +1230,7 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            veto: None,
         }
     };
 
@@ -1267,6 +1294,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            veto: None,
         }
     };
 
@@ -1548,3 +1576,760 @@ fn test_withdraw() {
     let balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
     assert_eq!(balance, Uint128::new(30));
 }
+
+fn update_approval_config(
+    app: &mut App,
+    module: Addr,
+    sender: &str,
+    committee: Option<UncheckedApprovalCommittee>,
+    pending_proposal_timeout: Option<Duration>,
+) -> Result<(), PreProposeError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateApprovalConfig {
+                committee,
+                pending_proposal_timeout,
+            },
+        },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn approver_vote(
+    app: &mut App,
+    module: Addr,
+    sender: &str,
+    msg: ExecuteExt,
+) -> Result<(), PreProposeError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension { msg },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
+
+#[test]
+fn test_approval_committee() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+
+    let committee = |threshold| UncheckedApprovalCommittee::Members {
+        members: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        threshold,
+    };
+
+    // Only the DAO may update the committee.
+    let err = update_approval_config(&mut app, pre_propose.clone(), "a", Some(committee(2)), None)
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    let err = update_approval_config(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        Some(committee(2)),
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // Thresholds must be reachable.
+    let err = update_approval_config(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(committee(4)),
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::InvalidApprovalThreshold {});
+
+    update_approval_config(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(committee(2)),
+        None,
+    )
+    .unwrap();
+    let stored: Option<ApprovalCommittee> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ApprovalCommittee {},
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stored,
+        Some(ApprovalCommittee::Members {
+            members: vec![
+                Addr::unchecked("a"),
+                Addr::unchecked("b"),
+                Addr::unchecked("c")
+            ],
+            threshold: 2,
+        })
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    // Once a committee is set, the approver may not remove it or
+    // update the timeout.
+    let err =
+        update_approval_config(&mut app, pre_propose.clone(), "approver", None, None).unwrap_err();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // The committee replaces the approver.
+    let err = approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "a",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap();
+    let err = approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "a",
+        ExecuteExt::Reject { id, reason: None },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::AlreadyVoted {});
+
+    let votes: Vec<ApproverVote> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ApproverVotes { id },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        votes,
+        vec![ApproverVote {
+            approver: Addr::unchecked("a"),
+            approve: true,
+            weight: 1,
+            reason: None,
+        }]
+    );
+
+    // The second approval creates the proposal.
+    let proposal_id = approve_proposal(&mut app, pre_propose.clone(), "b", id);
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+
+    // Two of three rejections make approval impossible, rejecting
+    // the proposal and refunding the deposit.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "a",
+        ExecuteExt::Reject { id, reason: None },
+    )
+    .unwrap();
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "b",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "c",
+        ExecuteExt::Reject { id, reason: None },
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    let err = approver_vote(
+        &mut app,
+        pre_propose,
+        "b",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::ProposalNotFound {});
+}
+
+// Votes count with their approvers' current weights, so votes cast by
+// members that have left the committee no longer count.
+#[test]
+fn test_approval_committee_member_removed() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let committee = |members: &[&str]| UncheckedApprovalCommittee::Members {
+        members: members.iter().map(|m| m.to_string()).collect(),
+        threshold: 2,
+    };
+    update_approval_config(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(committee(&["a", "b", "c"])),
+        None,
+    )
+    .unwrap();
+
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "a",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap();
+
+    // "a" leaves the committee.
+    update_approval_config(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(committee(&["b", "c", "d"])),
+        None,
+    )
+    .unwrap();
+
+    // "a"'s approval no longer counts, so one more approval does not
+    // create the proposal.
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "b",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap();
+    let pending: PendingProposal = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposal { id },
+            },
+        )
+        .unwrap();
+    assert_eq!(pending.votes.len(), 2);
+
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "c",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap();
+    let completed: CompletedProposal = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::CompletedProposal { id },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        completed.outcome,
+        PendingProposalOutcome::Approved { proposal_id: 1 }
+    );
+}
+
+#[test]
+fn test_pending_proposal_timeout() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+
+    update_approval_config(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        None,
+        Some(Duration::Height(10)),
+    )
+    .unwrap();
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    let err = approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "anyone",
+        ExecuteExt::RejectExpired { id },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::PendingProposalNotExpired {});
+
+    app.update_block(|block| block.height += 10);
+
+    let err = approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::PendingProposalExpired {});
+
+    // Anyone may reject an expired proposal, refunding the deposit
+    // per the refund policy.
+    approver_vote(
+        &mut app,
+        pre_propose,
+        "anyone",
+        ExecuteExt::RejectExpired { id },
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+}
+
+fn query_completed_proposal(app: &App, module: Addr, id: u64) -> CompletedProposal {
+    app.wrap()
+        .query_wasm_smart(
+            module,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::CompletedProposal { id },
+            },
+        )
+        .unwrap()
+}
+
+fn query_pending_proposal(app: &App, module: Addr, id: u64) -> PendingProposal {
+    app.wrap()
+        .query_wasm_smart(
+            module,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposal { id },
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_update_pending_proposal() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Never,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+
+    let update = ExecuteExt::UpdatePendingProposal {
+        id,
+        msg: ProposeMessage::Propose {
+            title: "updated".to_string(),
+            description: "updated description".to_string(),
+            msgs: vec![],
+            best_effort: false,
+        },
+    };
+
+    // Only the proposer may update a pending proposal.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("keze"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: update.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // Proposers who may no longer submit proposals may not update
+    // them either.
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateSubmissionPolicy {
+            policy: UncheckedSubmissionPolicy {
+                denylist: vec!["ekez".to_string()],
+                ..Default::default()
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: update.clone(),
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotAllowedProposer {});
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateSubmissionPolicy {
+            policy: UncheckedSubmissionPolicy::default(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The old deposit is refunded and a new one is taken.
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &ExecuteMsg::Extension { msg: update },
+        &coins(10, "ujuno"),
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+
+    let pending = query_pending_proposal(&app, pre_propose.clone(), id);
+    assert_eq!(pending.msg.title, "updated");
+    assert_eq!(pending.msg.description, "updated description");
+
+    // The updated proposal is the one created on approval.
+    let proposal_id = approve_proposal(&mut app, pre_propose.clone(), "approver", id);
+    let completed = query_completed_proposal(&app, pre_propose, id);
+    assert_eq!(completed.proposal.msg.title, "updated");
+    assert_eq!(
+        completed.outcome,
+        PendingProposalOutcome::Approved { proposal_id }
+    );
+}
+
+#[test]
+fn test_withdraw_pending_proposal() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Never,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    let err = approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::WithdrawPendingProposal { id },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // Withdrawn proposals are refunded even though the refund policy
+    // is never.
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::WithdrawPendingProposal { id },
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+
+    let err = approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve { id, reason: None },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::ProposalNotFound {});
+
+    let completed = query_completed_proposal(&app, pre_propose, id);
+    assert_eq!(completed.outcome, PendingProposalOutcome::Withdrawn);
+    assert_eq!(completed.completed_by, Addr::unchecked("ekez"));
+}
+
+#[test]
+fn test_completed_proposal_history() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let rejected = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let approved = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Reject {
+            id: rejected,
+            reason: Some("out of scope".to_string()),
+        },
+    )
+    .unwrap();
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve {
+            id: approved,
+            reason: Some("looks good".to_string()),
+        },
+    )
+    .unwrap();
+
+    let completed: Vec<CompletedProposal> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::CompletedProposals {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(completed.len(), 2);
+
+    // Most recent first.
+    assert_eq!(completed[0].proposal.approval_id, approved);
+    assert_eq!(
+        completed[0].outcome,
+        PendingProposalOutcome::Approved { proposal_id: 1 }
+    );
+    assert_eq!(completed[0].completed_by, Addr::unchecked("approver"));
+    assert_eq!(completed[0].reason, Some("looks good".to_string()));
+
+    assert_eq!(completed[1].proposal.approval_id, rejected);
+    assert_eq!(completed[1].outcome, PendingProposalOutcome::Rejected);
+    assert_eq!(completed[1].reason, Some("out of scope".to_string()));
+}
+
+#[test]
+fn test_pending_proposal_comments() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Comment {
+            id,
+            comment: "please add a timeline".to_string(),
+        },
+    )
+    .unwrap();
+    approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Comment {
+            id,
+            comment: "added".to_string(),
+        },
+    )
+    .unwrap();
+
+    // Only the proposer and approvers may comment.
+    let err = approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "keze",
+        ExecuteExt::Comment {
+            id,
+            comment: "spam".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // Comments are limited in length.
+    let err = approver_vote(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Comment {
+            id,
+            comment: "a".repeat(MAX_COMMENT_LENGTH + 1),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::CommentTooLong {
+            max: MAX_COMMENT_LENGTH
+        }
+    );
+
+    let comments: Vec<(Addr, String)> =
+        query_pending_comments(&app, pre_propose.clone(), id, None, None)
+            .into_iter()
+            .map(|c| (c.author, c.comment))
+            .collect();
+    assert_eq!(
+        comments,
+        vec![
+            (
+                Addr::unchecked("approver"),
+                "please add a timeline".to_string()
+            ),
+            (Addr::unchecked("ekez"), "added".to_string()),
+        ]
+    );
+
+    let comments = query_pending_comments(&app, pre_propose, id, Some(0), Some(1));
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].comment, "added");
+}
+
+fn query_pending_comments(
+    app: &App,
+    module: Addr,
+    id: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Vec<Comment> {
+    app.wrap()
+        .query_wasm_smart(
+            module,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Comments {
+                    id,
+                    start_after,
+                    limit,
+                },
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_approved_proposal_msgs_may_not_be_amended() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let proposal_id = approve_proposal(&mut app, pre_propose, "approver", id);
+
+    // The approver approved the proposal's messages, so the proposer
+    // may not replace them.
+    let err: dao_proposal_single::ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_single,
+            &dao_proposal_single::msg::ExecuteMsg::Amend {
+                proposal_id,
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![cosmwasm_std::BankMsg::Send {
+                    to_address: "ekez".to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        dao_proposal_single::ContractError::AmendMsgsWithPreProposeModule {}
+    ));
+}
@@ -154,17 +154,23 @@ pub fn execute_proposal_completed(
 
     // On completion send rejection or approval message
     let msg = match new_status {
-        Status::Closed => Some(WasmMsg::Execute {
+        Status::Closed | Status::Vetoed => Some(WasmMsg::Execute {
             contract_addr: approval_contract.into_string(),
             msg: to_binary(&PreProposeApprovalExecuteMsg::Extension {
-                msg: ApprovalExt::Reject { id: pre_propose_id },
+                msg: ApprovalExt::Reject {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             })?,
             funds: vec![],
         }),
         Status::Executed => Some(WasmMsg::Execute {
             contract_addr: approval_contract.into_string(),
             msg: to_binary(&PreProposeApprovalExecuteMsg::Extension {
-                msg: ApprovalExt::Approve { id: pre_propose_id },
+                msg: ApprovalExt::Approve {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             })?,
             funds: vec![],
         }),
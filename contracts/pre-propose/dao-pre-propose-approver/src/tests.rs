@@ -11,7 +11,7 @@ use dao_pre_propose_approval_single::{
     msg::{
         ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg,
     },
-    state::PendingProposal,
+    state::{CompletedProposal, PendingProposal},
 };
 use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
 use dao_proposal_single as cps;
@@ -99,6 +99,7 @@ fn get_proposal_module_approval_single_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        veto: None,
     }
 }
 
@@ -130,6 +131,7 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        veto: None,
     }
 }
 
@@ -301,6 +303,7 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                best_effort: false,
             },
         },
         funds,
@@ -1143,6 +1146,7 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    best_effort: false,
                 },
             },
             &[],
@@ -1194,7 +1198,10 @@ fn test_approval_and_rejection_permissions() {
             Addr::unchecked("nonmember"),
             pre_propose.clone(),
             &ExecuteMsg::Extension {
-                msg: ExecuteExt::Approve { id: pre_propose_id },
+                msg: ExecuteExt::Approve {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             },
             &[],
         )
@@ -1209,7 +1216,10 @@ fn test_approval_and_rejection_permissions() {
             Addr::unchecked("nonmember"),
             pre_propose,
             &ExecuteMsg::Extension {
-                msg: ExecuteExt::Reject { id: pre_propose_id },
+                msg: ExecuteExt::Reject {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             },
             &[],
         )
@@ -1541,3 +1551,56 @@ fn test_withdraw() {
     let balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
     assert_eq!(balance, Uint128::new(30));
 }
+
+#[test]
+fn test_update_pending_proposal_with_approver() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+        _approver_core_addr: _,
+        proposal_single_approver,
+        pre_propose_approver: _,
+    } = setup_default_test(&mut app, None, true);
+
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let approver_prop_id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+
+    // The approver DAO was only told of the proposal's original
+    // contents, so the proposer may not replace them.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdatePendingProposal {
+                    id: pre_propose_id,
+                    msg: ProposeMessage::Propose {
+                        title: "updated".to_string(),
+                        description: "updated description".to_string(),
+                        msgs: vec![],
+                        best_effort: false,
+                    },
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::PendingProposalUpdateWithHooks {});
+
+    // Approving creates the proposal the approver DAO reviewed.
+    approve_proposal(&mut app, proposal_single_approver, "ekez", approver_prop_id);
+    let completed: CompletedProposal = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::CompletedProposal { id: pre_propose_id },
+            },
+        )
+        .unwrap();
+    assert_eq!(completed.proposal.msg.title, "title");
+}
@@ -1,12 +1,17 @@
 use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+};
 use cw2::set_contract_version;
 
 use dao_pre_propose_base::{
     error::PreProposeError,
-    msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
+    msg::{
+        ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, ProposalMessages,
+        QueryMsg as QueryBase,
+    },
     state::PreProposeContract,
 };
 use dao_voting::multiple_choice::MultipleChoiceOptions;
@@ -40,6 +45,18 @@ enum ProposeMessageInternal {
     },
 }
 
+impl ProposalMessages for ProposeMessageInternal {
+    fn cosmos_msgs(&self) -> Vec<CosmosMsg> {
+        match self {
+            ProposeMessageInternal::Propose { choices, .. } => choices
+                .options
+                .iter()
+                .flat_map(|option| option.msgs.clone())
+                .collect(),
+        }
+    }
+}
+
 type PrePropose = PreProposeContract<Empty, Empty, Empty, ProposeMessageInternal>;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -91,6 +108,12 @@ pub fn execute(
             deposit_info,
             open_proposal_submission,
         },
+        ExecuteMsg::UpdateSubmissionPolicy { policy } => {
+            ExecuteInternal::UpdateSubmissionPolicy { policy }
+        }
+        ExecuteMsg::UpdateDepositOptions { options } => {
+            ExecuteInternal::UpdateDepositOptions { options }
+        }
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
         }
@@ -104,6 +127,9 @@ pub fn execute(
             proposal_id,
             new_status,
         },
+        ExecuteMsg::ClaimForfeitedDeposit { proposal_id } => {
+            ExecuteInternal::ClaimForfeitedDeposit { proposal_id }
+        }
     };
 
     PrePropose::default().execute(deps, env, info, internalized)
@@ -57,6 +57,7 @@ fn get_default_proposal_module_instantiate(
     cpm::msg::InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+            quorum_decay: None,
         },
         max_voting_period: Duration::Time(86400),
         min_voting_period: None,
@@ -1038,6 +1039,7 @@ fn test_instantiate_with_zero_native_deposit() {
         cpm::msg::InstantiateMsg {
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+                quorum_decay: None,
             },
             max_voting_period: Duration::Time(86400),
             min_voting_period: None,
@@ -1099,6 +1101,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
         cpm::msg::InstantiateMsg {
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+                quorum_decay: None,
             },
             max_voting_period: Duration::Time(86400),
             min_voting_period: None,
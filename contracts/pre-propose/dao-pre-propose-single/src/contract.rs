@@ -8,7 +8,10 @@ use cw2::set_contract_version;
 
 use dao_pre_propose_base::{
     error::PreProposeError,
-    msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
+    msg::{
+        ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, ProposalMessages,
+        QueryMsg as QueryBase,
+    },
     state::PreProposeContract,
 };
 use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
@@ -26,6 +29,11 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
+        /// If true, each of the proposal's messages is executed on
+        /// its own when the proposal is executed, and messages that
+        /// fail do not prevent the others from executing.
+        #[serde(default)]
+        best_effort: bool,
     },
 }
 
@@ -41,6 +49,14 @@ enum ProposeMessageInternal {
     Propose(ProposeMsg),
 }
 
+impl ProposalMessages for ProposeMessageInternal {
+    fn cosmos_msgs(&self) -> Vec<CosmosMsg> {
+        match self {
+            ProposeMessageInternal::Propose(ProposeMsg { msgs, .. }) => msgs.clone(),
+        }
+    }
+}
+
 type PrePropose = PreProposeContract<Empty, Empty, Empty, ProposeMessageInternal>;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -74,6 +90,7 @@ pub fn execute(
                     title,
                     description,
                     msgs,
+                    best_effort,
                 },
         } => ExecuteInternal::Propose {
             msg: ProposeMessageInternal::Propose(ProposeMsg {
@@ -82,6 +99,7 @@ pub fn execute(
                 title,
                 description,
                 msgs,
+                best_effort,
             }),
         },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
@@ -93,6 +111,12 @@ pub fn execute(
             deposit_info,
             open_proposal_submission,
         },
+        ExecuteMsg::UpdateSubmissionPolicy { policy } => {
+            ExecuteInternal::UpdateSubmissionPolicy { policy }
+        }
+        ExecuteMsg::UpdateDepositOptions { options } => {
+            ExecuteInternal::UpdateDepositOptions { options }
+        }
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
         }
@@ -106,6 +130,9 @@ pub fn execute(
             proposal_id,
             new_status,
         },
+        ExecuteMsg::ClaimForfeitedDeposit { proposal_id } => {
+            ExecuteInternal::ClaimForfeitedDeposit { proposal_id }
+        }
     };
 
     PrePropose::default().execute(deps, env, info, internalized)
@@ -1,4 +1,4 @@
-use cosmwasm_std::{coins, from_slice, to_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{coins, from_slice, to_binary, Addr, BankMsg, Coin, Decimal, Empty, Uint128};
 use cps::query::ProposalResponse;
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
@@ -7,11 +7,19 @@ use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::DepositInfoResponse,
+    state::{Config, ForfeitedDeposit},
+};
 use dao_proposal_single as cps;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, DepositRefundPolicy, DepositTier, DepositToken, UncheckedDepositInfo,
+        UncheckedDepositOption, UncheckedDepositOptions, UncheckedDepositPricing,
+        UncheckedForfeitDestination,
+    },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
@@ -74,6 +82,7 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        veto: None,
     }
 }
 
@@ -186,6 +195,7 @@ fn make_proposal(
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                best_effort: false,
             },
         },
         funds,
@@ -745,6 +755,42 @@ fn test_multiple_open_proposals() {
     assert_eq!(20, balance.u128());
 }
 
+#[test]
+fn test_propose_best_effort() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose,
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                best_effort: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The best effort flag is passed on to the proposal module.
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &cps::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert!(proposal.proposal.best_effort);
+}
+
 #[test]
 fn test_set_version() {
     let mut app = App::default();
@@ -827,6 +873,7 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    best_effort: false,
                 },
             },
             &[],
@@ -915,6 +962,7 @@ fn test_no_deposit_required_members_submission() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    best_effort: false,
                 },
             },
             &[],
@@ -999,6 +1047,7 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            veto: None,
         }
     };
 
@@ -1060,6 +1109,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            veto: None,
         }
     };
 
@@ -1353,3 +1403,277 @@ fn test_hook_management() {
     let hooks = query_hooks(app, pre_propose).hooks;
     assert_eq!(hooks, vec!["two".to_string()])
 }
+
+#[test]
+fn test_deposit_options() {
+    let app = &mut App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+
+    let options = UncheckedDepositOptions {
+        alternatives: vec![UncheckedDepositOption {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("uatom".to_string()),
+            },
+            amount: Uint128::new(20),
+        }],
+        pricing: Some(UncheckedDepositPricing::BankSendTiers {
+            denom: "ujuno".to_string(),
+            tiers: vec![DepositTier {
+                min_sent: Uint128::new(100),
+                multiplier: Decimal::percent(200),
+            }],
+        }),
+        ..Default::default()
+    };
+
+    // Only the DAO may update deposit options.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateDepositOptions {
+                options: options.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateDepositOptions { options },
+        &[],
+    )
+    .unwrap();
+
+    // The deposit may be paid in the alternative denom.
+    mint_natives(app, "ekez", coins(20, "uatom"));
+    let id = make_proposal(
+        app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(20, "uatom"),
+    );
+    let deposit = get_deposit_info(app, pre_propose.clone(), id);
+    assert_eq!(
+        deposit.deposit_info,
+        Some(CheckedDepositInfo {
+            denom: cw_denom::CheckedDenom::Native("uatom".to_string()),
+            amount: Uint128::new(20),
+            refund_policy: DepositRefundPolicy::Always,
+        })
+    );
+
+    // And is refunded in that denom.
+    vote(app, proposal_single.clone(), "ekez", id, Vote::No);
+    close_proposal(app, proposal_single.clone(), "ekez", id);
+    assert_eq!(get_balance_native(app, "ekez", "uatom"), Uint128::new(20));
+
+    // Proposals that send over 100 ujuno cost twice as much.
+    let propose = ExecuteMsg::Propose {
+        msg: ProposeMessage::Propose {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![BankMsg::Send {
+                to_address: "ekez".to_string(),
+                amount: coins(100, "ujuno"),
+            }
+            .into()],
+            best_effort: false,
+        },
+    };
+    mint_natives(app, "ekez", coins(20, "ujuno"));
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &propose,
+            &coins(10, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(dao_voting::deposit::DepositError::InvalidDeposit {
+            actual: Uint128::new(10),
+            expected: Uint128::new(20)
+        })
+    );
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose,
+        &propose,
+        &coins(20, "ujuno"),
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(app, "ekez", "ujuno"), Uint128::zero());
+}
+
+#[test]
+fn test_forfeit_destinations() {
+    let app = &mut App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(17),
+            refund_policy: DepositRefundPolicy::Never,
+        }),
+        false,
+    );
+    let set_destination = |app: &mut App, forfeit_destination: UncheckedForfeitDestination| {
+        app.execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateDepositOptions {
+                options: UncheckedDepositOptions {
+                    forfeit_destination,
+                    ..Default::default()
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // Forfeited deposits may be split among voters in proportion to
+    // their voting power.
+    set_destination(app, UncheckedForfeitDestination::Voters);
+    mint_natives(app, "ekez", coins(17, "ujuno"));
+    let id = make_proposal(
+        app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(17, "ujuno"),
+    );
+    vote(app, proposal_single.clone(), "keze", id, Vote::Yes);
+    vote(app, proposal_single.clone(), "ekez", id, Vote::No);
+    close_proposal(app, proposal_single.clone(), "ekez", id);
+
+    // Voters claim their shares themselves.
+    assert_eq!(get_balance_native(app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(
+        get_balance_native(app, pre_propose.as_str(), "ujuno"),
+        Uint128::new(17)
+    );
+    let forfeited: Option<ForfeitedDeposit> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::ForfeitedDeposit { proposal_id: id },
+        )
+        .unwrap();
+    assert_eq!(forfeited.unwrap().power_cast, Uint128::new(17));
+    let claim = |app: &mut App, sender: &str| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::ClaimForfeitedDeposit { proposal_id: id },
+            &[],
+        )
+    };
+    claim(app, "ekez").unwrap();
+    claim(app, "keze").unwrap();
+    assert_eq!(get_balance_native(app, "ekez", "ujuno"), Uint128::new(9));
+    assert_eq!(get_balance_native(app, "keze", "ujuno"), Uint128::new(8));
+
+    // Shares may only be claimed once, and only by voters.
+    let err: PreProposeError = claim(app, "ekez").unwrap_err().downcast().unwrap();
+    assert_eq!(err, PreProposeError::ForfeitAlreadyClaimed { id });
+    let err: PreProposeError = claim(app, "nonmember").unwrap_err().downcast().unwrap();
+    assert_eq!(err, PreProposeError::NotVoter { id });
+    assert_eq!(
+        get_balance_native(app, core_addr.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+
+    // Or burned.
+    set_destination(
+        app,
+        UncheckedForfeitDestination::Burn {
+            native_burn_address: None,
+        },
+    );
+    mint_natives(app, "ekez", coins(8, "ujuno"));
+    let id = make_proposal(
+        app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(17, "ujuno"),
+    );
+    vote(app, proposal_single.clone(), "ekez", id, Vote::No);
+    close_proposal(app, proposal_single, "ekez", id);
+    assert_eq!(get_balance_native(app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(
+        get_balance_native(app, core_addr.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+    assert_eq!(
+        get_balance_native(app, pre_propose.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_only_quorum_refund_without_quorum() {
+    // Proposals whose threshold has no quorum are treated as having
+    // reached it, so deposits are refunded.
+    let app = &mut App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::OnlyQuorum,
+        }),
+        false,
+    );
+    mint_natives(app, "ekez", coins(10, "ujuno"));
+    let id = make_proposal(
+        app,
+        pre_propose,
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    vote(app, proposal_single.clone(), "ekez", id, Vote::No);
+    close_proposal(app, proposal_single, "ekez", id);
+    assert_eq!(get_balance_native(app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(
+        get_balance_native(app, core_addr.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+}
@@ -0,0 +1,14 @@
+pub mod config;
+pub mod contract;
+mod error;
+pub mod msg;
+pub mod proposal;
+pub mod state;
+pub mod tally;
+
+#[cfg(test)]
+mod testing;
+
+pub mod vote;
+
+pub use crate::error::ContractError;
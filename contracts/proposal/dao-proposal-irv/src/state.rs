@@ -0,0 +1,35 @@
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+use crate::{
+    config::Config,
+    proposal::Proposal,
+    tally::Tally,
+    vote::{Ballot, Vote},
+};
+
+pub(crate) const DAO: Item<Addr> = Item::new("dao");
+pub(crate) const CONFIG: Item<Config> = Item::new("config");
+
+pub(crate) const TALLY: Map<u32, Tally> = Map::new("tallys");
+pub(crate) const PROPOSAL: Map<u32, Proposal> = Map::new("proposals");
+pub(crate) const VOTE: Map<(u32, Addr), Vote> = Map::new("votes");
+/// Votes aggregated by ranking. Keyed by proposal ID and
+/// `Vote::key`.
+pub(crate) const BALLOTS: Map<(u32, Vec<u8>), Ballot> = Map::new("ballots");
+
+pub(crate) fn next_proposal_id(storage: &dyn Storage) -> StdResult<u32> {
+    PROPOSAL
+        .keys(storage, None, None, cosmwasm_std::Order::Descending)
+        .next()
+        .transpose()
+        .map(|id| id.unwrap_or(0) + 1)
+}
+
+pub(crate) fn ballots(storage: &dyn Storage, proposal_id: u32) -> StdResult<Vec<Ballot>> {
+    BALLOTS
+        .prefix(proposal_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, ballot)| ballot))
+        .collect()
+}
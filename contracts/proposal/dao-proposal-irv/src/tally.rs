@@ -0,0 +1,150 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{BlockInfo, Uint128};
+use cw_utils::Expiration;
+
+use crate::vote::{Ballot, Vote};
+
+/// Stores the state of an instant-runoff election that can be
+/// maintained as votes are cast. Running the elimination rounds
+/// requires every ballot, so that is done by `elect` once voting has
+/// ended. While voting is ongoing the first preferences are enough to
+/// determine if a candidate has already won: a candidate that is the
+/// first preference of a majority of all voting power can never be
+/// eliminated.
+#[cw_serde]
+pub struct Tally {
+    /// The voting power that ranked each candidate first.
+    pub first_preferences: Vec<Uint128>,
+
+    /// When this tally will stop accepting votes.
+    pub expiration: Expiration,
+    /// The block height that this tally began at.
+    pub start_height: u64,
+    /// Amount of voting power that has yet to vote in this tally.
+    pub power_outstanding: Uint128,
+}
+
+/// A round of an instant-runoff election.
+#[cw_serde]
+pub struct Round {
+    /// The voting power counted for each candidate this round. Every
+    /// ballot counts for its most favored candidate that has not yet
+    /// been eliminated, so eliminated candidates hold zero.
+    pub votes: Vec<Uint128>,
+    /// The candidate eliminated at the end of this round. `None` in
+    /// the final round.
+    pub eliminated: Option<u32>,
+}
+
+/// The result of an instant-runoff election.
+#[cw_serde]
+pub struct Election {
+    pub rounds: Vec<Round>,
+    pub winner: u32,
+}
+
+impl Tally {
+    pub fn new(
+        candidates: u32,
+        total_power: Uint128,
+        start_height: u64,
+        expiration: Expiration,
+    ) -> Self {
+        Self {
+            first_preferences: vec![Uint128::zero(); candidates as usize],
+            power_outstanding: total_power,
+            start_height,
+            expiration,
+        }
+    }
+
+    pub fn candidates(&self) -> u32 {
+        self.first_preferences.len() as u32
+    }
+
+    pub fn expired(&self, block: &BlockInfo) -> bool {
+        self.expiration.is_expired(block)
+    }
+
+    /// Records a vote in the tally.
+    ///
+    /// Invariants:
+    ///
+    /// - Voter has not already voted.
+    /// - Tally is not expired.
+    pub fn add_vote(&mut self, vote: &Vote, power: Uint128) {
+        self.first_preferences[vote[0] as usize] += power;
+        self.power_outstanding -= power;
+    }
+
+    /// The candidate that is the first preference of more than half
+    /// of `total_power`, if any. This candidate will win the election
+    /// no matter how the outstanding voting power is cast.
+    pub fn majority(&self, total_power: Uint128) -> Option<u32> {
+        self.first_preferences
+            .iter()
+            .position(|votes| *votes > total_power - *votes)
+            .map(|c| c as u32)
+    }
+}
+
+/// Runs an instant-runoff election over `ballots`.
+///
+/// Each round every ballot counts for its most favored candidate that
+/// has not been eliminated. If a candidate holds more than half of
+/// the voting power counted in a round, or only one candidate
+/// remains, that candidate wins. Otherwise the candidate with the
+/// least voting power is eliminated and another round is run.
+///
+/// Ties for elimination are broken deterministically by eliminating
+/// the tied candidate with the least first preference voting power,
+/// and if still tied, the candidate with the lowest index. As "none
+/// of the above" is always the last candidate, it is eliminated last
+/// among tied candidates.
+pub fn elect(candidates: u32, ballots: &[Ballot]) -> Election {
+    let mut eliminated = vec![false; candidates as usize];
+    let mut rounds: Vec<Round> = vec![];
+
+    loop {
+        let mut votes = vec![Uint128::zero(); candidates as usize];
+        for ballot in ballots {
+            // Ballots rank every candidate and at least one candidate
+            // always remains, so this always finds a candidate.
+            if let Some(c) = ballot.vote.iter().find(|c| !eliminated[**c as usize]) {
+                votes[*c as usize] += ballot.power;
+            }
+        }
+        let counted: Uint128 = votes.iter().sum();
+        let remaining: Vec<u32> = (0..candidates)
+            .filter(|c| !eliminated[*c as usize])
+            .collect();
+
+        let winner = if remaining.len() == 1 {
+            Some(remaining[0])
+        } else {
+            remaining
+                .iter()
+                .copied()
+                .find(|c| votes[*c as usize] > counted - votes[*c as usize])
+        };
+        if let Some(winner) = winner {
+            rounds.push(Round {
+                votes,
+                eliminated: None,
+            });
+            return Election { rounds, winner };
+        }
+
+        let first_preferences = rounds.first().map_or(&votes, |round| &round.votes);
+        let loser = remaining
+            .iter()
+            .copied()
+            .min_by_key(|c| (votes[*c as usize], first_preferences[*c as usize], *c))
+            .expect("more than one candidate remains");
+        eliminated[loser as usize] = true;
+        rounds.push(Round {
+            votes,
+            eliminated: Some(loser),
+        });
+    }
+}
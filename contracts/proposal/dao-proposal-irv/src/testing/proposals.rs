@@ -0,0 +1,197 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    Reply, SubMsgResponse, SubMsgResult, Uint128,
+};
+use dao_voting::reply::mask_proposal_hook_index;
+
+use crate::{
+    contract::reply,
+    proposal::{ProposalResponse, Status},
+    testing::suite::unimportant_message,
+    vote::VoteError,
+    ContractError,
+};
+
+use super::{is_error, suite::SuiteBuilder};
+
+#[test]
+fn test_make_proposal() {
+    let mut suite = SuiteBuilder::default().build();
+    let id = suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    let ProposalResponse { proposal, tally } = suite.query_proposal(id);
+
+    assert_eq!(proposal.id, id);
+    assert_eq!(proposal.choices.len(), 2);
+    assert_eq!(proposal.choices[0].msgs[0], unimportant_message());
+    assert_eq!(proposal.choices[1].msgs, vec![]); // none-of-the-above added to the end.
+    assert_eq!(proposal.none_of_the_above(), 1);
+
+    assert_eq!(tally.candidates(), 2);
+    assert_eq!(tally.power_outstanding, proposal.total_power);
+    assert_eq!(tally.start_height, suite.block_height());
+}
+
+#[test]
+fn test_proposal_zero_choices() {
+    let mut suite = SuiteBuilder::default().build();
+    let err = suite.propose(suite.sender(), vec![]);
+    is_error!(err, &ContractError::ZeroChoices {}.to_string());
+}
+
+// no choice wins a majority of first preferences so the winner is
+// decided by elimination rounds once voting ends.
+#[test]
+fn test_proposal_lifecycle_runoff() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("blue", 40), ("violet", 30), ("magenta", 20), ("gold", 10)])
+        .build();
+    let id = suite.propose("blue", vec![vec![], vec![], vec![]]).unwrap();
+
+    suite.vote("blue", id, vec![0, 1, 2, 3]).unwrap();
+    suite.vote("violet", id, vec![1, 2, 0, 3]).unwrap();
+    suite.vote("magenta", id, vec![2, 1, 0, 3]).unwrap();
+    suite.vote("gold", id, vec![3, 2, 1, 0]).unwrap();
+
+    suite.a_day_passes();
+    assert_eq!(suite.query_status(id), Status::Open);
+
+    suite.a_week_passes();
+    assert_eq!(suite.query_status(id), Status::Passed { winner: 1 });
+
+    let election = suite.query_rounds(id);
+    assert_eq!(election.winner, 1);
+    assert_eq!(
+        election
+            .rounds
+            .iter()
+            .map(|round| round.eliminated)
+            .collect::<Vec<_>>(),
+        vec![Some(3), Some(2), None]
+    );
+    assert_eq!(
+        election.rounds[2].votes,
+        vec![
+            Uint128::new(40),
+            Uint128::new(60),
+            Uint128::zero(),
+            Uint128::zero()
+        ]
+    );
+
+    suite.execute("violet", id).unwrap();
+    assert_eq!(suite.query_status(id), Status::Executed);
+}
+
+// a choice that wins a majority of first preferences wins before the
+// voting period ends.
+#[test]
+fn test_proposal_passes_with_majority() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("pleb", 60), ("belp", 40)])
+        .with_proposal(2)
+        .build();
+
+    suite.vote("pleb", 1, vec![1, 0, 2]).unwrap();
+    assert_eq!(suite.query_status(1), Status::Open); // min voting period!
+
+    suite.a_day_passes();
+    assert_eq!(suite.query_status(1), Status::Passed { winner: 1 });
+
+    suite.execute("pleb", 1).unwrap();
+    // the DAO has no funds for the bank message.
+    assert_eq!(suite.query_status(1), Status::ExecutionFailed);
+}
+
+#[test]
+fn test_proposal_none_of_the_above_wins() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("pleb", 60), ("belp", 40)])
+        .with_proposal(1)
+        .build();
+
+    suite.vote("pleb", 1, vec![1, 0]).unwrap();
+    suite.a_day_passes();
+    assert_eq!(suite.query_status(1), Status::Rejected);
+
+    let err = suite.execute("pleb", 1);
+    is_error!(err, &ContractError::Unexecutable {}.to_string());
+
+    suite.close("belp", 1).unwrap();
+    assert_eq!(suite.query_status(1), Status::Closed);
+}
+
+#[test]
+fn test_proposal_never_reaches_quorum() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("pleb", 1), ("belp", 10)])
+        .with_proposal(2)
+        .build();
+
+    suite.vote("pleb", 1, vec![0, 2, 1]).unwrap();
+
+    suite.a_week_passes();
+
+    assert_eq!(suite.query_status(1), Status::Rejected);
+}
+
+#[test]
+fn test_no_vote_after_expiry() {
+    let mut suite = SuiteBuilder::default().with_proposal(1).build();
+
+    suite.a_week_passes();
+
+    let err = suite.vote(suite.sender(), 1, vec![0, 1]);
+    is_error!(err, &ContractError::Expired {}.to_string());
+}
+
+#[test]
+fn test_no_revoting() {
+    let mut suite = SuiteBuilder::default().with_proposal(1).build();
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+
+    let err = suite.vote(suite.sender(), 1, vec![0, 1]);
+    is_error!(err, &ContractError::Voted {}.to_string());
+}
+
+#[test]
+fn test_no_vote_zero_power() {
+    let mut suite = SuiteBuilder::default().with_proposal(1).build();
+    let err = suite.vote("somebody", 1, vec![0, 1]);
+    is_error!(err, &ContractError::ZeroVotingPower {}.to_string());
+}
+
+#[test]
+fn test_vote_must_rank_every_choice() {
+    let mut suite = SuiteBuilder::default().with_proposal(2).build();
+    let err = suite.vote(suite.sender(), 1, vec![0, 1]);
+    is_error!(
+        err,
+        &VoteError::LenMissmatch {
+            got: 2,
+            expected: 3
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_unknown_reply_id() {
+    let mut deps = mock_dependencies();
+    let id = mask_proposal_hook_index(0);
+    let err = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::UnknownReplyId { id: got } if got == id));
+}
@@ -0,0 +1,124 @@
+use cosmwasm_std::Uint128;
+use cw_utils::Expiration;
+
+use crate::{
+    tally::{elect, Round, Tally},
+    vote::{Ballot, Vote},
+};
+
+fn ballot(vote: Vec<u32>, power: u128) -> Ballot {
+    let candidates = vote.len() as u32;
+    Ballot {
+        vote: Vote::new(vote, candidates).unwrap(),
+        power: Uint128::new(power),
+    }
+}
+
+fn votes(votes: &[u128]) -> Vec<Uint128> {
+    votes.iter().copied().map(Uint128::new).collect()
+}
+
+#[test]
+fn test_first_round_majority() {
+    let election = elect(3, &[ballot(vec![0, 1, 2], 3), ballot(vec![1, 0, 2], 2)]);
+
+    assert_eq!(election.winner, 0);
+    assert_eq!(
+        election.rounds,
+        vec![Round {
+            votes: votes(&[3, 2, 0]),
+            eliminated: None
+        }]
+    );
+}
+
+#[test]
+fn test_elimination_transfers_votes() {
+    let election = elect(
+        3,
+        &[
+            ballot(vec![0, 1, 2], 4),
+            ballot(vec![1, 0, 2], 3),
+            ballot(vec![2, 1, 0], 2),
+        ],
+    );
+
+    // 0 leads the first round but the votes of 2 transfer to 1 once
+    // it is eliminated.
+    assert_eq!(election.winner, 1);
+    assert_eq!(
+        election.rounds,
+        vec![
+            Round {
+                votes: votes(&[4, 3, 2]),
+                eliminated: Some(2)
+            },
+            Round {
+                votes: votes(&[4, 5, 0]),
+                eliminated: None
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_tie_breaking() {
+    let election = elect(
+        3,
+        &[
+            ballot(vec![0, 1, 2], 1),
+            ballot(vec![1, 0, 2], 1),
+            ballot(vec![2, 0, 1], 2),
+        ],
+    );
+
+    // 0 and 1 tie for the least votes and first preferences, so the
+    // lower index is eliminated. 1 and 2 then tie for the least
+    // votes, and 1 has fewer first preferences.
+    assert_eq!(election.winner, 2);
+    assert_eq!(
+        election.rounds,
+        vec![
+            Round {
+                votes: votes(&[1, 1, 2]),
+                eliminated: Some(0)
+            },
+            Round {
+                votes: votes(&[0, 2, 2]),
+                eliminated: Some(1)
+            },
+            Round {
+                votes: votes(&[0, 0, 4]),
+                eliminated: None
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_no_ballots() {
+    // With no votes the last candidate, none of the above, wins.
+    let election = elect(3, &[]);
+    assert_eq!(election.winner, 2);
+    assert_eq!(election.rounds.len(), 3);
+}
+
+#[test]
+fn test_tally_majority() {
+    let candidates = 3;
+    let total_power = Uint128::new(10);
+    let mut tally = Tally::new(candidates, total_power, 0, Expiration::Never {});
+
+    tally.add_vote(
+        &Vote::new(vec![1, 0, 2], candidates).unwrap(),
+        Uint128::new(5),
+    );
+    assert_eq!(tally.majority(total_power), None);
+
+    tally.add_vote(
+        &Vote::new(vec![1, 2, 0], candidates).unwrap(),
+        Uint128::new(1),
+    );
+    assert_eq!(tally.majority(total_power), Some(1));
+    assert_eq!(tally.power_outstanding, Uint128::new(4));
+}
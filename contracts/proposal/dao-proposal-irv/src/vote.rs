@@ -0,0 +1,103 @@
+use std::ops::Index;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+use thiserror::Error;
+
+/// A ranked ballot. Lists every candidate, from most to least
+/// favored.
+#[cw_serde]
+pub struct Vote(Vec<u32>);
+
+/// The voting power of all voters who cast a given ranking. Votes
+/// with the same ranking are aggregated so that the cost of running
+/// an election grows with the number of distinct rankings cast
+/// rather than the number of voters.
+#[cw_serde]
+pub struct Ballot {
+    pub vote: Vote,
+    pub power: Uint128,
+}
+
+impl Vote {
+    pub(crate) fn new(vote: Vec<u32>, candidates: u32) -> Result<Self, VoteError> {
+        if vote.len() != candidates as usize {
+            return Err(VoteError::LenMissmatch {
+                got: vote.len() as u32,
+                expected: candidates,
+            });
+        }
+        let mut seen = vec![];
+        for v in vote {
+            if v >= candidates {
+                return Err(VoteError::InvalidCandidate { candidate: v });
+            }
+            if seen.contains(&v) {
+                return Err(VoteError::DuplicateCandidate { candidate: v });
+            }
+            seen.push(v);
+        }
+        Ok(Vote(seen))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, u32> {
+        self.0.iter()
+    }
+
+    /// The storage key used to aggregate votes with this ranking.
+    pub(crate) fn key(&self) -> Vec<u8> {
+        self.0.iter().flat_map(|c| c.to_be_bytes()).collect()
+    }
+}
+
+impl Index<usize> for Vote {
+    type Output = u32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0.index(index)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum VoteError {
+    #[error("candidate ({candidate}) appears in ballot more than once")]
+    DuplicateCandidate { candidate: u32 },
+
+    #[error("no such candidate ({candidate})")]
+    InvalidCandidate { candidate: u32 },
+
+    #[error("ballot has wrong number of candidates. got ({got}) expected ({expected})")]
+    LenMissmatch { got: u32, expected: u32 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vote_validation() {
+        assert_eq!(
+            Vote::new(vec![1, 2, 3, 0], 2).unwrap_err(),
+            VoteError::LenMissmatch {
+                got: 4,
+                expected: 2
+            }
+        );
+        assert_eq!(
+            Vote::new(vec![1, 2], 2).unwrap_err(),
+            VoteError::InvalidCandidate { candidate: 2 }
+        );
+        assert_eq!(
+            Vote::new(vec![1, 1, 2, 2], 4).unwrap_err(),
+            VoteError::DuplicateCandidate { candidate: 1 }
+        )
+    }
+
+    #[test]
+    fn test_vote_key() {
+        let a = Vote::new(vec![0, 1, 2], 3).unwrap();
+        let b = Vote::new(vec![0, 2, 1], 3).unwrap();
+        assert_eq!(a.key(), vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2]);
+        assert_ne!(a.key(), b.key());
+    }
+}
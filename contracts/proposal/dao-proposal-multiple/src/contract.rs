@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdResult,
-    Storage, SubMsg, WasmMsg,
+    to_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
+    Response, StdError, StdResult, Storage, SubMsg, Timestamp, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
@@ -10,12 +10,17 @@ use cw_hooks::Hooks;
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_instantiate_data, Duration};
 use dao_interface::voting::IsActiveResponse;
+use dao_pre_propose_base::msg::DepositInfoResponse;
 use dao_pre_propose_multiple::contract::ExecuteMsg as PreProposeMsg;
 use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
 use dao_vote_hooks::new_vote_hooks;
 use dao_voting::{
+    delegation::{get_delegate, get_delegated_voting_power},
+    deposit::DepositRefundPolicy,
+    error::VotingError,
     multiple_choice::{
-        MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
+        MultiOptionVote, MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes,
+        VotingStrategy,
     },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     proposal::{DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
@@ -32,7 +37,8 @@ use crate::{
     proposal::{MultipleChoiceProposal, VoteResult},
     query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
     state::{
-        Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+        Ballot, Config, BALLOTS, CLOSE_EXPIRED_CURSOR, CLOSE_EXPIRED_REWARD, CONFIG,
+        DELEGATION_MODULE, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
     },
     ContractError,
 };
@@ -40,6 +46,10 @@ use crate::{
 pub const CONTRACT_NAME: &str = "crates.io:dao-proposal-multiple";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Message type used for querying this module's pre-propose module,
+/// if one is installed.
+type PreProposeQueryMsg = dao_pre_propose_base::msg::QueryMsg<Empty>;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -55,6 +65,9 @@ pub fn instantiate(
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(msg.min_voting_period, msg.max_voting_period)?;
+    if let Some(decay) = msg.voting_strategy.quorum_decay() {
+        decay.validate_units(&max_voting_period)?;
+    }
 
     let (initial_policy, pre_propose_messages) = msg
         .pre_propose_info
@@ -108,9 +121,23 @@ pub fn execute(
             proposal_id,
             vote,
             rationale,
-        } => execute_vote(deps, env, info, proposal_id, vote, rationale),
+        } => execute_vote(deps, env, info, proposal_id, vote, None, rationale),
+        ExecuteMsg::VoteMultiOption {
+            proposal_id,
+            vote,
+            rationale,
+        } => execute_vote(
+            deps,
+            env,
+            info,
+            proposal_id,
+            vote.position(),
+            Some(vote),
+            rationale,
+        ),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::CloseExpired { limit } => execute_close_expired(deps, env, info, limit),
         ExecuteMsg::UpdateConfig {
             voting_strategy,
             min_voting_period,
@@ -143,6 +170,12 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::UpdateDelegationModule { delegation_module } => {
+            execute_update_delegation_module(deps, info, delegation_module)
+        }
+        ExecuteMsg::UpdateCloseExpiredReward { reward } => {
+            execute_update_close_expired_reward(deps, env, info, reward)
+        }
         ExecuteMsg::UpdateRationale {
             proposal_id,
             rationale,
@@ -215,6 +248,7 @@ pub fn execute_propose(
             total_power,
             status: Status::Open,
             votes: MultipleChoiceVotes::zero(checked_multiple_choice_options.len()),
+            power_cast: Uint128::zero(),
             allow_revoting: config.allow_revoting,
             choices: checked_multiple_choice_options,
         };
@@ -265,6 +299,7 @@ pub fn execute_vote(
     info: MessageInfo,
     proposal_id: u64,
     vote: MultipleChoiceVote,
+    multi_option_vote: Option<MultiOptionVote>,
     rationale: Option<String>,
 ) -> Result<Response<Empty>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -273,8 +308,18 @@ pub fn execute_vote(
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
     // Check that this is a valid vote.
-    if vote.option_id as usize >= prop.choices.len() {
-        return Err(ContractError::InvalidVote {});
+    match multi_option_vote {
+        Some(ref multi_option_vote) => {
+            multi_option_vote.validate(&prop.voting_strategy, prop.choices.len() as u32)?
+        }
+        None => {
+            if !matches!(prop.voting_strategy, VotingStrategy::SingleChoice { .. }) {
+                return Err(VotingError::WrongVoteType {}.into());
+            }
+            if vote.option_id as usize >= prop.choices.len() {
+                return Err(ContractError::InvalidVote {});
+            }
+        }
     }
 
     // Allow voting on proposals until they expire.
@@ -288,31 +333,50 @@ pub fn execute_vote(
         return Err(ContractError::Expired { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
+    let own_power = get_voting_power(
         deps.as_ref(),
         info.sender.clone(),
         &config.dao,
         Some(prop.start_height),
     )?;
+    // Delegates vote with the voting power of their delegators,
+    // except for delegators that have cast their own vote.
+    let delegation_module = DELEGATION_MODULE.may_load(deps.storage)?;
+    let vote_power = match delegation_module {
+        Some(ref delegation_module) => own_power
+            .checked_add(get_delegated_voting_power(
+                deps.as_ref(),
+                delegation_module,
+                &config.dao,
+                &info.sender,
+                prop.start_height,
+                |delegator| Ok(BALLOTS.has(deps.storage, (proposal_id, delegator))),
+            )?)
+            .map_err(StdError::overflow)?,
+        None => own_power,
+    };
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
 
-    BALLOTS.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
+    let is_first_vote = !BALLOTS.has(deps.storage, (proposal_id, &info.sender));
+    let ballot = BALLOTS.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
-                if current_ballot.vote == vote {
+                if current_ballot.vote == vote
+                    && current_ballot.multi_option_vote == multi_option_vote
+                {
                     // Don't allow casting the same vote more than
                     // once. This seems liable to be confusing
                     // behavior.
                     Err(ContractError::AlreadyCast {})
                 } else {
                     // Remove the old vote if this is a re-vote.
-                    prop.votes
-                        .remove_vote(current_ballot.vote, current_ballot.power)?;
+                    remove_ballot(&mut prop, &current_ballot)?;
                     Ok(Ballot {
                         power: vote_power,
                         vote,
+                        multi_option_vote: multi_option_vote.clone(),
                         rationale,
                     })
                 }
@@ -322,6 +386,7 @@ pub fn execute_vote(
         }
         None => Ok(Ballot {
             vote,
+            multi_option_vote: multi_option_vote.clone(),
             power: vote_power,
             rationale,
         }),
@@ -329,7 +394,32 @@ pub fn execute_vote(
 
     let old_status = prop.status;
 
-    prop.votes.add_vote(vote, vote_power)?;
+    add_ballot(&mut prop, &ballot)?;
+
+    // A delegator's first vote overrides their delegate's vote. If
+    // their delegate has already voted, remove the delegator's voting
+    // power from the delegate's ballot.
+    if let Some(delegation_module) = delegation_module.filter(|_| is_first_vote) {
+        if let Some(delegate) = get_delegate(
+            deps.as_ref(),
+            &delegation_module,
+            &info.sender,
+            prop.start_height,
+        )? {
+            if let Some(mut ballot) = BALLOTS.may_load(deps.storage, (proposal_id, &delegate))? {
+                // Re-add the delegate's whole ballot so weighted
+                // votes are recomputed from its new power.
+                remove_ballot(&mut prop, &ballot)?;
+                ballot.power = ballot
+                    .power
+                    .checked_sub(own_power)
+                    .map_err(StdError::overflow)?;
+                add_ballot(&mut prop, &ballot)?;
+                BALLOTS.save(deps.storage, (proposal_id, &delegate), &ballot)?;
+            }
+        }
+    }
+
     prop.update_status(&env.block)?;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
     let new_status = prop.status;
@@ -340,12 +430,15 @@ pub fn execute_vote(
         old_status.to_string(),
         new_status.to_string(),
     )?;
+    // Approval and score votes are reported in the form
+    // `approval:0,2` or `score:3,0,1`.
+    let position = multi_option_vote.map_or_else(|| vote.to_string(), |vote| vote.to_string());
     let vote_hooks = new_vote_hooks(
         VOTE_HOOKS,
         deps.storage,
         proposal_id,
         info.sender.to_string(),
-        vote.to_string(),
+        position.clone(),
     )?;
     Ok(Response::default()
         .add_submessages(change_hooks)
@@ -353,10 +446,42 @@ pub fn execute_vote(
         .add_attribute("action", "vote")
         .add_attribute("sender", info.sender)
         .add_attribute("proposal_id", proposal_id.to_string())
-        .add_attribute("position", vote.to_string())
+        .add_attribute("position", position)
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Adds a ballot's voting power to a proposal's tally.
+fn add_ballot(prop: &mut MultipleChoiceProposal, ballot: &Ballot) -> StdResult<()> {
+    match ballot.multi_option_vote {
+        Some(ref vote) => {
+            prop.votes
+                .add_multi_option_vote(vote, &prop.voting_strategy, ballot.power)?;
+            prop.power_cast = prop
+                .power_cast
+                .checked_add(ballot.power)
+                .map_err(StdError::overflow)?;
+            Ok(())
+        }
+        None => prop.votes.add_vote(ballot.vote, ballot.power),
+    }
+}
+
+/// Removes a ballot's voting power from a proposal's tally.
+fn remove_ballot(prop: &mut MultipleChoiceProposal, ballot: &Ballot) -> StdResult<()> {
+    match ballot.multi_option_vote {
+        Some(ref vote) => {
+            prop.votes
+                .remove_multi_option_vote(vote, &prop.voting_strategy, ballot.power)?;
+            prop.power_cast = prop
+                .power_cast
+                .checked_sub(ballot.power)
+                .map_err(StdError::overflow)?;
+            Ok(())
+        }
+        None => prop.votes.remove_vote(ballot.vote, ballot.power),
+    }
+}
+
 pub fn execute_execute(
     deps: DepsMut,
     env: Env,
@@ -472,22 +597,148 @@ pub fn execute_close(
         return Err(ContractError::WrongCloseStatus {});
     }
 
+    let hooks = close_proposal(deps.storage, proposal_id, &mut prop)?;
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "close")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+pub fn execute_close_expired(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u64>,
+) -> Result<Response<Empty>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let cursor = CLOSE_EXPIRED_CURSOR.may_load(deps.storage)?;
+    // `limit` caps the number of proposals scanned, not closed, so
+    // that the gas used by this method is bounded.
+    let proposal_ids = PROPOSALS
+        .keys(
+            deps.storage,
+            cursor.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<u64>>>()?;
+    let creation_policy = CREATION_POLICY.load(deps.storage)?;
+
+    // Proposals that are no longer open or rejected will never need
+    // to be closed, so the cursor is advanced past them until the
+    // first proposal that is still open.
+    let mut new_cursor = cursor;
+    let mut settled = true;
+    let mut closed = 0;
+    let mut rewarded = 0u128;
+    let mut msgs = vec![];
+    for proposal_id in proposal_ids {
+        let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+        prop.update_status(&env.block)?;
+        match prop.status {
+            Status::Open => settled = false,
+            Status::Rejected => {
+                if forfeits_deposit(deps.as_ref(), &creation_policy, proposal_id) {
+                    rewarded += 1;
+                }
+                msgs.extend(close_proposal(deps.storage, proposal_id, &mut prop)?);
+                closed += 1;
+            }
+            _ => (),
+        }
+        if settled {
+            new_cursor = Some(proposal_id);
+        }
+    }
+    if let Some(cursor) = new_cursor {
+        CLOSE_EXPIRED_CURSOR.save(deps.storage, &cursor)?;
+    }
+
+    let reward = CLOSE_EXPIRED_REWARD.may_load(deps.storage)?;
+    if let Some(reward) = reward.filter(|_| rewarded > 0) {
+        // The reward is paid from this module's balance rather than by
+        // the DAO, so that it is not queued by the DAO's timelock or
+        // limited by this module's permissions. If the balance can not
+        // cover the reward what remains is paid, and the proposals are
+        // closed regardless.
+        let amount = reward
+            .amount
+            .checked_mul(Uint128::new(rewarded))
+            .map_err(StdError::overflow)?;
+        let balance = deps
+            .querier
+            .query_balance(&env.contract.address, &reward.denom)?;
+        let amount = amount.min(balance.amount);
+        if !amount.is_zero() {
+            msgs.push(SubMsg::new(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![Coin {
+                    denom: reward.denom,
+                    amount,
+                }],
+            }));
+        }
+    }
+
+    Ok(Response::default()
+        .add_submessages(msgs)
+        .add_attribute("action", "close_expired")
+        .add_attribute("sender", info.sender)
+        .add_attribute("closed", closed.to_string()))
+}
+
+/// Returns true if the proposal's proposer forfeits a deposit now
+/// that the proposal has been rejected. `CloseExpired` rewards are
+/// only paid for these proposals so that creating proposals to be
+/// closed for the reward costs more than it earns, so long as the
+/// reward is smaller than the deposit.
+fn forfeits_deposit(
+    deps: Deps,
+    creation_policy: &ProposalCreationPolicy,
+    proposal_id: u64,
+) -> bool {
+    let addr = match creation_policy {
+        ProposalCreationPolicy::Anyone {} => return false,
+        ProposalCreationPolicy::Module { addr } => addr,
+    };
+    // A pre-propose module that can not answer this query is
+    // treated as not having taken a deposit.
+    deps.querier
+        .query_wasm_smart::<DepositInfoResponse>(
+            addr,
+            &PreProposeQueryMsg::DepositInfo { proposal_id },
+        )
+        .ok()
+        .and_then(|response| response.deposit_info)
+        .map(|deposit| deposit.refund_policy != DepositRefundPolicy::Always)
+        .unwrap_or(false)
+}
+
+/// Closes a rejected proposal, returning the hooks to fire and the
+/// message settling its deposit with the pre-propose module (if any).
+fn close_proposal(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    prop: &mut MultipleChoiceProposal,
+) -> Result<Vec<SubMsg>, ContractError> {
     let old_status = prop.status;
 
     prop.status = Status::Closed;
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    PROPOSALS.save(storage, proposal_id, prop)?;
 
     let hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
-        deps.storage,
+        storage,
         proposal_id,
         old_status.to_string(),
         prop.status.to_string(),
     )?;
 
     // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_creation_policy = CREATION_POLICY.load(storage)?;
     let hooks = match proposal_creation_policy {
         ProposalCreationPolicy::Anyone {} => hooks,
         ProposalCreationPolicy::Module { addr } => {
@@ -507,11 +758,7 @@ pub fn execute_close(
             hooks
         }
     };
-    Ok(Response::default()
-        .add_submessages(hooks)
-        .add_attribute("action", "close")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+    Ok(hooks)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -539,6 +786,9 @@ pub fn execute_update_config(
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    if let Some(decay) = voting_strategy.quorum_decay() {
+        decay.validate_units(&max_voting_period)?;
+    }
 
     CONFIG.save(
         deps.storage,
@@ -692,6 +942,87 @@ pub fn execute_remove_vote_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_update_delegation_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    delegation_module: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match delegation_module {
+        Some(ref delegation_module) => {
+            let delegation_module = deps.api.addr_validate(delegation_module)?;
+            DELEGATION_MODULE.save(deps.storage, &delegation_module)?;
+        }
+        None => DELEGATION_MODULE.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "update_delegation_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "delegation_module",
+            delegation_module.as_deref().unwrap_or("_none"),
+        ))
+}
+
+pub fn execute_update_close_expired_reward(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    reward: Option<Coin>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Rewards are paid from this module's balance. Once a denom is no
+    // longer paid as a reward, the module's balance of it is returned
+    // to the DAO.
+    let mut msgs = vec![];
+    if let Some(old) = CLOSE_EXPIRED_REWARD.may_load(deps.storage)? {
+        if reward
+            .as_ref()
+            .map_or(true, |reward| reward.denom != old.denom)
+        {
+            let balance = deps
+                .querier
+                .query_balance(&env.contract.address, &old.denom)?;
+            if !balance.amount.is_zero() {
+                msgs.push(BankMsg::Send {
+                    to_address: config.dao.into_string(),
+                    amount: vec![balance],
+                });
+            }
+        }
+    }
+
+    match reward {
+        Some(ref reward) => {
+            if reward.amount.is_zero() {
+                return Err(ContractError::ZeroCloseExpiredReward {});
+            }
+            CLOSE_EXPIRED_REWARD.save(deps.storage, reward)?;
+        }
+        None => CLOSE_EXPIRED_REWARD.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_messages(msgs)
+        .add_attribute("action", "update_close_expired_reward")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "reward",
+            reward
+                .map(|reward| reward.to_string())
+                .unwrap_or_else(|| "_none".to_string()),
+        ))
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -740,6 +1071,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => query_list_votes(deps, proposal_id, start_after, limit),
+        QueryMsg::EffectiveQuorum {
+            proposal_id,
+            height,
+            time,
+        } => query_effective_quorum(deps, env, proposal_id, height, time),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ReverseProposals {
             start_before,
@@ -748,6 +1084,8 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::DelegationModule {} => to_binary(&DELEGATION_MODULE.may_load(deps.storage)?),
+        QueryMsg::CloseExpiredReward {} => to_binary(&CLOSE_EXPIRED_REWARD.may_load(deps.storage)?),
         QueryMsg::Dao {} => query_dao(deps),
     }
 }
@@ -825,6 +1163,7 @@ pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Bina
     let vote = ballot.map(|ballot| VoteInfo {
         voter,
         vote: ballot.vote,
+        multi_option_vote: ballot.multi_option_vote,
         power: ballot.power,
         rationale: ballot.rationale,
     });
@@ -852,6 +1191,7 @@ pub fn query_list_votes(
             Ok(VoteInfo {
                 voter,
                 vote: ballot.vote,
+                multi_option_vote: ballot.multi_option_vote,
                 power: ballot.power,
                 rationale: ballot.rationale,
             })
@@ -861,6 +1201,24 @@ pub fn query_list_votes(
     to_binary(&VoteListResponse { votes })
 }
 
+pub fn query_effective_quorum(
+    deps: Deps,
+    env: Env,
+    proposal_id: u64,
+    height: Option<u64>,
+    time: Option<Timestamp>,
+) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let mut block = env.block;
+    if let Some(height) = height {
+        block.height = height;
+    }
+    if let Some(time) = time {
+        block.time = time;
+    }
+    to_binary(&proposal.effective_quorum(&block))
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_binary(&dao_interface::voting::InfoResponse { info })
@@ -925,6 +1283,10 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             };
             Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
         }
+        // This module does not execute proposals message by message.
+        TaggedReplyId::ProposalMessageExecution(..) => {
+            Err(ContractError::InvalidReplyID { id: msg.id })
+        }
     }
 }
 
@@ -62,6 +62,9 @@ pub enum ContractError {
     #[error("Only rejected proposals may be closed.")]
     WrongCloseStatus {},
 
+    #[error("Close expired reward must not be zero.")]
+    ZeroCloseExpiredReward {},
+
     #[error("The DAO is currently inactive, you cannot create proposals.")]
     InactiveDao {},
 
@@ -1,8 +1,9 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, Timestamp};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy},
+    multiple_choice::{MultiOptionVote, MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy},
     pre_propose::PreProposeInfo,
 };
 
@@ -68,6 +69,19 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Votes on a proposal using approval or score voting, supporting
+    /// several options. The vote must match the proposal's voting
+    /// strategy.
+    VoteMultiOption {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The options supported by the sender.
+        vote: MultiOptionVote,
+        /// An optional rationale for why this vote was cast. This can
+        /// be updated, set, or removed later by the address casting
+        /// the vote.
+        rationale: Option<String>,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
@@ -81,6 +95,17 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to close.
         proposal_id: u64,
     },
+    /// Closes proposals that have been rejected or have expired
+    /// without passing, returning their deposits as `Close` would.
+    /// Anyone may call this method. If the DAO has set a reward with
+    /// `UpdateCloseExpiredReward`, the caller is paid the reward for
+    /// each proposal closed whose proposer forfeits a deposit.
+    CloseExpired {
+        /// The maximum number of proposals to check, starting after
+        /// the last proposal that no longer needs closing. If no
+        /// limit is specified a max of 30 are checked.
+        limit: Option<u64>,
+    },
     /// Updates the governance module's config.
     UpdateConfig {
         /// The new proposal voting strategy. This will only apply
@@ -140,6 +165,25 @@ pub enum ExecuteMsg {
     RemoveVoteHook {
         address: String,
     },
+    /// Sets or removes the vote delegation contract used to count
+    /// delegated voting power. Delegates vote with the voting power
+    /// delegated to them, and delegators that vote override their
+    /// delegate's vote with their own voting power. Only the DAO may
+    /// call this method.
+    UpdateDelegationModule {
+        delegation_module: Option<String>,
+    },
+    /// Sets or removes the reward paid to callers of `CloseExpired`
+    /// for each proposal they close whose proposer forfeits a
+    /// deposit. Rewards are paid from this module's balance, which
+    /// the DAO funds by sending it tokens. The module's balance of the
+    /// previous reward denom is returned to the DAO if the reward is
+    /// removed or its denom changes. The reward should be smaller
+    /// than the deposit so that creating proposals to be closed is
+    /// not profitable. Only the DAO may call this method.
+    UpdateCloseExpiredReward {
+        reward: Option<Coin>,
+    },
 }
 
 #[proposal_module_query]
@@ -175,6 +219,18 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u64>,
     },
+    /// Gets the quorum a proposal must meet, accounting for any
+    /// quorum decay.
+    #[returns(::dao_voting::threshold::PercentageThreshold)]
+    EffectiveQuorum {
+        proposal_id: u64,
+        /// The block height to get the quorum at. Defaults to the
+        /// current block height.
+        height: Option<u64>,
+        /// The block time to get the quorum at. Defaults to the
+        /// current block time.
+        time: Option<Timestamp>,
+    },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
@@ -187,6 +243,14 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the vote delegation contract used by this module, if
+    /// any.
+    #[returns(Option<::cosmwasm_std::Addr>)]
+    DelegationModule {},
+    /// Gets the reward paid to callers of `CloseExpired` for each
+    /// proposal they close, if any.
+    #[returns(Option<::cosmwasm_std::Coin>)]
+    CloseExpiredReward {},
 }
 
 #[cw_serde]
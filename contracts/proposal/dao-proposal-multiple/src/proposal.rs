@@ -6,6 +6,7 @@ use dao_voting::{
         CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVotes, VotingStrategy,
     },
     status::Status,
+    threshold::PercentageThreshold,
     voting::does_vote_count_pass,
 };
 
@@ -38,6 +39,11 @@ pub struct MultipleChoiceProposal {
     pub total_power: Uint128,
     /// The vote tally.
     pub votes: MultipleChoiceVotes,
+    /// The voting power that has voted on this proposal. Only tracked
+    /// for approval and score voting, where a vote may count towards
+    /// several options.
+    #[serde(default)]
+    pub power_cast: Uint128,
     /// Whether DAO members are allowed to change their votes.
     /// When disabled, proposals can be executed as soon as they pass.
     /// When enabled, proposals can only be executed after the voting
@@ -76,6 +82,21 @@ impl MultipleChoiceProposal {
         }
     }
 
+    /// The voting power that has voted on this proposal, which is
+    /// compared against the quorum.
+    pub fn turnout(&self) -> Uint128 {
+        match self.voting_strategy {
+            VotingStrategy::SingleChoice { .. } => self.votes.total(),
+            VotingStrategy::Approval { .. } | VotingStrategy::Score { .. } => self.power_cast,
+        }
+    }
+
+    /// The quorum this proposal must meet at `block`, accounting for
+    /// any quorum decay.
+    pub fn effective_quorum(&self, block: &BlockInfo) -> PercentageThreshold {
+        self.voting_strategy.quorum_at(&self.expiration, block)
+    }
+
     /// Sets a proposals status to its current status.
     pub fn update_status(&mut self, block: &BlockInfo) -> StdResult<()> {
         let new_status = self.current_status(block)?;
@@ -108,9 +129,9 @@ impl MultipleChoiceProposal {
 
         // Proposal can only pass if quorum has been met.
         if does_vote_count_pass(
-            self.votes.total(),
+            self.turnout(),
             self.total_power,
-            self.voting_strategy.get_quorum(),
+            self.effective_quorum(block),
         ) {
             let vote_result = self.calculate_vote_result()?;
             match vote_result {
@@ -147,15 +168,15 @@ impl MultipleChoiceProposal {
             // there is no voting power left.
             VoteResult::Tie => {
                 let rejected =
-                    self.expiration.is_expired(block) || self.total_power == self.votes.total();
+                    self.expiration.is_expired(block) || self.total_power == self.turnout();
                 Ok(rejected)
             }
             VoteResult::SingleWinner(winning_choice) => {
                 match (
                     does_vote_count_pass(
-                        self.votes.total(),
+                        self.turnout(),
                         self.total_power,
-                        self.voting_strategy.get_quorum(),
+                        self.effective_quorum(block),
                     ),
                     self.expiration.is_expired(block),
                 ) {
@@ -185,8 +206,12 @@ impl MultipleChoiceProposal {
 
     /// Find the option with the highest vote weight, and note if there is a tie.
     pub fn calculate_vote_result(&self) -> StdResult<VoteResult> {
+        // With every voting strategy the option with the most votes
+        // wins.
         match self.voting_strategy {
-            VotingStrategy::SingleChoice { quorum: _ } => {
+            VotingStrategy::SingleChoice { .. }
+            | VotingStrategy::Approval { .. }
+            | VotingStrategy::Score { .. } => {
                 // We expect to have at least 3 vote weights
                 if let Some(max_weight) = self.votes.vote_weights.iter().max_by(|&a, &b| a.cmp(b)) {
                     let top_choices: Vec<(usize, &Uint128)> = self
@@ -233,7 +258,7 @@ impl MultipleChoiceProposal {
             .max_by(|&a, &b| a.cmp(b))
         {
             // Check if the remaining vote power can be used to overtake the current winning choice.
-            let remaining_vote_power = self.total_power - self.votes.total();
+            let remaining_vote_power = self.total_power - self.turnout();
             match winning_choice.option_type {
                 MultipleChoiceOptionType::Standard => {
                     if winning_choice_power > *second_choice_power + remaining_vote_power {
@@ -260,8 +285,10 @@ impl MultipleChoiceProposal {
 mod tests {
     use super::*;
 
-    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{testing::mock_env, Decimal};
+    use cw_utils::Duration;
     use dao_voting::multiple_choice::{MultipleChoiceOption, MultipleChoiceOptions};
+    use dao_voting::threshold::QuorumDecay;
 
     fn create_proposal(
         block: &BlockInfo,
@@ -304,6 +331,7 @@ mod tests {
             voting_strategy,
             total_power,
             votes,
+            power_cast: Uint128::zero(),
             allow_revoting,
             min_voting_period: None,
         }
@@ -314,6 +342,7 @@ mod tests {
         let env = mock_env();
         let voting_strategy = VotingStrategy::SingleChoice {
             quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            quorum_decay: None,
         };
 
         let votes = MultipleChoiceVotes {
@@ -421,6 +450,7 @@ mod tests {
             quorum: dao_voting::threshold::PercentageThreshold::Percent(
                 cosmwasm_std::Decimal::percent(10),
             ),
+            quorum_decay: None,
         };
 
         let votes = MultipleChoiceVotes {
@@ -528,6 +558,7 @@ mod tests {
             quorum: dao_voting::threshold::PercentageThreshold::Percent(
                 cosmwasm_std::Decimal::percent(10),
             ),
+            quorum_decay: None,
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(50), Uint128::new(500)],
@@ -553,6 +584,7 @@ mod tests {
             quorum: dao_voting::threshold::PercentageThreshold::Percent(
                 cosmwasm_std::Decimal::percent(10),
             ),
+            quorum_decay: None,
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(10), Uint128::new(0), Uint128::new(0)],
@@ -575,6 +607,7 @@ mod tests {
             quorum: dao_voting::threshold::PercentageThreshold::Percent(
                 cosmwasm_std::Decimal::percent(100),
             ),
+            quorum_decay: None,
         };
 
         let votes = MultipleChoiceVotes {
@@ -598,6 +631,7 @@ mod tests {
             quorum: dao_voting::threshold::PercentageThreshold::Percent(
                 cosmwasm_std::Decimal::percent(99),
             ),
+            quorum_decay: None,
         };
 
         let votes = MultipleChoiceVotes {
@@ -624,6 +658,7 @@ mod tests {
             quorum: dao_voting::threshold::PercentageThreshold::Percent(
                 cosmwasm_std::Decimal::from_ratio(7u32, 13u32),
             ),
+            quorum_decay: None,
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(7), Uint128::new(0), Uint128::new(6)],
@@ -660,6 +695,7 @@ mod tests {
         let env = mock_env();
         let voting_strategy = VotingStrategy::SingleChoice {
             quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            quorum_decay: None,
         };
 
         let votes = MultipleChoiceVotes {
@@ -699,6 +735,7 @@ mod tests {
         let env = mock_env();
         let voting_strategy = VotingStrategy::SingleChoice {
             quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            quorum_decay: None,
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(6), Uint128::new(0), Uint128::new(0)],
@@ -734,6 +771,7 @@ mod tests {
         let env = mock_env();
         let voting_strategy = VotingStrategy::SingleChoice {
             quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            quorum_decay: None,
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(5), Uint128::new(5), Uint128::new(0)],
@@ -775,6 +813,7 @@ mod tests {
             quorum: dao_voting::threshold::PercentageThreshold::Percent(
                 cosmwasm_std::Decimal::percent(80),
             ),
+            quorum_decay: None,
         };
 
         let votes = MultipleChoiceVotes {
@@ -813,6 +852,7 @@ mod tests {
             quorum: dao_voting::threshold::PercentageThreshold::Percent(
                 cosmwasm_std::Decimal::percent(80),
             ),
+            quorum_decay: None,
         };
 
         let votes = MultipleChoiceVotes {
@@ -845,4 +885,65 @@ mod tests {
         // No quorum reached & proposal has expired => rejection
         assert!(prop.is_rejected(&env.block).unwrap());
     }
+
+    #[test]
+    fn test_quorum_decay() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+            quorum_decay: Some(QuorumDecay {
+                floor: Decimal::percent(10),
+                period: Duration::Height(10),
+                steps: None,
+            }),
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(15), Uint128::new(0), Uint128::new(0)],
+        };
+
+        // Halfway through the decay period the quorum is 20%, which
+        // has not been met.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert_eq!(
+            prop.effective_quorum(&env.block),
+            PercentageThreshold::Percent(Decimal::percent(20))
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        // At expiration the quorum has decayed to its floor, so the
+        // proposal passes.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // Without decay the proposal does not reach quorum.
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+            quorum_decay: None,
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
 }
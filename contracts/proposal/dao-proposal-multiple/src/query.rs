@@ -2,7 +2,7 @@ use crate::{proposal::MultipleChoiceProposal, state::Config};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 
-use dao_voting::multiple_choice::MultipleChoiceVote;
+use dao_voting::multiple_choice::{MultiOptionVote, MultipleChoiceVote};
 
 #[cw_serde]
 pub struct ProposalListResponse {
@@ -21,8 +21,11 @@ pub struct ProposalResponse {
 pub struct VoteInfo {
     /// The address that voted.
     pub voter: Addr,
-    /// Position on the vote.
+    /// Position on the vote. For approval and score votes, the most
+    /// supported option.
     pub vote: MultipleChoiceVote,
+    /// If set, the approval or score vote that was cast.
+    pub multi_option_vote: Option<MultiOptionVote>,
     /// The voting power behind the vote.
     pub power: Uint128,
     /// The rationale behind the vote.
@@ -1,11 +1,11 @@
 use crate::proposal::MultipleChoiceProposal;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, VotingStrategy},
+    multiple_choice::{MultiOptionVote, MultipleChoiceVote, VotingStrategy},
     pre_propose::ProposalCreationPolicy,
 };
 
@@ -50,8 +50,13 @@ pub struct Config {
 pub struct Ballot {
     /// The amount of voting power behind the vote.
     pub power: Uint128,
-    /// The position.
+    /// The position. For approval and score votes, the most supported
+    /// option.
     pub vote: MultipleChoiceVote,
+    /// If set, the ballot's power counts towards the options this
+    /// approval or score vote supports.
+    #[serde(default)]
+    pub multi_option_vote: Option<MultiOptionVote>,
     /// An optional rationale for why this vote was cast.
     pub rationale: Option<String>,
 }
@@ -61,6 +66,15 @@ pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, MultipleChoiceProposal> = Map::new("proposals");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The vote delegation contract used to count delegated voting
+/// power, if any.
+pub const DELEGATION_MODULE: Item<Addr> = Item::new("delegation_module");
+/// The reward paid from this module's balance to callers of
+/// `CloseExpired` for each proposal they close, if any.
+pub const CLOSE_EXPIRED_REWARD: Item<Coin> = Item::new("close_expired_reward");
+/// All proposals with an ID less than or equal to this will never
+/// need to be closed by `CloseExpired`.
+pub const CLOSE_EXPIRED_CURSOR: Item<u64> = Item::new("close_expired_cursor");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -265,6 +265,7 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
     let instantiate = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(66)),
+            quorum_decay: None,
         },
         max_voting_period: Duration::Time(604800),
         min_voting_period: None,
@@ -7,7 +7,8 @@ use dao_testing::ShouldExecute;
 use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
     multiple_choice::{
-        MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy,
+        MultiOptionVote, MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote,
+        VotingStrategy,
     },
     status::Status,
     threshold::PercentageThreshold,
@@ -82,6 +83,57 @@ fn do_votes_cw4_weights(
     );
 }
 
+/// An approval or score vote to be cast in a test.
+pub struct TestMultiOptionVote {
+    /// The address casting the vote.
+    pub voter: String,
+    /// The options supported by the vote.
+    pub vote: MultiOptionVote,
+    /// Voting power of the address.
+    pub weight: Uint128,
+    /// If this vote is expected to execute.
+    pub should_execute: ShouldExecute,
+}
+
+fn do_multi_option_votes_staked_balances(
+    votes: Vec<TestMultiOptionVote>,
+    voting_strategy: VotingStrategy,
+    expected_status: Status,
+    total_supply: Option<Uint128>,
+    should_expire: bool,
+) {
+    let votes = votes
+        .into_iter()
+        .map(
+            |TestMultiOptionVote {
+                 voter,
+                 vote,
+                 weight,
+                 should_execute,
+             }| {
+                (
+                    TestMultipleChoiceVote {
+                        voter,
+                        position: vote.position(),
+                        weight,
+                        should_execute,
+                    },
+                    Some(vote),
+                )
+            },
+        )
+        .collect();
+    do_test_votes_inner(
+        votes,
+        voting_strategy,
+        expected_status,
+        total_supply,
+        None::<UncheckedDepositInfo>,
+        should_expire,
+        instantiate_with_staked_balances_governance,
+    );
+}
+
 // Creates multiple choice proposal with provided config and executes provided votes against it.
 fn do_test_votes<F>(
     votes: Vec<TestMultipleChoiceVote>,
@@ -92,6 +144,31 @@ fn do_test_votes<F>(
     should_expire: bool,
     setup_governance: F,
 ) -> (App, Addr)
+where
+    F: Fn(&mut App, InstantiateMsg, Option<Vec<Cw20Coin>>) -> Addr,
+{
+    do_test_votes_inner(
+        votes.into_iter().map(|vote| (vote, None)).collect(),
+        voting_strategy,
+        expected_status,
+        total_supply,
+        deposit_info,
+        should_expire,
+        setup_governance,
+    )
+}
+
+// Votes paired with an approval or score vote are cast with
+// `ExecuteMsg::VoteMultiOption`.
+fn do_test_votes_inner<F>(
+    votes: Vec<(TestMultipleChoiceVote, Option<MultiOptionVote>)>,
+    voting_strategy: VotingStrategy,
+    expected_status: Status,
+    total_supply: Option<Uint128>,
+    deposit_info: Option<UncheckedDepositInfo>,
+    should_expire: bool,
+    setup_governance: F,
+) -> (App, Addr)
 where
     F: Fn(&mut App, InstantiateMsg, Option<Vec<Cw20Coin>>) -> Addr,
 {
@@ -100,12 +177,14 @@ where
 
     let mut initial_balances = votes
         .iter()
-        .map(|TestMultipleChoiceVote { voter, weight, .. }| Cw20Coin {
-            address: voter.to_string(),
-            amount: *weight,
-        })
+        .map(
+            |(TestMultipleChoiceVote { voter, weight, .. }, _)| Cw20Coin {
+                address: voter.to_string(),
+                amount: *weight,
+            },
+        )
         .collect::<Vec<Cw20Coin>>();
-    let initial_balances_supply = votes.iter().fold(Uint128::zero(), |p, n| p + n.weight);
+    let initial_balances_supply = votes.iter().fold(Uint128::zero(), |p, n| p + n.0.weight);
     let to_fill = total_supply.map(|total_supply| total_supply - initial_balances_supply);
     if let Some(fill) = to_fill {
         initial_balances.push(Cw20Coin {
@@ -117,7 +196,7 @@ where
     let pre_propose_info = get_pre_propose_info(&mut app, deposit_info, false);
 
     let proposer = match votes.first() {
-        Some(vote) => vote.voter.clone(),
+        Some((vote, _)) => vote.voter.clone(),
         None => panic!("do_test_votes must have at least one vote."),
     };
 
@@ -219,7 +298,7 @@ where
     .unwrap();
 
     // Cast votes.
-    for vote in votes {
+    for (vote, multi_option_vote) in votes {
         let TestMultipleChoiceVote {
             voter,
             position,
@@ -227,16 +306,19 @@ where
             should_execute,
         } = vote;
         // Vote on the proposal.
-        let res = app.execute_contract(
-            Addr::unchecked(voter.clone()),
-            govmod.clone(),
-            &ExecuteMsg::Vote {
+        let msg = match multi_option_vote {
+            Some(ref vote) => ExecuteMsg::VoteMultiOption {
+                proposal_id: 1,
+                vote: vote.clone(),
+                rationale: None,
+            },
+            None => ExecuteMsg::Vote {
                 proposal_id: 1,
                 vote: position,
                 rationale: None,
             },
-            &[],
-        );
+        };
+        let res = app.execute_contract(Addr::unchecked(voter.clone()), govmod.clone(), &msg, &[]);
         match should_execute {
             ShouldExecute::Yes => {
                 if res.is_err() {
@@ -258,6 +340,7 @@ where
                     vote: Some(VoteInfo {
                         voter: Addr::unchecked(&voter),
                         vote: position,
+                        multi_option_vote,
                         power: match deposit_config.deposit_info {
                             Some(CheckedDepositInfo {
                                 amount,
@@ -339,6 +422,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -355,6 +439,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         Status::Rejected,
         None,
@@ -376,6 +461,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         Status::Open,
         None,
@@ -396,6 +482,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -419,6 +506,7 @@ where
         ],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -447,6 +535,7 @@ where
         ],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         Status::Rejected,
         None,
@@ -467,6 +556,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         Status::Rejected,
         None,
@@ -483,6 +573,7 @@ where
             }],
             VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Percent(Decimal::percent(i)),
+                quorum_decay: None,
             },
             Status::Rejected,
             None,
@@ -508,6 +599,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(1)),
+            quorum_decay: None,
         },
         Status::Passed,
         Some(Uint128::new(100)),
@@ -523,6 +615,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(1)),
+            quorum_decay: None,
         },
         Status::Passed,
         Some(Uint128::new(1000)),
@@ -540,6 +633,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(1)),
+            quorum_decay: None,
         },
         Status::Rejected,
         Some(Uint128::new(1000000000)),
@@ -556,6 +650,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(1)),
+            quorum_decay: None,
         },
         Status::Rejected,
         None,
@@ -584,6 +679,7 @@ where
         ],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         // NOTE: Updating our cw20-base version will cause this to
         // fail. In versions of cw20-base before Feb 15 2022 (the one
@@ -620,6 +716,7 @@ where
         ],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+            quorum_decay: None,
         },
         Status::Passed,
         Some(Uint128::new(40)),
@@ -644,6 +741,7 @@ where
         ],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Rejected,
         Some(Uint128::new(40)),
@@ -664,6 +762,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+            quorum_decay: None,
         },
         Status::Passed,
         Some(Uint128::new(100)),
@@ -680,6 +779,7 @@ where
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+            quorum_decay: None,
         },
         Status::Rejected,
         Some(Uint128::new(100)),
@@ -748,6 +848,7 @@ where
             votes,
             VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             expected_status,
             None,
@@ -756,6 +857,203 @@ where
     }
 }
 
+pub fn test_approval_votes<F>(do_votes: F)
+where
+    F: Fn(Vec<TestMultiOptionVote>, VotingStrategy, Status, Option<Uint128>, bool),
+{
+    let approval = VotingStrategy::Approval {
+        quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+        quorum_decay: None,
+    };
+
+    // Both voters approve of option 1, so it wins.
+    do_votes(
+        vec![
+            TestMultiOptionVote {
+                voter: "bluenote".to_string(),
+                vote: MultiOptionVote::Approval {
+                    option_ids: vec![0, 1],
+                },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestMultiOptionVote {
+                voter: "bob".to_string(),
+                vote: MultiOptionVote::Approval {
+                    option_ids: vec![1],
+                },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        approval.clone(),
+        Status::Passed,
+        None,
+        false,
+    );
+
+    // Approving of several options ties them.
+    do_votes(
+        vec![TestMultiOptionVote {
+            voter: "bluenote".to_string(),
+            vote: MultiOptionVote::Approval {
+                option_ids: vec![0, 1],
+            },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        approval.clone(),
+        Status::Rejected,
+        None,
+        false,
+    );
+
+    // Quorum counts voting power that voted, not approvals. 15 of
+    // 40 voting power has voted which is less than half though the
+    // options have received 25 approvals in total.
+    do_votes(
+        vec![
+            TestMultiOptionVote {
+                voter: "bluenote".to_string(),
+                vote: MultiOptionVote::Approval {
+                    option_ids: vec![0, 1],
+                },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestMultiOptionVote {
+                voter: "bob".to_string(),
+                vote: MultiOptionVote::Approval {
+                    option_ids: vec![0],
+                },
+                weight: Uint128::new(5),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        approval.clone(),
+        Status::Rejected,
+        Some(Uint128::new(40)),
+        true,
+    );
+
+    // Options may not be approved of more than once.
+    do_votes(
+        vec![TestMultiOptionVote {
+            voter: "bluenote".to_string(),
+            vote: MultiOptionVote::Approval {
+                option_ids: vec![0, 0],
+            },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::No,
+        }],
+        approval.clone(),
+        Status::Open,
+        None,
+        false,
+    );
+
+    // Score votes may not be cast on approval proposals.
+    do_votes(
+        vec![TestMultiOptionVote {
+            voter: "bluenote".to_string(),
+            vote: MultiOptionVote::Score {
+                scores: vec![1, 0, 0],
+            },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::No,
+        }],
+        approval,
+        Status::Open,
+        None,
+        false,
+    );
+}
+
+pub fn test_score_votes<F>(do_votes: F)
+where
+    F: Fn(Vec<TestMultiOptionVote>, VotingStrategy, Status, Option<Uint128>, bool),
+{
+    let score = VotingStrategy::Score {
+        quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+        max_score: 10,
+        quorum_decay: None,
+    };
+
+    // Option 0 receives 10 + 0 and option 1 receives 5 + 10.
+    do_votes(
+        vec![
+            TestMultiOptionVote {
+                voter: "bluenote".to_string(),
+                vote: MultiOptionVote::Score {
+                    scores: vec![10, 5, 0],
+                },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestMultiOptionVote {
+                voter: "bob".to_string(),
+                vote: MultiOptionVote::Score {
+                    scores: vec![0, 10, 0],
+                },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        score.clone(),
+        Status::Passed,
+        None,
+        false,
+    );
+
+    // Equal scores tie.
+    do_votes(
+        vec![TestMultiOptionVote {
+            voter: "bluenote".to_string(),
+            vote: MultiOptionVote::Score {
+                scores: vec![5, 5, 0],
+            },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        score.clone(),
+        Status::Rejected,
+        None,
+        false,
+    );
+
+    // None of the above scoring highest rejects the proposal.
+    do_votes(
+        vec![TestMultiOptionVote {
+            voter: "bluenote".to_string(),
+            vote: MultiOptionVote::Score {
+                scores: vec![2, 1, 10],
+            },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        score.clone(),
+        Status::Rejected,
+        None,
+        false,
+    );
+
+    // Scores may not exceed the max score.
+    do_votes(
+        vec![TestMultiOptionVote {
+            voter: "bluenote".to_string(),
+            vote: MultiOptionVote::Score {
+                scores: vec![11, 0, 0],
+            },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::No,
+        }],
+        score,
+        Status::Open,
+        None,
+        false,
+    );
+}
+
 #[test]
 fn test_vote_simple() {
     test_simple_votes(do_votes_cw20_balances);
@@ -840,3 +1138,32 @@ fn fuzz_votes_cw4_weights() {
 fn fuzz_votes_staked_balances() {
     fuzz_voting(do_votes_staked_balances)
 }
+
+#[test]
+fn test_approval() {
+    test_approval_votes(do_multi_option_votes_staked_balances)
+}
+
+#[test]
+fn test_score() {
+    test_score_votes(do_multi_option_votes_staked_balances)
+}
+
+#[test]
+fn test_single_choice_vote_on_approval_proposal() {
+    do_votes_staked_balances(
+        vec![TestMultipleChoiceVote {
+            voter: "bluenote".to_string(),
+            position: MultipleChoiceVote { option_id: 0 },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::No,
+        }],
+        VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
+        },
+        Status::Open,
+        None,
+        false,
+    );
+}
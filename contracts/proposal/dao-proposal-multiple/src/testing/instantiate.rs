@@ -49,7 +49,10 @@ fn get_pre_propose_info(
 
 pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> InstantiateMsg {
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
 
     InstantiateMsg {
         voting_strategy,
@@ -73,7 +76,10 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
 // Same as above but no proposal deposit.
 fn _get_default_non_token_dao_proposal_module_instantiate(app: &mut App) -> InstantiateMsg {
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
 
     InstantiateMsg {
         voting_strategy,
@@ -9,9 +9,9 @@ use dao_interface::state::{Admin, ModuleInstantiateInfo};
 use dao_voting::{
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
     multiple_choice::{
-        CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
-        MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
-        MAX_NUM_CHOICES,
+        CheckedMultipleChoiceOption, MultiOptionVote, MultipleChoiceOption,
+        MultipleChoiceOptionType, MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes,
+        VotingStrategy, MAX_NUM_CHOICES,
     },
     pre_propose::PreProposeInfo,
     status::Status,
@@ -81,6 +81,15 @@ pub fn pre_propose_multiple_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn vote_delegation_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_vote_delegation::contract::execute,
+        dao_vote_delegation::contract::instantiate,
+        dao_vote_delegation::contract::query,
+    );
+    Box::new(contract)
+}
+
 pub fn get_pre_propose_info(
     app: &mut App,
     deposit_info: Option<UncheckedDepositInfo>,
@@ -110,7 +119,10 @@ fn test_propose() {
     let max_voting_period = Duration::Height(6);
     let quorum = PercentageThreshold::Majority {};
 
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
 
     let instantiate = InstantiateMsg {
         max_voting_period,
@@ -173,6 +185,7 @@ fn test_propose() {
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(); 3],
         },
+        power_cast: Uint128::zero(),
         allow_revoting: false,
         min_voting_period: None,
     };
@@ -189,7 +202,10 @@ fn test_propose_wrong_num_choices() {
     let max_voting_period = cw_utils::Duration::Height(6);
     let quorum = PercentageThreshold::Majority {};
 
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
 
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -268,6 +284,7 @@ fn test_proposal_count_initialized_to_zero() {
     let msg = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+            quorum_decay: None,
         },
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
@@ -302,6 +319,7 @@ fn test_no_early_pass_with_min_duration() {
     let msg = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+            quorum_decay: None,
         },
         max_voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(2)),
@@ -396,6 +414,7 @@ fn test_propose_with_messages() {
     let msg = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+            quorum_decay: None,
         },
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
@@ -432,6 +451,7 @@ fn test_propose_with_messages() {
     let config_msg = ExecuteMsg::UpdateConfig {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
@@ -515,6 +535,7 @@ fn test_min_duration_units_missmatch() {
     let msg = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+            quorum_decay: None,
         },
         max_voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Time(2)),
@@ -547,6 +568,7 @@ fn test_min_duration_larger_than_proposal_duration() {
     let msg = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+            quorum_decay: None,
         },
         max_voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(11)),
@@ -578,6 +600,7 @@ fn test_min_duration_same_as_proposal_duration() {
     let msg = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+            quorum_decay: None,
         },
         max_voting_period: Duration::Time(10),
         min_voting_period: Some(Duration::Time(10)),
@@ -686,7 +709,10 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
     let _govmod_id = app.store_code(proposal_multiple_contract());
 
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
 
     let instantiate = InstantiateMsg {
@@ -760,7 +786,10 @@ fn test_different_token_proposal_deposit() {
         .unwrap();
 
     let quorum = PercentageThreshold::Percent(Decimal::percent(10));
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -821,7 +850,10 @@ fn test_bad_token_proposal_deposit() {
         .unwrap();
 
     let quorum = PercentageThreshold::Percent(Decimal::percent(10));
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -852,7 +884,10 @@ fn test_take_proposal_deposit() {
     let _govmod_id = app.store_code(proposal_multiple_contract());
 
     let quorum = PercentageThreshold::Percent(Decimal::percent(10));
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
 
     let instantiate = InstantiateMsg {
@@ -959,6 +994,7 @@ fn test_native_proposal_deposit() {
     let instantiate = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         max_voting_period,
         min_voting_period: None,
@@ -1106,6 +1142,7 @@ fn test_deposit_return_on_execute() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -1167,6 +1204,7 @@ fn test_deposit_return_zero() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -1222,6 +1260,7 @@ fn test_query_list_votes() {
         ],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -1254,12 +1293,14 @@ fn test_query_list_votes() {
         VoteInfo {
             voter: Addr::unchecked("blue"),
             vote: MultipleChoiceVote { option_id: 0 },
+            multi_option_vote: None,
             power: Uint128::new(10),
             rationale: None,
         },
         VoteInfo {
             voter: Addr::unchecked("note"),
             vote: MultipleChoiceVote { option_id: 1 },
+            multi_option_vote: None,
             power: Uint128::new(20),
             rationale: None,
         },
@@ -1280,6 +1321,7 @@ fn test_invalid_quorum() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::from_ratio(1u128, 10u128)),
+            quorum_decay: None,
         },
         Status::Rejected,
         None,
@@ -1300,6 +1342,7 @@ fn test_cant_vote_executed_or_closed() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Rejected,
         None,
@@ -1348,6 +1391,7 @@ fn test_cant_vote_executed_or_closed() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -1383,7 +1427,10 @@ fn test_cant_propose_zero_power() {
     let mut app = App::default();
     let _govmod_id = app.store_code(proposal_multiple_contract());
     let quorum = PercentageThreshold::Percent(Decimal::percent(10));
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -1506,6 +1553,7 @@ fn test_cant_vote_not_registered() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Open,
         Some(Uint128::new(100)),
@@ -1555,7 +1603,10 @@ fn test_cant_execute_not_member() {
     let max_voting_period = cw_utils::Duration::Height(6);
     let quorum = PercentageThreshold::Majority {};
 
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
 
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -1645,7 +1696,10 @@ fn test_cant_execute_not_member_when_proposal_created() {
     let max_voting_period = cw_utils::Duration::Height(6);
     let quorum = PercentageThreshold::Majority {};
 
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
 
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -1765,6 +1819,7 @@ fn test_open_proposal_submission() {
     let instantiate = InstantiateMsg {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         max_voting_period,
         min_voting_period: None,
@@ -1805,11 +1860,13 @@ fn test_open_proposal_submission() {
         start_height: current_block.height,
         expiration: max_voting_period.after(&current_block),
         min_voting_period: None,
+        power_cast: Uint128::zero(),
         allow_revoting: false,
         total_power: Uint128::new(100_000_000),
         status: Status::Open,
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Percent(Decimal::percent(100)),
+            quorum_decay: None,
         },
         choices: vec![
             CheckedMultipleChoiceOption {
@@ -1857,6 +1914,7 @@ fn test_close_open_proposal() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Open,
         Some(Uint128::new(100)),
@@ -1914,6 +1972,86 @@ fn test_close_open_proposal() {
     };
 }
 
+#[test]
+fn test_close_expired() {
+    let (mut app, core_addr) = do_test_votes_cw20_balances(
+        vec![TestMultipleChoiceVote {
+            voter: "blue".to_string(),
+            position: MultipleChoiceVote { option_id: 2 },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
+        },
+        Status::Open,
+        Some(Uint128::new(100)),
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::VotingModuleToken {},
+            amount: Uint128::new(1),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        }),
+        false,
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    app.execute_contract(
+        core_addr.clone(),
+        govmod.clone(),
+        &ExecuteMsg::UpdateCloseExpiredReward {
+            reward: Some(Coin::new(5, "ujuno")),
+        },
+        &[],
+    )
+    .unwrap();
+    // Rewards are paid from the proposal module's balance.
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: govmod.to_string(),
+        amount: vec![Coin::new(5, "ujuno")],
+    }))
+    .unwrap();
+
+    // Nothing is closed or paid for before the proposal expires.
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        govmod.clone(),
+        &ExecuteMsg::CloseExpired { limit: None },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(query_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+
+    // Make the proposal expire.
+    app.update_block(|block| block.height += 10);
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        govmod.clone(),
+        &ExecuteMsg::CloseExpired { limit: None },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+    assert_eq!(query_balance_native(&app, "ekez", "ujuno"), Uint128::new(5));
+
+    // The deposit has been forfeited.
+    let (deposit_config, _) = query_deposit_config_and_pre_propose_module(&app, &govmod);
+    if let CheckedDepositInfo {
+        denom: CheckedDenom::Cw20(ref token),
+        ..
+    } = deposit_config.deposit_info.unwrap()
+    {
+        let balance = query_balance_cw20(&app, token, "blue".to_string());
+        assert_eq!(balance, Uint128::new(9));
+    } else {
+        panic!()
+    };
+}
+
 #[test]
 fn test_no_refund_failed_proposal() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
@@ -1925,6 +2063,7 @@ fn test_no_refund_failed_proposal() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Open,
         Some(Uint128::new(100)),
@@ -1983,6 +2122,7 @@ fn test_zero_deposit() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -1994,7 +2134,10 @@ fn test_zero_deposit() {
 #[test]
 fn test_deposit_return_on_close() {
     let quorum = PercentageThreshold::Percent(Decimal::percent(10));
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
 
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
@@ -2055,7 +2198,10 @@ fn test_execute_expired_proposal() {
     let mut app = App::default();
     let _govmod_id = app.store_code(proposal_multiple_contract());
     let quorum = PercentageThreshold::Percent(Decimal::percent(10));
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -2185,6 +2331,7 @@ fn test_update_config() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -2206,7 +2353,8 @@ fn test_update_config() {
     assert_eq!(
         govmod_config.voting_strategy,
         VotingStrategy::SingleChoice {
-            quorum: PercentageThreshold::Majority {}
+            quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         }
     );
 
@@ -2220,6 +2368,7 @@ fn test_update_config() {
         &ExecuteMsg::UpdateConfig {
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
@@ -2239,6 +2388,7 @@ fn test_update_config() {
         &ExecuteMsg::UpdateConfig {
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
@@ -2256,6 +2406,7 @@ fn test_update_config() {
     let expected = Config {
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
@@ -2274,6 +2425,7 @@ fn test_update_config() {
         &ExecuteMsg::UpdateConfig {
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
@@ -2298,6 +2450,7 @@ fn test_no_return_if_no_refunds() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Rejected,
         None,
@@ -2346,7 +2499,10 @@ fn test_query_list_proposals() {
     let mut app = App::default();
     let _govmod_id = app.store_code(proposal_multiple_contract());
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -2434,6 +2590,7 @@ fn test_query_list_proposals() {
             votes: MultipleChoiceVotes {
                 vote_weights: vec![Uint128::zero(); 3],
             },
+            power_cast: Uint128::zero(),
             allow_revoting: false,
             min_voting_period: None,
         },
@@ -2462,6 +2619,7 @@ fn test_query_list_proposals() {
             votes: MultipleChoiceVotes {
                 vote_weights: vec![Uint128::zero(); 3],
             },
+            power_cast: Uint128::zero(),
             allow_revoting: false,
             min_voting_period: None,
         },
@@ -2479,7 +2637,10 @@ fn test_hooks() {
     let _govmod_id = app.store_code(proposal_multiple_contract());
 
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -2605,7 +2766,10 @@ fn test_active_threshold_absolute() {
     let _govmod_id = app.store_code(proposal_multiple_contract());
 
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -2732,7 +2896,10 @@ fn test_active_threshold_percent() {
     let mut app = App::default();
     let _govmod_id = app.store_code(proposal_multiple_contract());
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -2860,7 +3027,10 @@ fn test_active_threshold_none() {
     let mut app = App::default();
     let _govmod_id = app.store_code(proposal_multiple_contract());
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
@@ -2977,6 +3147,7 @@ fn test_revoting() {
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -3109,6 +3280,7 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -3167,6 +3339,7 @@ fn test_allow_revoting_config_changes() {
             dao: core_addr.to_string(),
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
         },
@@ -3260,6 +3433,7 @@ fn test_revoting_same_vote_twice() {
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -3354,6 +3528,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -3482,6 +3657,7 @@ fn test_return_deposit_to_dao_on_proposal_failure() {
         }],
         VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Open,
         Some(Uint128::new(100)),
@@ -3538,7 +3714,10 @@ fn test_close_failed_proposal() {
     let _govmod_id = app.store_code(proposal_multiple_contract());
 
     let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let voting_strategy = VotingStrategy::SingleChoice {
+        quorum,
+        quorum_decay: None,
+    };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         max_voting_period,
@@ -3675,7 +3854,10 @@ fn test_close_failed_proposal() {
                             msgs: vec![WasmMsg::Execute {
                                 contract_addr: govmod.to_string(),
                                 msg: to_binary(&ExecuteMsg::UpdateConfig {
-                                    voting_strategy: VotingStrategy::SingleChoice { quorum },
+                                    voting_strategy: VotingStrategy::SingleChoice {
+                                        quorum,
+                                        quorum_decay: None,
+                                    },
                                     max_voting_period: original.max_voting_period,
                                     min_voting_period: original.min_voting_period,
                                     only_members_execute: original.only_members_execute,
@@ -3775,6 +3957,7 @@ fn test_no_double_refund_on_execute_fail_and_close() {
 
     let voting_strategy = VotingStrategy::SingleChoice {
         quorum: PercentageThreshold::Majority {},
+        quorum_decay: None,
     };
     let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
@@ -3970,6 +4153,7 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         allow_revoting: false,
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
@@ -4062,6 +4246,7 @@ fn test_next_proposal_id() {
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -4133,6 +4318,7 @@ fn test_vote_with_rationale() {
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -4229,6 +4415,7 @@ fn test_revote_with_rationale() {
             allow_revoting: true, // Enable revoting
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -4383,6 +4570,7 @@ fn test_update_rationale() {
             allow_revoting: true, // Enable revoting
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -4499,3 +4687,256 @@ fn test_update_rationale() {
         Some("This may be a good idea, but I'm not sure. YOLO".to_string())
     );
 }
+
+#[test]
+fn test_vote_delegation() {
+    let mut app = App::default();
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: true,
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+                quorum_decay: None,
+            },
+            close_proposal_on_execution_failure: false,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "alice".to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "bob".to_string(),
+                amount: Uint128::new(20),
+            },
+            Cw20Coin {
+                address: "carol".to_string(),
+                amount: Uint128::new(30),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let delegation_id = app.store_code(vote_delegation_contract());
+    let delegation = app
+        .instantiate_contract(
+            delegation_id,
+            core_addr.clone(),
+            &dao_vote_delegation::msg::InstantiateMsg {},
+            &[],
+            "vote delegation",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &ExecuteMsg::UpdateDelegationModule {
+            delegation_module: Some(delegation.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("bob"),
+        delegation.clone(),
+        &dao_vote_delegation::msg::ExecuteMsg::Register {},
+        &[],
+    )
+    .unwrap();
+    for delegator in ["alice", "carol"] {
+        app.execute_contract(
+            Addr::unchecked(delegator),
+            delegation.clone(),
+            &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+                delegate: "bob".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.update_block(next_block);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    let proposal_id = make_proposal(&mut app, &govmod, "bob", MultipleChoiceOptions { options });
+
+    let vote = |app: &mut App, voter: &str, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // Carol votes before her delegate, so her voting power is not
+    // counted in bob's vote.
+    vote(&mut app, "carol", 1);
+    vote(&mut app, "bob", 0);
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(30), Uint128::new(30), Uint128::zero()]
+    );
+
+    // Alice votes after her delegate, overriding bob's vote with her
+    // own voting power.
+    vote(&mut app, "alice", 1);
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(20), Uint128::new(40), Uint128::zero()]
+    );
+    let ballot: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::GetVote {
+                proposal_id,
+                voter: "bob".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(ballot.vote.unwrap().power, Uint128::new(20));
+}
+
+#[test]
+fn test_score_vote_delegation() {
+    let mut app = App::default();
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::Score {
+                quorum: PercentageThreshold::Majority {},
+                max_score: 3,
+                quorum_decay: None,
+            },
+            close_proposal_on_execution_failure: false,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "alice".to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "bob".to_string(),
+                amount: Uint128::new(20),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let delegation_id = app.store_code(vote_delegation_contract());
+    let delegation = app
+        .instantiate_contract(
+            delegation_id,
+            core_addr.clone(),
+            &dao_vote_delegation::msg::InstantiateMsg {},
+            &[],
+            "vote delegation",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &ExecuteMsg::UpdateDelegationModule {
+            delegation_module: Some(delegation.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("bob"),
+        delegation.clone(),
+        &dao_vote_delegation::msg::ExecuteMsg::Register {},
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("alice"),
+        delegation,
+        &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+            delegate: "bob".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    let proposal_id = make_proposal(&mut app, &govmod, "bob", MultipleChoiceOptions { options });
+
+    let vote = |app: &mut App, voter: &str, scores: Vec<u32>| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiOption {
+                proposal_id,
+                vote: MultiOptionVote::Score { scores },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // Bob votes with 30 voting power, giving option 0 a third of it.
+    vote(&mut app, "bob", vec![1, 0, 0]);
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(10), Uint128::zero(), Uint128::zero()]
+    );
+
+    // Alice overrides bob's vote. Bob's score for option 0 is
+    // recomputed from his remaining 20 voting power, rather than a
+    // third of alice's 10 being subtracted from it, which would leave
+    // rounding dust in the tally.
+    vote(&mut app, "alice", vec![0, 3, 0]);
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(6), Uint128::new(10), Uint128::zero()]
+    );
+    assert_eq!(proposal.proposal.power_cast, Uint128::new(30));
+}
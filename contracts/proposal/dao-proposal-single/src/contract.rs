@@ -1,8 +1,9 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Reply, Response, StdError, StdResult, Storage, SubMsg, SubMsgResult, Timestamp, Uint128,
+    WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_hooks::Hooks;
@@ -10,21 +11,30 @@ use cw_proposal_single_v1 as v1;
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_instantiate_data, Duration};
 use dao_interface::voting::IsActiveResponse;
-use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
+use dao_pre_propose_base::msg::DepositInfoResponse;
+use dao_proposal_hooks::{
+    new_proposal_hooks, proposal_amended_hooks, proposal_status_changed_hooks,
+};
 use dao_vote_hooks::new_vote_hooks;
+use dao_voting::delegation::{get_delegate, get_delegated_voting_power};
+use dao_voting::deposit::DepositRefundPolicy;
 use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
 use dao_voting::proposal::{
     SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
 };
 use dao_voting::reply::{
-    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
+    mask_proposal_message_execution, TaggedReplyId,
 };
 use dao_voting::status::Status;
 use dao_voting::threshold::Threshold;
-use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
+use dao_voting::veto::{VetoConfig, VetoError};
+use dao_voting::voting::{
+    get_total_power, get_voting_power, validate_voting_period, Vote, Votes, WeightedVote,
+};
 
 use crate::msg::MigrateMsg;
-use crate::proposal::{next_proposal_id, SingleChoiceProposal};
+use crate::proposal::{next_proposal_id, MessageResult, SingleChoiceProposal};
 use crate::state::{Config, CREATION_POLICY};
 
 use crate::v1_state::{
@@ -35,17 +45,28 @@ use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
-    state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
+    query::{ProposalResponse, RevisionListResponse, VoteInfo, VoteListResponse, VoteResponse},
+    state::{
+        Ballot, ProposalRevision, BALLOTS, CLOSE_EXPIRED_CURSOR, CLOSE_EXPIRED_REWARD, CONFIG,
+        DELEGATION_MODULE, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, REVISIONS, VOTE_HOOKS,
+    },
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The maximum length, in bytes, of an execution error recorded on a
+/// proposal.
+pub(crate) const MAX_EXECUTION_ERROR_LENGTH: usize = 256;
+
 /// Message type used for firing hooks to this module's pre-propose
 /// module, if one is installed.
 type PreProposeHookMsg = dao_pre_propose_base::msg::ExecuteMsg<Empty, Empty>;
 
+/// Message type used for querying this module's pre-propose module,
+/// if one is installed.
+type PreProposeQueryMsg = dao_pre_propose_base::msg::QueryMsg<Empty>;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -61,6 +82,13 @@ pub fn instantiate(
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(msg.min_voting_period, msg.max_voting_period)?;
+    if let Some(decay) = msg.threshold.quorum_decay() {
+        decay.validate_units(&max_voting_period)?;
+    }
+
+    if let Some(veto) = &msg.veto {
+        veto.validate(&deps.as_ref(), &max_voting_period)?;
+    }
 
     let (initial_policy, pre_propose_messages) = msg
         .pre_propose_info
@@ -74,6 +102,7 @@ pub fn instantiate(
         dao: dao.clone(),
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        veto: msg.veto,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -101,18 +130,52 @@ pub fn execute(
             description,
             msgs,
             proposer,
-        }) => execute_propose(deps, env, info.sender, title, description, msgs, proposer),
+            best_effort,
+        }) => execute_propose(
+            deps,
+            env,
+            info.sender,
+            title,
+            description,
+            msgs,
+            proposer,
+            best_effort,
+        ),
         ExecuteMsg::Vote {
             proposal_id,
             vote,
             rationale,
-        } => execute_vote(deps, env, info, proposal_id, vote, rationale),
+        } => execute_vote(deps, env, info, proposal_id, vote, None, rationale),
+        ExecuteMsg::VoteWeighted {
+            proposal_id,
+            weights,
+            rationale,
+        } => {
+            weights.validate()?;
+            execute_vote(
+                deps,
+                env,
+                info,
+                proposal_id,
+                weights.position(),
+                Some(weights),
+                rationale,
+            )
+        }
         ExecuteMsg::UpdateRationale {
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
+        ExecuteMsg::Amend {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        } => execute_amend(deps, env, info, proposal_id, title, description, msgs),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::CloseExpired { limit } => execute_close_expired(deps, env, info, limit),
         ExecuteMsg::UpdateConfig {
             threshold,
             max_voting_period,
@@ -121,6 +184,7 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            veto,
         } => execute_update_config(
             deps,
             info,
@@ -131,6 +195,7 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            veto,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -145,9 +210,16 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::UpdateDelegationModule { delegation_module } => {
+            execute_update_delegation_module(deps, info, delegation_module)
+        }
+        ExecuteMsg::UpdateCloseExpiredReward { reward } => {
+            execute_update_close_expired_reward(deps, env, info, reward)
+        }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_propose(
     deps: DepsMut,
     env: Env,
@@ -156,6 +228,7 @@ pub fn execute_propose(
     description: String,
     msgs: Vec<CosmosMsg<Empty>>,
     proposer: Option<String>,
+    best_effort: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
@@ -213,46 +286,178 @@ pub fn execute_propose(
             status: Status::Open,
             votes: Votes::zero(),
             allow_revoting: config.allow_revoting,
+            veto: config.veto,
+            best_effort,
+            execution_error: None,
+            failed_message_index: None,
+            message_results: vec![],
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
-        proposal.update_status(&env.block);
+        proposal.update_status(&env.block)?;
         proposal
     };
     let id = advance_proposal_id(deps.storage)?;
 
-    // Limit the size of proposals.
-    //
-    // The Juno mainnet has a larger limit for data that can be
-    // uploaded as part of an execute message than it does for data
-    // that can be queried as part of a query. This means that without
-    // this check it is possible to create a proposal that can not be
-    // queried.
-    //
-    // The size selected was determined by uploading versions of this
-    // contract to the Juno mainnet until queries worked within a
-    // reasonable margin of error.
-    //
+    check_proposal_size(&proposal)?;
+
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "propose")
+        .add_attribute("sender", sender)
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("status", proposal.status.to_string()))
+}
+
+/// Limits the size of proposals.
+///
+/// The Juno mainnet has a larger limit for data that can be uploaded
+/// as part of an execute message than it does for data that can be
+/// queried as part of a query. This means that without this check it
+/// is possible to create a proposal that can not be queried.
+///
+/// The size selected was determined by uploading versions of this
+/// contract to the Juno mainnet until queries worked within a
+/// reasonable margin of error.
+fn check_proposal_size(proposal: &SingleChoiceProposal) -> Result<(), ContractError> {
     // `to_vec` is the method used by cosmwasm to convert a struct
     // into it's byte representation in storage.
-    let proposal_size = cosmwasm_std::to_vec(&proposal)?.len() as u64;
+    let proposal_size = cosmwasm_std::to_vec(proposal)?.len() as u64;
     if proposal_size > MAX_PROPOSAL_SIZE {
         return Err(ContractError::ProposalTooLarge {
             size: proposal_size,
             max: MAX_PROPOSAL_SIZE,
         });
     }
+    Ok(())
+}
 
-    PROPOSALS.save(deps.storage, id, &proposal)?;
+/// Creates a submessage that executes the message at index `idx` of
+/// a proposal's messages and replies with the result.
+fn proposal_message_execution(
+    dao: &Addr,
+    proposal_id: u64,
+    idx: u64,
+    msg: CosmosMsg,
+) -> StdResult<SubMsg> {
+    let execute_message = WasmMsg::Execute {
+        contract_addr: dao.to_string(),
+        msg: to_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook { msgs: vec![msg] })?,
+        funds: vec![],
+    };
+    Ok(SubMsg::reply_always(
+        execute_message,
+        mask_proposal_message_execution(proposal_id, idx),
+    ))
+}
 
-    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
+/// Truncates `error` to at most `max` bytes without splitting a
+/// character.
+fn truncate_error(error: &str, max: usize) -> String {
+    let mut end = error.len().min(max);
+    while !error.is_char_boundary(end) {
+        end -= 1;
+    }
+    error[..end].to_string()
+}
+
+/// Records an execution error on a proposal using `record`. The
+/// error is truncated to `MAX_EXECUTION_ERROR_LENGTH` bytes, and
+/// further if needed, so that the proposal stays within
+/// `MAX_PROPOSAL_SIZE` and remains queryable.
+fn record_execution_error(
+    prop: &mut SingleChoiceProposal,
+    error: &str,
+    record: impl Fn(&mut SingleChoiceProposal, String),
+) -> StdResult<()> {
+    let mut max = MAX_EXECUTION_ERROR_LENGTH;
+    loop {
+        record(prop, truncate_error(error, max));
+        if max == 0 || cosmwasm_std::to_vec(prop)?.len() as u64 <= MAX_PROPOSAL_SIZE {
+            return Ok(());
+        }
+        max /= 2;
+    }
+}
+
+pub fn execute_amend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    if info.sender != prop.proposer {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Proposals which have expired or completed may not be amended.
+    prop.update_status(&env.block)?;
+    if prop.status != Status::Open {
+        return Err(ContractError::AmendNotOpen {});
+    }
+    // Voters should not have their votes applied to a proposal
+    // different from the one they voted on.
+    if BALLOTS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+    {
+        return Err(ContractError::AmendAfterVotes {});
+    }
+    // Proposals created through a pre-propose module had their
+    // messages checked by it, for example by an approver, so their
+    // messages may not be replaced without it.
+    if prop.msgs != msgs
+        && matches!(
+            CREATION_POLICY.load(deps.storage)?,
+            ProposalCreationPolicy::Module { .. }
+        )
+    {
+        return Err(ContractError::AmendMsgsWithPreProposeModule {});
+    }
+
+    let revision = REVISIONS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last| last + 1);
+    REVISIONS.save(
+        deps.storage,
+        (proposal_id, revision),
+        &ProposalRevision {
+            revision,
+            title: std::mem::replace(&mut prop.title, title),
+            description: std::mem::replace(&mut prop.description, description),
+            msgs: std::mem::replace(&mut prop.msgs, msgs),
+            amended_at_height: env.block.height,
+        },
+    )?;
+
+    check_proposal_size(&prop)?;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    // The amended proposal is the revision after the one replaced.
+    let hooks = proposal_amended_hooks(PROPOSAL_HOOKS, deps.storage, proposal_id, revision + 1)?;
 
     Ok(Response::default()
         .add_submessages(hooks)
-        .add_attribute("action", "propose")
-        .add_attribute("sender", sender)
-        .add_attribute("proposal_id", id.to_string())
-        .add_attribute("status", proposal.status.to_string()))
+        .add_attribute("action", "amend")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("revision", (revision + 1).to_string()))
 }
 
 pub fn execute_execute(
@@ -282,17 +487,47 @@ pub fn execute_execute(
     // even if it is expired so long as it passed during its voting
     // period.
     let old_status = prop.status;
-    prop.update_status(&env.block);
-    if prop.status != Status::Passed {
-        return Err(ContractError::NotPassed {});
+    prop.update_status(&env.block)?;
+    match prop.status {
+        Status::Passed => (),
+        // Proposals in their veto timelock may only be executed early
+        // by the vetoer, and only if the veto config allows it.
+        Status::VetoTimelock { expiration } => {
+            let may_execute_early = prop
+                .veto
+                .as_ref()
+                .map(|veto| veto.early_execute && info.sender == veto.vetoer)
+                .unwrap_or(false);
+            if !may_execute_early {
+                return Err(ContractError::VetoTimelock { expiration });
+            }
+        }
+        _ => return Err(ContractError::NotPassed {}),
     }
 
     prop.status = Status::Executed;
+    if prop.best_effort {
+        prop.message_results = vec![MessageResult::Pending; prop.msgs.len()];
+    }
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
     let response = {
-        if !prop.msgs.is_empty() {
+        if prop.best_effort {
+            // Execute each message on its own so that failing
+            // messages do not prevent the others from executing. The
+            // result of each message is recorded when its reply is
+            // received.
+            let messages = prop
+                .msgs
+                .into_iter()
+                .enumerate()
+                .map(|(idx, msg)| {
+                    proposal_message_execution(&config.dao, proposal_id, idx as u64, msg)
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            Response::default().add_submessages(messages)
+        } else if !prop.msgs.is_empty() {
             let execute_message = WasmMsg::Execute {
                 contract_addr: config.dao.to_string(),
                 msg: to_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
@@ -313,35 +548,18 @@ pub fn execute_execute(
         }
     };
 
-    let hooks = proposal_status_changed_hooks(
+    let mut hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
         deps.storage,
         proposal_id,
         old_status.to_string(),
         prop.status.to_string(),
     )?;
-
-    // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let hooks = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => hooks,
-        ProposalCreationPolicy::Module { addr } => {
-            let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
-                proposal_id,
-                new_status: prop.status,
-            })?;
-            let mut hooks = hooks;
-            hooks.push(SubMsg::reply_on_error(
-                WasmMsg::Execute {
-                    contract_addr: addr.into_string(),
-                    msg,
-                    funds: vec![],
-                },
-                failed_pre_propose_module_hook_id(),
-            ));
-            hooks
-        }
-    };
+    hooks.extend(pre_propose_completed_hook(
+        deps.storage,
+        proposal_id,
+        prop.status,
+    )?);
 
     Ok(response
         .add_submessages(hooks)
@@ -357,6 +575,7 @@ pub fn execute_vote(
     info: MessageInfo,
     proposal_id: u64,
     vote: Vote,
+    weights: Option<WeightedVote>,
     rationale: Option<String>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -375,31 +594,48 @@ pub fn execute_vote(
         return Err(ContractError::Expired { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
+    let own_power = get_voting_power(
         deps.as_ref(),
         info.sender.clone(),
         &config.dao,
         Some(prop.start_height),
     )?;
+    // Delegates vote with the voting power of their delegators,
+    // except for delegators that have cast their own vote.
+    let delegation_module = DELEGATION_MODULE.may_load(deps.storage)?;
+    let vote_power = match delegation_module {
+        Some(ref delegation_module) => own_power
+            .checked_add(get_delegated_voting_power(
+                deps.as_ref(),
+                delegation_module,
+                &config.dao,
+                &info.sender,
+                prop.start_height,
+                |delegator| Ok(BALLOTS.has(deps.storage, (proposal_id, delegator))),
+            )?)
+            .map_err(StdError::overflow)?,
+        None => own_power,
+    };
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
 
+    let is_first_vote = !BALLOTS.has(deps.storage, (proposal_id, &info.sender));
     BALLOTS.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
-                if current_ballot.vote == vote {
+                if current_ballot.vote == vote && current_ballot.weights == weights {
                     // Don't allow casting the same vote more than
                     // once. This seems liable to be confusing
                     // behavior.
                     Err(ContractError::AlreadyCast {})
                 } else {
                     // Remove the old vote if this is a re-vote.
-                    prop.votes
-                        .remove_vote(current_ballot.vote, current_ballot.power);
+                    remove_ballot(&mut prop.votes, &current_ballot);
                     Ok(Ballot {
                         power: vote_power,
                         vote,
+                        weights: weights.clone(),
                         // Roll over the previous rationale. If
                         // you're changing your vote, you've also
                         // likely changed your thinking.
@@ -413,14 +649,41 @@ pub fn execute_vote(
         None => Ok(Ballot {
             power: vote_power,
             vote,
+            weights: weights.clone(),
             rationale: rationale.clone(),
         }),
     })?;
 
     let old_status = prop.status;
 
-    prop.votes.add_vote(vote, vote_power);
-    prop.update_status(&env.block);
+    match weights {
+        Some(ref weights) => prop.votes.add_weighted_vote(weights, vote_power),
+        None => prop.votes.add_vote(vote, vote_power),
+    }
+
+    // A delegator's first vote overrides their delegate's vote. If
+    // their delegate has already voted, remove the delegator's voting
+    // power from the delegate's ballot.
+    if let Some(delegation_module) = delegation_module.filter(|_| is_first_vote) {
+        if let Some(delegate) = get_delegate(
+            deps.as_ref(),
+            &delegation_module,
+            &info.sender,
+            prop.start_height,
+        )? {
+            if let Some(mut ballot) = BALLOTS.may_load(deps.storage, (proposal_id, &delegate))? {
+                remove_ballot(&mut prop.votes, &ballot);
+                ballot.power = ballot
+                    .power
+                    .checked_sub(own_power)
+                    .map_err(StdError::overflow)?;
+                add_ballot(&mut prop.votes, &ballot);
+                BALLOTS.save(deps.storage, (proposal_id, &delegate), &ballot)?;
+            }
+        }
+    }
+
+    prop.update_status(&env.block)?;
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
@@ -433,12 +696,15 @@ pub fn execute_vote(
         new_status.to_string(),
     )?;
 
+    // Weighted votes are reported with their split, for example
+    // `yes:0.6,no:0.4,abstain:0`.
+    let position = weights.map_or_else(|| vote.to_string(), |weights| weights.to_string());
     let vote_hooks = new_vote_hooks(
         VOTE_HOOKS,
         deps.storage,
         proposal_id,
         info.sender.to_string(),
-        vote.to_string(),
+        position.clone(),
     )?;
 
     Ok(Response::default()
@@ -447,11 +713,27 @@ pub fn execute_vote(
         .add_attribute("action", "vote")
         .add_attribute("sender", info.sender)
         .add_attribute("proposal_id", proposal_id.to_string())
-        .add_attribute("position", vote.to_string())
+        .add_attribute("position", position)
         .add_attribute("rationale", rationale.as_deref().unwrap_or("_none"))
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Adds a ballot's voting power to a proposal's tally.
+fn add_ballot(votes: &mut Votes, ballot: &Ballot) {
+    match ballot.weights {
+        Some(ref weights) => votes.add_weighted_vote(weights, ballot.power),
+        None => votes.add_vote(ballot.vote, ballot.power),
+    }
+}
+
+/// Removes a ballot's voting power from a proposal's tally.
+fn remove_ballot(votes: &mut Votes, ballot: &Ballot) {
+    match ballot.weights {
+        Some(ref weights) => votes.remove_weighted_vote(weights, ballot.power),
+        None => votes.remove_vote(ballot.vote, ballot.power),
+    }
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -492,49 +774,225 @@ pub fn execute_close(
 
     // Update status to ensure that proposals which were open and have
     // expired are moved to "rejected."
-    prop.update_status(&env.block);
+    prop.update_status(&env.block)?;
     if prop.status != Status::Rejected {
         return Err(ContractError::WrongCloseStatus {});
     }
 
+    let hooks = close_proposal(deps.storage, proposal_id, &mut prop)?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "close")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+pub fn execute_close_expired(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let cursor = CLOSE_EXPIRED_CURSOR.may_load(deps.storage)?;
+    // `limit` caps the number of proposals scanned, not closed, so
+    // that the gas used by this method is bounded.
+    let proposal_ids = PROPOSALS
+        .keys(
+            deps.storage,
+            cursor.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<u64>>>()?;
+    let creation_policy = CREATION_POLICY.load(deps.storage)?;
+
+    // Proposals that are no longer open or rejected will never need
+    // to be closed, so the cursor is advanced past them until the
+    // first proposal that is still open.
+    let mut new_cursor = cursor;
+    let mut settled = true;
+    let mut closed = 0;
+    let mut rewarded = 0u128;
+    let mut msgs = vec![];
+    for proposal_id in proposal_ids {
+        let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+        prop.update_status(&env.block)?;
+        match prop.status {
+            Status::Open => settled = false,
+            Status::Rejected => {
+                if forfeits_deposit(deps.as_ref(), &creation_policy, proposal_id) {
+                    rewarded += 1;
+                }
+                msgs.extend(close_proposal(deps.storage, proposal_id, &mut prop)?);
+                closed += 1;
+            }
+            _ => (),
+        }
+        if settled {
+            new_cursor = Some(proposal_id);
+        }
+    }
+    if let Some(cursor) = new_cursor {
+        CLOSE_EXPIRED_CURSOR.save(deps.storage, &cursor)?;
+    }
+
+    let reward = CLOSE_EXPIRED_REWARD.may_load(deps.storage)?;
+    if let Some(reward) = reward.filter(|_| rewarded > 0) {
+        // The reward is paid from this module's balance rather than by
+        // the DAO, so that it is not queued by the DAO's timelock or
+        // limited by this module's permissions. If the balance can not
+        // cover the reward what remains is paid, and the proposals are
+        // closed regardless.
+        let amount = reward
+            .amount
+            .checked_mul(Uint128::new(rewarded))
+            .map_err(StdError::overflow)?;
+        let balance = deps
+            .querier
+            .query_balance(&env.contract.address, &reward.denom)?;
+        let amount = amount.min(balance.amount);
+        if !amount.is_zero() {
+            msgs.push(SubMsg::new(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![Coin {
+                    denom: reward.denom,
+                    amount,
+                }],
+            }));
+        }
+    }
+
+    Ok(Response::default()
+        .add_submessages(msgs)
+        .add_attribute("action", "close_expired")
+        .add_attribute("sender", info.sender)
+        .add_attribute("closed", closed.to_string()))
+}
+
+/// Returns true if the proposal's proposer forfeits a deposit now
+/// that the proposal has been rejected. `CloseExpired` rewards are
+/// only paid for these proposals so that creating proposals to be
+/// closed for the reward costs more than it earns, so long as the
+/// reward is smaller than the deposit.
+fn forfeits_deposit(
+    deps: Deps,
+    creation_policy: &ProposalCreationPolicy,
+    proposal_id: u64,
+) -> bool {
+    let addr = match creation_policy {
+        ProposalCreationPolicy::Anyone {} => return false,
+        ProposalCreationPolicy::Module { addr } => addr,
+    };
+    // A pre-propose module that can not answer this query is
+    // treated as not having taken a deposit.
+    deps.querier
+        .query_wasm_smart::<DepositInfoResponse>(
+            addr,
+            &PreProposeQueryMsg::DepositInfo { proposal_id },
+        )
+        .ok()
+        .and_then(|response| response.deposit_info)
+        .map(|deposit| deposit.refund_policy != DepositRefundPolicy::Always)
+        .unwrap_or(false)
+}
+
+/// The hook informing the pre-propose module, if proposals are
+/// created through one, that a proposal has been completed so that it
+/// may settle the proposal's deposit.
+fn pre_propose_completed_hook(
+    storage: &dyn Storage,
+    proposal_id: u64,
+    new_status: Status,
+) -> StdResult<Option<SubMsg>> {
+    match CREATION_POLICY.load(storage)? {
+        ProposalCreationPolicy::Anyone {} => Ok(None),
+        ProposalCreationPolicy::Module { addr } => Ok(Some(SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: addr.into_string(),
+                msg: to_binary(&PreProposeHookMsg::ProposalCompletedHook {
+                    proposal_id,
+                    new_status,
+                })?,
+                funds: vec![],
+            },
+            failed_pre_propose_module_hook_id(),
+        ))),
+    }
+}
+
+/// Closes a rejected proposal, returning the hooks to fire and the
+/// message settling its deposit with the pre-propose module (if any).
+fn close_proposal(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    prop: &mut SingleChoiceProposal,
+) -> Result<Vec<SubMsg>, ContractError> {
     let old_status = prop.status;
 
     prop.status = Status::Closed;
+    PROPOSALS.save(storage, proposal_id, prop)?;
+
+    let mut hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+    hooks.extend(pre_propose_completed_hook(
+        storage,
+        proposal_id,
+        prop.status,
+    )?);
+    Ok(hooks)
+}
+
+pub fn execute_veto(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    let veto = prop
+        .veto
+        .clone()
+        .ok_or(ContractError::NoVetoConfiguration { id: proposal_id })?;
+    veto.check_is_vetoer(&info.sender)?;
+
+    // Proposals may only be vetoed after they have passed and before
+    // their timelock has expired.
+    let old_status = prop.status;
+    prop.update_status(&env.block)?;
+    if !matches!(prop.status, Status::VetoTimelock { .. }) {
+        return Err(VetoError::NotVetoable {}.into());
+    }
+
+    prop.status = Status::Vetoed;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
-    let hooks = proposal_status_changed_hooks(
+    let mut hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
         deps.storage,
         proposal_id,
         old_status.to_string(),
         prop.status.to_string(),
     )?;
-
-    // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let hooks = match proposal_creation_policy {
-        ProposalCreationPolicy::Anyone {} => hooks,
-        ProposalCreationPolicy::Module { addr } => {
-            let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
-                proposal_id,
-                new_status: prop.status,
-            })?;
-            let mut hooks = hooks;
-            hooks.push(SubMsg::reply_on_error(
-                WasmMsg::Execute {
-                    contract_addr: addr.into_string(),
-                    msg,
-                    funds: vec![],
-                },
-                failed_pre_propose_module_hook_id(),
-            ));
-            hooks
-        }
-    };
+    hooks.extend(pre_propose_completed_hook(
+        deps.storage,
+        proposal_id,
+        prop.status,
+    )?);
 
     Ok(Response::default()
         .add_submessages(hooks)
-        .add_attribute("action", "close")
+        .add_attribute("action", "veto")
         .add_attribute("sender", info.sender)
         .add_attribute("proposal_id", proposal_id.to_string()))
 }
@@ -550,6 +1008,7 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    veto: Option<VetoConfig>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -562,6 +1021,13 @@ pub fn execute_update_config(
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    if let Some(decay) = threshold.quorum_decay() {
+        decay.validate_units(&max_voting_period)?;
+    }
+
+    if let Some(veto) = &veto {
+        veto.validate(&deps.as_ref(), &max_voting_period)?;
+    }
 
     CONFIG.save(
         deps.storage,
@@ -573,6 +1039,7 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            veto,
         },
     )?;
 
@@ -601,6 +1068,87 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_update_delegation_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    delegation_module: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match delegation_module {
+        Some(ref delegation_module) => {
+            let delegation_module = deps.api.addr_validate(delegation_module)?;
+            DELEGATION_MODULE.save(deps.storage, &delegation_module)?;
+        }
+        None => DELEGATION_MODULE.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "update_delegation_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "delegation_module",
+            delegation_module.as_deref().unwrap_or("_none"),
+        ))
+}
+
+pub fn execute_update_close_expired_reward(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    reward: Option<Coin>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Rewards are paid from this module's balance. Once a denom is no
+    // longer paid as a reward, the module's balance of it is returned
+    // to the DAO.
+    let mut msgs = vec![];
+    if let Some(old) = CLOSE_EXPIRED_REWARD.may_load(deps.storage)? {
+        if reward
+            .as_ref()
+            .map_or(true, |reward| reward.denom != old.denom)
+        {
+            let balance = deps
+                .querier
+                .query_balance(&env.contract.address, &old.denom)?;
+            if !balance.amount.is_zero() {
+                msgs.push(BankMsg::Send {
+                    to_address: config.dao.into_string(),
+                    amount: vec![balance],
+                });
+            }
+        }
+    }
+
+    match reward {
+        Some(ref reward) => {
+            if reward.amount.is_zero() {
+                return Err(ContractError::ZeroCloseExpiredReward {});
+            }
+            CLOSE_EXPIRED_REWARD.save(deps.storage, reward)?;
+        }
+        None => CLOSE_EXPIRED_REWARD.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_messages(msgs)
+        .add_attribute("action", "update_close_expired_reward")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "reward",
+            reward
+                .map(|reward| reward.to_string())
+                .unwrap_or_else(|| "_none".to_string()),
+        ))
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -724,6 +1272,16 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => query_list_votes(deps, proposal_id, start_after, limit),
+        QueryMsg::ListRevisions {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_list_revisions(deps, proposal_id, start_after, limit),
+        QueryMsg::EffectiveQuorum {
+            proposal_id,
+            height,
+            time,
+        } => query_effective_quorum(deps, env, proposal_id, height, time),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ReverseProposals {
             start_before,
@@ -732,6 +1290,8 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::DelegationModule {} => to_binary(&DELEGATION_MODULE.may_load(deps.storage)?),
+        QueryMsg::CloseExpiredReward {} => to_binary(&CLOSE_EXPIRED_REWARD.may_load(deps.storage)?),
     }
 }
 
@@ -747,7 +1307,7 @@ pub fn query_dao(deps: Deps) -> StdResult<Binary> {
 
 pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
     let proposal = PROPOSALS.load(deps.storage, id)?;
-    to_binary(&proposal.into_response(&env.block, id))
+    to_binary(&proposal.into_response(&env.block, id)?)
 }
 
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
@@ -769,7 +1329,7 @@ pub fn query_list_proposals(
         .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
         .into_iter()
         .map(|(id, proposal)| proposal.into_response(&env.block, id))
-        .collect();
+        .collect::<StdResult<_>>()?;
 
     to_binary(&ProposalListResponse { proposals: props })
 }
@@ -788,7 +1348,7 @@ pub fn query_reverse_proposals(
         .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
         .into_iter()
         .map(|(id, proposal)| proposal.into_response(&env.block, id))
-        .collect();
+        .collect::<StdResult<_>>()?;
 
     to_binary(&ProposalListResponse { proposals: props })
 }
@@ -808,6 +1368,7 @@ pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Bina
     let vote = ballot.map(|ballot| VoteInfo {
         voter,
         vote: ballot.vote,
+        weights: ballot.weights,
         power: ballot.power,
         rationale: ballot.rationale,
     });
@@ -835,6 +1396,7 @@ pub fn query_list_votes(
             Ok(VoteInfo {
                 voter,
                 vote: ballot.vote,
+                weights: ballot.weights,
                 power: ballot.power,
                 rationale: ballot.rationale,
             })
@@ -844,6 +1406,43 @@ pub fn query_list_votes(
     to_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_revisions(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::exclusive);
+
+    let revisions = REVISIONS
+        .prefix(proposal_id)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(_, revision)| revision))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_binary(&RevisionListResponse { revisions })
+}
+
+pub fn query_effective_quorum(
+    deps: Deps,
+    env: Env,
+    proposal_id: u64,
+    height: Option<u64>,
+    time: Option<Timestamp>,
+) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let mut block = env.block;
+    if let Some(height) = height {
+        block.height = height;
+    }
+    if let Some(time) = time {
+        block.time = time;
+    }
+    to_binary(&proposal.effective_quorum(&block))
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_binary(&dao_interface::voting::InfoResponse { info })
@@ -880,6 +1479,7 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     allow_revoting: current_config.allow_revoting,
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
+                    veto: None,
                 },
             )?;
 
@@ -924,6 +1524,11 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         status: v1_status_to_v2(prop.status),
                         votes: v1_votes_to_v2(prop.votes),
                         allow_revoting: prop.allow_revoting,
+                        veto: None,
+                        best_effort: false,
+                        execution_error: None,
+                        failed_message_index: None,
+                        message_results: vec![],
                     };
 
                     PROPOSALS
@@ -948,16 +1553,46 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
-                Some(mut prop) => {
-                    prop.status = Status::ExecutionFailed;
+            let mut prop = PROPOSALS
+                .may_load(deps.storage, proposal_id)?
+                .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+            prop.status = Status::ExecutionFailed;
+            if let Some(error) = msg.result.into_result().err() {
+                record_execution_error(&mut prop, &error, |prop, error| {
+                    prop.execution_error = Some(error)
+                })?;
+            }
+            PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
-                    Ok(prop)
+            Ok(Response::new().add_attribute("proposal_execution_failed", proposal_id.to_string()))
+        }
+        TaggedReplyId::ProposalMessageExecution(proposal_id, idx) => {
+            let mut prop = PROPOSALS
+                .may_load(deps.storage, proposal_id)?
+                .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+            if prop.message_results.get(idx as usize).is_none() {
+                return Err(ContractError::InvalidReplyID { id: msg.id });
+            }
+            let attribute = match msg.result {
+                SubMsgResult::Ok(_) => {
+                    prop.message_results[idx as usize] = MessageResult::Succeeded;
+                    "proposal_message_executed"
                 }
-                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
-            })?;
+                SubMsgResult::Err(error) => {
+                    // Replies are received in order, so the first
+                    // failure recorded is the first message to fail.
+                    if prop.failed_message_index.is_none() {
+                        prop.failed_message_index = Some(idx);
+                    }
+                    record_execution_error(&mut prop, &error, |prop, error| {
+                        prop.message_results[idx as usize] = MessageResult::Failed { error }
+                    })?;
+                    "proposal_message_failed"
+                }
+            };
+            PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
-            Ok(Response::new().add_attribute("proposal_execution_failed", proposal_id.to_string()))
+            Ok(Response::new().add_attribute(attribute, format!("{proposal_id}:{idx}")))
         }
         TaggedReplyId::FailedProposalHook(idx) => {
             let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
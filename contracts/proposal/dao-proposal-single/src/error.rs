@@ -2,7 +2,7 @@ use std::u64;
 
 use cosmwasm_std::StdError;
 use cw_hooks::HookError;
-use cw_utils::ParseReplyError;
+use cw_utils::{Expiration, ParseReplyError};
 use dao_voting::reply::error::TagError;
 use thiserror::Error;
 
@@ -26,6 +26,9 @@ pub enum ContractError {
     #[error(transparent)]
     VotingError(#[from] dao_voting::error::VotingError),
 
+    #[error(transparent)]
+    VetoError(#[from] dao_voting::veto::VetoError),
+
     #[error("no such proposal ({id})")]
     NoSuchProposal { id: u64 },
 
@@ -50,15 +53,33 @@ pub enum ContractError {
     #[error("proposal is not in 'passed' state")]
     NotPassed {},
 
+    #[error("proposal is in its veto timelock until ({expiration})")]
+    VetoTimelock { expiration: Expiration },
+
+    #[error("no veto is configured for proposal ({id})")]
+    NoVetoConfiguration { id: u64 },
+
     #[error("proposal has already been executed")]
     AlreadyExecuted {},
 
     #[error("proposal is closed")]
     Closed {},
 
+    #[error("only open proposals may be amended")]
+    AmendNotOpen {},
+
+    #[error("proposals may not be amended once votes have been cast")]
+    AmendAfterVotes {},
+
+    #[error("the messages of proposals created through a pre-propose module may not be amended")]
+    AmendMsgsWithPreProposeModule {},
+
     #[error("only rejected proposals may be closed")]
     WrongCloseStatus {},
 
+    #[error("close expired reward must not be zero")]
+    ZeroCloseExpiredReward {},
+
     #[error("the DAO is currently inactive, you cannot create proposals")]
     InactiveDao {},
 
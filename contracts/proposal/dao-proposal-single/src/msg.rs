@@ -1,9 +1,13 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, CosmosMsg, Empty, Timestamp};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
-    voting::Vote,
+    pre_propose::PreProposeInfo,
+    proposal::SingleChoiceProposeMsg,
+    threshold::Threshold,
+    veto::VetoConfig,
+    voting::{Vote, WeightedVote},
 };
 
 #[cw_serde]
@@ -38,6 +42,10 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// Optional veto configuration. If set, passed proposals enter a
+    /// timelock during which the vetoer may veto them before they
+    /// can be executed.
+    pub veto: Option<VetoConfig>,
 }
 
 #[cw_serde]
@@ -56,18 +64,56 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Votes on a proposal, splitting the sender's voting power
+    /// across positions. Useful for voters that represent many
+    /// others, for example a custodian, who may not all agree.
+    VoteWeighted {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The fraction of the sender's voting power behind each
+        /// position. Must sum to one.
+        weights: WeightedVote,
+        /// An optional rationale for why this vote was cast. This can
+        /// be updated, set, or removed later by the address casting
+        /// the vote.
+        rationale: Option<String>,
+    },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
     UpdateRationale {
         proposal_id: u64,
         rationale: Option<String>,
     },
+    /// Replaces the title, description, and messages of an open
+    /// proposal. Only the proposal's proposer may amend it, and only
+    /// before any votes have been cast. The replaced version is
+    /// recorded in the proposal's revision history.
+    ///
+    /// If proposals are created through a pre-propose module only the
+    /// title and description may be amended, as the module's checks
+    /// would otherwise be bypassed.
+    Amend {
+        /// The ID of the proposal to amend.
+        proposal_id: u64,
+        /// The new title of the proposal.
+        title: String,
+        /// The new description of the proposal.
+        description: String,
+        /// The new messages to be executed if the proposal passes.
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
         /// The ID of the proposal to execute.
         proposal_id: u64,
     },
+    /// Vetoes a proposal that is in its veto timelock. Only callable
+    /// by the vetoer configured for the proposal.
+    Veto {
+        /// The ID of the proposal to veto.
+        proposal_id: u64,
+    },
     /// Closes a proposal that has failed (either not passed or timed
     /// out). If applicable this will cause the proposal deposit
     /// associated wth said proposal to be returned.
@@ -75,6 +121,17 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to close.
         proposal_id: u64,
     },
+    /// Closes proposals that have been rejected or have expired
+    /// without passing, returning their deposits as `Close` would.
+    /// Anyone may call this method. If the DAO has set a reward with
+    /// `UpdateCloseExpiredReward`, the caller is paid the reward for
+    /// each proposal closed whose proposer forfeits a deposit.
+    CloseExpired {
+        /// The maximum number of proposals to check, starting after
+        /// the last proposal that no longer needs closing. If no
+        /// limit is specified a max of 30 are checked.
+        limit: Option<u64>,
+    },
     /// Updates the governance module's config.
     UpdateConfig {
         /// The new proposal passing threshold. This will only apply
@@ -110,6 +167,11 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// Optional veto configuration. If set, passed proposals
+        /// enter a timelock during which the vetoer may veto them
+        /// before they can be executed. This will only apply to
+        /// proposals created after the config update.
+        veto: Option<VetoConfig>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
@@ -129,6 +191,21 @@ pub enum ExecuteMsg {
     AddVoteHook { address: String },
     /// Removed a consumer of vote hooks.
     RemoveVoteHook { address: String },
+    /// Sets or removes the vote delegation contract used to count
+    /// delegated voting power. Delegates vote with the voting power
+    /// delegated to them, and delegators that vote override their
+    /// delegate's vote with their own voting power. Only the DAO may
+    /// call this method.
+    UpdateDelegationModule { delegation_module: Option<String> },
+    /// Sets or removes the reward paid to callers of `CloseExpired`
+    /// for each proposal they close whose proposer forfeits a
+    /// deposit. Rewards are paid from this module's balance, which
+    /// the DAO funds by sending it tokens. The module's balance of the
+    /// previous reward denom is returned to the DAO if the reward is
+    /// removed or its denom changes. The reward should be smaller
+    /// than the deposit so that creating proposals to be closed is
+    /// not profitable. Only the DAO may call this method.
+    UpdateCloseExpiredReward { reward: Option<Coin> },
 }
 
 #[proposal_module_query]
@@ -183,6 +260,33 @@ pub enum QueryMsg {
         /// query. If no limit is specified a max of 30 are returned.
         limit: Option<u64>,
     },
+    /// Lists the previous versions of a proposal that have been
+    /// replaced by amendments, in the order they were replaced. The
+    /// current version is returned by the `Proposal` query.
+    #[returns(crate::query::RevisionListResponse)]
+    ListRevisions {
+        /// The proposal to list the revisions of.
+        proposal_id: u64,
+        /// The revision to start listing revisions after.
+        start_after: Option<u64>,
+        /// The maximum number of revisions to return in response to
+        /// this query. If no limit is specified a max of 30 are
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Gets the quorum a proposal must meet, accounting for any
+    /// quorum decay. Returns `None` if the proposal's threshold has no
+    /// quorum.
+    #[returns(Option<::dao_voting::threshold::PercentageThreshold>)]
+    EffectiveQuorum {
+        proposal_id: u64,
+        /// The block height to get the quorum at. Defaults to the
+        /// current block height.
+        height: Option<u64>,
+        /// The block time to get the quorum at. Defaults to the
+        /// current block time.
+        time: Option<Timestamp>,
+    },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
@@ -195,6 +299,14 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the vote delegation contract used by this module, if
+    /// any.
+    #[returns(Option<::cosmwasm_std::Addr>)]
+    DelegationModule {},
+    /// Gets the reward paid to callers of `CloseExpired` for each
+    /// proposal they close, if any.
+    #[returns(Option<::cosmwasm_std::Coin>)]
+    CloseExpiredReward {},
 }
 
 #[cw_serde]
@@ -5,6 +5,7 @@ use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty, StdResult, Storag
 use cw_utils::Expiration;
 use dao_voting::status::Status;
 use dao_voting::threshold::{PercentageThreshold, Threshold};
+use dao_voting::veto::VetoConfig;
 use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, Votes};
 
 #[cw_serde]
@@ -34,6 +35,43 @@ pub struct SingleChoiceProposal {
     pub status: Status,
     pub votes: Votes,
     pub allow_revoting: bool,
+    /// Optional veto configuration. If set, this proposal enters a
+    /// veto timelock once it passes.
+    pub veto: Option<VetoConfig>,
+    /// If true, each of this proposal's messages is executed on its
+    /// own and messages that fail do not prevent the others from
+    /// executing.
+    #[serde(default)]
+    pub best_effort: bool,
+    /// The error returned when this proposal's execution failed, if
+    /// it has. Only recorded for proposals that are not best effort
+    /// and are closed on execution failure. Errors are truncated so
+    /// that the proposal does not exceed the maximum proposal size.
+    #[serde(default)]
+    pub execution_error: Option<String>,
+    /// The index of the first of this proposal's messages that
+    /// failed to execute, if any. Only recorded for best effort
+    /// proposals, as the messages of other proposals are executed
+    /// together.
+    #[serde(default)]
+    pub failed_message_index: Option<u64>,
+    /// The result of executing each of this proposal's messages, in
+    /// order. Only recorded for best effort proposals, once they have
+    /// been executed.
+    #[serde(default)]
+    pub message_results: Vec<MessageResult>,
+}
+
+/// The result of executing one of a best effort proposal's messages.
+#[cw_serde]
+pub enum MessageResult {
+    /// The message has been dispatched but its result has not yet
+    /// been received.
+    Pending,
+    /// The message executed successfully.
+    Succeeded,
+    /// The message failed to execute.
+    Failed { error: String },
 }
 
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
@@ -54,28 +92,55 @@ impl SingleChoiceProposal {
     /// a vote has occurred, the status we read from the proposal status
     /// may be out of date. This method recomputes the status so that
     /// queries get accurate information.
-    pub fn into_response(mut self, block: &BlockInfo, id: u64) -> ProposalResponse {
-        self.update_status(block);
-        ProposalResponse { id, proposal: self }
+    pub fn into_response(mut self, block: &BlockInfo, id: u64) -> StdResult<ProposalResponse> {
+        self.update_status(block)?;
+        Ok(ProposalResponse { id, proposal: self })
     }
 
     /// Gets the current status of the proposal.
-    pub fn current_status(&self, block: &BlockInfo) -> Status {
+    pub fn current_status(&self, block: &BlockInfo) -> StdResult<Status> {
         if self.status == Status::Open && self.is_passed(block) {
-            Status::Passed
+            match &self.veto {
+                // Passed proposals with a veto configured may be
+                // vetoed until the timelock after the voting period
+                // has ended.
+                Some(veto) => {
+                    let expiration = (self.expiration + veto.timelock_duration)?;
+                    if expiration.is_expired(block) {
+                        Ok(Status::Passed)
+                    } else {
+                        Ok(Status::VetoTimelock { expiration })
+                    }
+                }
+                None => Ok(Status::Passed),
+            }
         } else if self.status == Status::Open
             && (self.expiration.is_expired(block) || self.is_rejected(block))
         {
-            Status::Rejected
+            Ok(Status::Rejected)
+        } else if let Status::VetoTimelock { expiration } = self.status {
+            if expiration.is_expired(block) {
+                Ok(Status::Passed)
+            } else {
+                Ok(self.status)
+            }
         } else {
-            self.status
+            Ok(self.status)
         }
     }
 
     /// Sets a proposals status to its current status.
-    pub fn update_status(&mut self, block: &BlockInfo) {
-        let new_status = self.current_status(block);
-        self.status = new_status
+    pub fn update_status(&mut self, block: &BlockInfo) -> StdResult<()> {
+        let new_status = self.current_status(block)?;
+        self.status = new_status;
+        Ok(())
+    }
+
+    /// The quorum this proposal must meet at `block`, accounting for
+    /// any quorum decay. `None` if the proposal's threshold has no
+    /// quorum.
+    pub fn effective_quorum(&self, block: &BlockInfo) -> Option<PercentageThreshold> {
+        self.threshold.quorum_at(&self.expiration, block)
     }
 
     /// Returns true iff this proposal is sure to pass (even before
@@ -103,7 +168,15 @@ impl SingleChoiceProposal {
                 let options = self.total_power - self.votes.abstain;
                 does_vote_count_pass(self.votes.yes, options, percentage)
             }
-            Threshold::ThresholdQuorum { threshold, quorum } => {
+            Threshold::ThresholdQuorum {
+                threshold,
+                quorum,
+                quorum_decay,
+            } => {
+                let quorum = match quorum_decay {
+                    Some(decay) => decay.quorum_at(quorum, &self.expiration, block),
+                    None => quorum,
+                };
                 if !does_vote_count_pass(self.votes.total(), self.total_power, quorum) {
                     return false;
                 }
@@ -121,6 +194,23 @@ impl SingleChoiceProposal {
                 }
             }
             Threshold::AbsoluteCount { threshold } => self.votes.yes >= threshold,
+            Threshold::Optimistic { objection } => {
+                if self.expiration.is_expired(block) {
+                    // Optimistic proposals pass at expiration unless
+                    // enough no votes have been cast to object.
+                    !does_vote_count_pass(self.votes.no, self.total_power, objection)
+                } else {
+                    // Before expiration the proposal has passed only
+                    // if the outstanding votes voting no would not be
+                    // enough to object.
+                    let outstanding_votes = self.total_power - self.votes.total();
+                    !does_vote_count_pass(
+                        self.votes.no + outstanding_votes,
+                        self.total_power,
+                        objection,
+                    )
+                }
+            }
         }
     }
 
@@ -161,7 +251,15 @@ impl SingleChoiceProposal {
 
                 does_vote_count_fail(self.votes.no, options, percentage_needed)
             }
-            Threshold::ThresholdQuorum { threshold, quorum } => {
+            Threshold::ThresholdQuorum {
+                threshold,
+                quorum,
+                quorum_decay,
+            } => {
+                let quorum = match quorum_decay {
+                    Some(decay) => decay.quorum_at(quorum, &self.expiration, block),
+                    None => quorum,
+                };
                 match (
                     does_vote_count_pass(self.votes.total(), self.total_power, quorum),
                     self.expiration.is_expired(block),
@@ -234,6 +332,12 @@ impl SingleChoiceProposal {
                 let outstanding_votes = self.total_power - self.votes.total();
                 self.votes.yes + outstanding_votes < threshold
             }
+            Threshold::Optimistic { objection } => {
+                // No votes only ever increase without re-voting, so
+                // once they reach the objection threshold this is
+                // rejected.
+                does_vote_count_pass(self.votes.no, self.total_power, objection)
+            }
         }
     }
 }
@@ -245,6 +349,8 @@ mod test {
         testing::{mock_dependencies, mock_env},
         Decimal,
     };
+    use cw_utils::Duration;
+    use dao_voting::threshold::QuorumDecay;
 
     fn setup_prop(
         threshold: Threshold,
@@ -277,6 +383,11 @@ mod test {
             threshold,
             total_power,
             votes,
+            veto: None,
+            best_effort: false,
+            execution_error: None,
+            failed_message_index: None,
+            message_results: vec![],
         };
         (prop, block)
     }
@@ -623,6 +734,233 @@ mod test {
         ));
     }
 
+    /// Tests that optimistic proposals pass at expiration unless no
+    /// votes reach the objection threshold.
+    #[test]
+    fn test_optimistic_threshold() {
+        let threshold = Threshold::Optimistic {
+            objection: PercentageThreshold::Percent(Decimal::percent(20)),
+        };
+
+        // No votes cast. Open until expiration, then passes.
+        assert!(!check_is_passed(
+            threshold.clone(),
+            Votes::zero(),
+            Uint128::new(100),
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold.clone(),
+            Votes::zero(),
+            Uint128::new(100),
+            false,
+            true,
+            false
+        ));
+        assert!(check_is_passed(
+            threshold.clone(),
+            Votes::zero(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+
+        // Objections below the threshold do not prevent passing.
+        let some_objection = Votes {
+            yes: Uint128::zero(),
+            no: Uint128::new(19),
+            abstain: Uint128::new(5),
+        };
+        assert!(check_is_passed(
+            threshold.clone(),
+            some_objection.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold.clone(),
+            some_objection,
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+
+        // Reaching the objection threshold rejects early.
+        let objected = Votes {
+            yes: Uint128::new(70),
+            no: Uint128::new(20),
+            abstain: Uint128::zero(),
+        };
+        assert!(check_is_rejected(
+            threshold.clone(),
+            objected.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_passed(
+            threshold.clone(),
+            objected,
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+
+        // Once the outstanding votes can no longer object the
+        // proposal passes early, but not before the min voting period.
+        let unobjectable = Votes {
+            yes: Uint128::new(81),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+        };
+        assert!(check_is_passed(
+            threshold.clone(),
+            unobjectable.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_passed(
+            threshold,
+            unobjectable,
+            Uint128::new(100),
+            false,
+            false,
+            false
+        ));
+    }
+
+    /// Tests that revoting works as expected with an optimistic
+    /// threshold.
+    #[test]
+    fn test_optimistic_threshold_revoting() {
+        let threshold = Threshold::Optimistic {
+            objection: PercentageThreshold::Majority {},
+        };
+        let objected = Votes {
+            yes: Uint128::zero(),
+            no: Uint128::new(51),
+            abstain: Uint128::zero(),
+        };
+
+        assert!(!check_is_rejected(
+            threshold.clone(),
+            objected.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            true
+        ));
+        assert!(check_is_rejected(
+            threshold.clone(),
+            objected,
+            Uint128::new(100),
+            true,
+            true,
+            true
+        ));
+
+        let unobjectable = Votes {
+            yes: Uint128::new(50),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+        };
+        assert!(!check_is_passed(
+            threshold.clone(),
+            unobjectable.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            true
+        ));
+        assert!(check_is_passed(
+            threshold,
+            unobjectable,
+            Uint128::new(100),
+            true,
+            true,
+            true
+        ));
+    }
+
+    #[test]
+    fn test_quorum_decay() {
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+            quorum_decay: Some(QuorumDecay {
+                floor: Decimal::percent(10),
+                period: Duration::Height(200),
+                steps: None,
+            }),
+        };
+        let votes = Votes {
+            yes: Uint128::new(15),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+        };
+
+        // Halfway through the decay period the quorum is 20%, which
+        // has not been met.
+        let (prop, block) = setup_prop(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            false,
+            true,
+            false,
+        );
+        assert_eq!(
+            prop.effective_quorum(&block),
+            Some(PercentageThreshold::Percent(Decimal::percent(20)))
+        );
+        assert!(!prop.is_passed(&block));
+        assert!(!prop.is_rejected(&block));
+
+        // At expiration the quorum has decayed to its floor, so the
+        // proposal passes.
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold,
+            votes.clone(),
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+
+        // Without decay the proposal does not reach quorum.
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+            quorum_decay: None,
+        };
+        assert!(check_is_rejected(
+            threshold,
+            votes,
+            Uint128::new(100),
+            true,
+            true,
+            false
+        ));
+    }
+
     #[test]
     fn test_tricky_pass() {
         let threshold = Threshold::AbsolutePercentage {
@@ -775,6 +1113,7 @@ mod test {
         let quorum = Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(50)),
             quorum: PercentageThreshold::Percent(Decimal::percent(40)),
+            quorum_decay: None,
         };
         // all non-yes votes are counted for quorum
         let passing = Votes {
@@ -887,6 +1226,7 @@ mod test {
         let quorum = Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Majority {},
             quorum: PercentageThreshold::Percent(Decimal::percent(40)),
+            quorum_decay: None,
         };
         // all non-yes votes are counted for quorum
         let rejecting = Votes {
@@ -1017,6 +1357,7 @@ mod test {
         let quorum = Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(60)),
             quorum: PercentageThreshold::Percent(Decimal::percent(80)),
+            quorum_decay: None,
         };
 
         // Try 9 yes, 1 no (out of 15) -> 90% voter threshold, 60%
@@ -1,7 +1,7 @@
-use crate::proposal::SingleChoiceProposal;
+use crate::{proposal::SingleChoiceProposal, state::ProposalRevision};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
-use dao_voting::voting::Vote;
+use dao_voting::voting::{Vote, WeightedVote};
 
 /// Information about a proposal returned by proposal queries.
 #[cw_serde]
@@ -16,8 +16,11 @@ pub struct ProposalResponse {
 pub struct VoteInfo {
     /// The address that voted.
     pub voter: Addr,
-    /// Position on the vote.
+    /// Position on the vote. For weighted votes, the position with
+    /// the largest weight.
     pub vote: Vote,
+    /// If set, the fraction of `power` behind each position.
+    pub weights: Option<WeightedVote>,
     /// The voting power behind the vote.
     pub power: Uint128,
     /// Address-specified rationale for the vote.
@@ -43,3 +46,9 @@ pub struct VoteListResponse {
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// Information about the previous versions of a proposal.
+#[cw_serde]
+pub struct RevisionListResponse {
+    pub revisions: Vec<ProposalRevision>,
+}
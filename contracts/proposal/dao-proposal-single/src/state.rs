@@ -1,9 +1,14 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Empty, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
-use dao_voting::{pre_propose::ProposalCreationPolicy, threshold::Threshold, voting::Vote};
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy,
+    threshold::Threshold,
+    veto::VetoConfig,
+    voting::{Vote, WeightedVote},
+};
 
 use crate::proposal::SingleChoiceProposal;
 
@@ -12,8 +17,13 @@ use crate::proposal::SingleChoiceProposal;
 pub struct Ballot {
     /// The amount of voting power behind the vote.
     pub power: Uint128,
-    /// The position.
+    /// The position. For weighted votes, the position with the
+    /// largest weight.
     pub vote: Vote,
+    /// If set, the vote is weighted and the ballot's power is split
+    /// across positions according to these weights.
+    #[serde(default)]
+    pub weights: Option<WeightedVote>,
 
     /// An optional rationale for why this vote was cast. If the key
     /// is missing (i.e. the ballot was cast in a v1 proposal module),
@@ -21,6 +31,22 @@ pub struct Ballot {
     #[serde(default)]
     pub rationale: Option<String>,
 }
+
+/// A previous version of a proposal, recorded when the proposal is
+/// amended.
+#[cw_serde]
+pub struct ProposalRevision {
+    /// The revision number. The version the proposal was created
+    /// with is revision zero, and each amendment creates the next
+    /// revision.
+    pub revision: u64,
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The block height at which this version was replaced.
+    pub amended_at_height: u64,
+}
+
 /// The governance module's configuration.
 #[cw_serde]
 pub struct Config {
@@ -55,6 +81,10 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// Optional veto configuration. If set, passed proposals enter a
+    /// timelock during which the vetoer may veto them before they
+    /// can be executed.
+    pub veto: Option<VetoConfig>,
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -64,6 +94,18 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// Previous versions of amended proposals, keyed by proposal ID and
+/// revision number.
+pub const REVISIONS: Map<(u64, u64), ProposalRevision> = Map::new("revisions");
+/// The vote delegation contract used to count delegated voting
+/// power, if any.
+pub const DELEGATION_MODULE: Item<Addr> = Item::new("delegation_module");
+/// The reward paid from this module's balance to callers of
+/// `CloseExpired` for each proposal they close, if any.
+pub const CLOSE_EXPIRED_REWARD: Item<Coin> = Item::new("close_expired_reward");
+/// All proposals with an ID less than or equal to this will never
+/// need to be closed by `CloseExpired`.
+pub const CLOSE_EXPIRED_CURSOR: Item<u64> = Item::new("close_expired_cursor");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -177,6 +177,7 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        veto: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -273,6 +274,7 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        veto: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -117,3 +117,12 @@ pub(crate) fn cw4_voting_contract() -> Box<dyn Contract<Empty>> {
     .with_reply(dao_voting_cw4::contract::reply);
     Box::new(contract)
 }
+
+pub(crate) fn vote_delegation_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_vote_delegation::contract::execute,
+        dao_vote_delegation::contract::instantiate,
+        dao_vote_delegation::contract::query,
+    );
+    Box::new(contract)
+}
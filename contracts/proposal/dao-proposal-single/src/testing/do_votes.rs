@@ -127,6 +127,7 @@ where
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        veto: None,
         pre_propose_info,
     };
 
@@ -193,6 +194,7 @@ where
                 title: "A simple text proposal".to_string(),
                 description: "This is a simple text proposal".to_string(),
                 msgs: vec![],
+                best_effort: false,
             },
         },
         &funds,
@@ -235,6 +237,7 @@ where
                 let expected = VoteResponse {
                     vote: Some(VoteInfo {
                         rationale: None,
+                        weights: None,
                         voter: Addr::unchecked(&voter),
                         vote: position,
                         power: match deposit_config.deposit_info {
@@ -362,6 +365,14 @@ fn test_pass_threshold_exactly_quorum() {
     dao_testing::test_pass_exactly_quorum(do_votes_native_staked_balances);
 }
 
+#[test]
+fn test_optimistic_votes() {
+    dao_testing::test_optimistic_votes(do_votes_cw4_weights);
+    dao_testing::test_optimistic_votes(do_votes_staked_balances);
+    dao_testing::test_optimistic_votes(do_votes_nft_balances);
+    dao_testing::test_optimistic_votes(do_votes_native_staked_balances);
+}
+
 /// Generate some random voting selections and make sure they behave
 /// as expected. We split this test up as these take a while and cargo
 /// can parallize tests.
@@ -78,6 +78,7 @@ pub(crate) fn make_proposal(
                     description: "description".to_string(),
                     msgs: msgs.clone(),
                     proposer: None,
+                    best_effort: false,
                 }),
                 &[],
             )
@@ -91,6 +92,7 @@ pub(crate) fn make_proposal(
                         title: "title".to_string(),
                         description: "description".to_string(),
                         msgs: msgs.clone(),
+                        best_effort: false,
                     },
                 },
                 &funds,
@@ -156,6 +158,50 @@ pub(crate) fn vote_on_proposal_should_fail(
     .unwrap()
 }
 
+pub(crate) fn amend_proposal(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    proposal_id: u64,
+    title: &str,
+    msgs: Vec<CosmosMsg>,
+) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Amend {
+            proposal_id,
+            title: title.to_string(),
+            description: "description".to_string(),
+            msgs,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+pub(crate) fn amend_proposal_should_fail(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    proposal_id: u64,
+) -> ContractError {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Amend {
+            proposal_id,
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+        },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
 pub(crate) fn execute_proposal_should_fail(
     app: &mut App,
     proposal_single: &Addr,
@@ -260,6 +306,21 @@ pub(crate) fn close_proposal(
     .unwrap();
 }
 
+pub(crate) fn close_expired_proposals(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    limit: Option<u64>,
+) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::CloseExpired { limit },
+        &[],
+    )
+    .unwrap();
+}
+
 pub(crate) fn mint_natives(app: &mut App, receiver: &str, amount: Vec<Coin>) {
     app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
         to_address: receiver.to_string(),
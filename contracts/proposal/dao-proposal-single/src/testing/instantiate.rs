@@ -51,6 +51,7 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
         threshold: ThresholdQuorum {
             quorum: PercentageThreshold::Percent(Decimal::percent(15)),
             threshold: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         max_voting_period: Duration::Time(604800), // One week.
         min_voting_period: None,
@@ -66,6 +67,7 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
             false,
         ),
         close_proposal_on_execution_failure: true,
+        veto: None,
     }
 }
 
@@ -77,6 +79,7 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         threshold: ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         max_voting_period: Duration::Time(604800), // One week.
         min_voting_period: None,
@@ -84,6 +87,7 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        veto: None,
     }
 }
 
@@ -1,14 +1,17 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
 use cw_multi_test::App;
 use dao_interface::state::{ProposalModule, ProposalModuleStatus};
 
 use cw_hooks::HooksResponse;
 use dao_pre_propose_single as cppbps;
-use dao_voting::pre_propose::ProposalCreationPolicy;
+use dao_voting::{pre_propose::ProposalCreationPolicy, threshold::PercentageThreshold};
 
 use crate::{
     msg::QueryMsg,
-    query::{ProposalListResponse, ProposalResponse, VoteListResponse, VoteResponse},
+    query::{
+        ProposalListResponse, ProposalResponse, RevisionListResponse, VoteListResponse,
+        VoteResponse,
+    },
     state::Config,
 };
 
@@ -72,6 +75,49 @@ pub(crate) fn query_list_votes(
         .unwrap()
 }
 
+pub(crate) fn query_list_revisions(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> RevisionListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ListRevisions {
+                proposal_id,
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_effective_quorum(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+    time: Option<Timestamp>,
+) -> Option<PercentageThreshold> {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::EffectiveQuorum {
+                proposal_id,
+                height: None,
+                time,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_close_expired_reward(app: &App, proposal_single: &Addr) -> Option<Coin> {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::CloseExpiredReward {})
+        .unwrap()
+}
+
 pub(crate) fn query_vote(
     app: &App,
     proposal_module: &Addr,
@@ -1,8 +1,8 @@
 use cosmwasm_std::{
     coins,
     testing::{mock_dependencies, mock_env},
-    to_binary, Addr, Attribute, BankMsg, Binary, ContractInfoResponse, CosmosMsg, Decimal, Empty,
-    Reply, StdError, SubMsgResult, Uint128, WasmMsg, WasmQuery,
+    to_binary, Addr, Attribute, BankMsg, Binary, Coin, ContractInfoResponse, CosmosMsg, Decimal,
+    Empty, Reply, StdError, SubMsgResult, Uint128, WasmMsg, WasmQuery,
 };
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
@@ -17,6 +17,7 @@ use dao_interface::{
 use dao_testing::{ShouldExecute, TestSingleChoiceVote};
 use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
+    error::VotingError,
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     proposal::{SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE},
     reply::{
@@ -24,30 +25,32 @@ use dao_voting::{
         mask_proposal_hook_index, mask_vote_hook_index,
     },
     status::Status,
-    threshold::{PercentageThreshold, Threshold},
-    voting::{Vote, Votes},
+    threshold::{PercentageThreshold, QuorumDecay, Threshold, ThresholdError},
+    veto::{VetoConfig, VetoError},
+    voting::{Vote, Votes, WeightedVote},
 };
 use dao_voting_cw20_staked::msg::ActiveThreshold;
 
 use crate::{
-    contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
+    contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION, MAX_EXECUTION_ERROR_LENGTH},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
-    proposal::SingleChoiceProposal,
+    proposal::{MessageResult, SingleChoiceProposal},
     query::{ProposalResponse, VoteInfo},
-    state::Config,
+    state::{Config, ProposalRevision},
     testing::{
         contracts::{
             cw20_base_contract, cw20_stake_contract, cw20_staked_balances_voting_contract,
             cw_core_contract, pre_propose_single_contract, proposal_single_contract,
-            v1_proposal_single_contract,
+            v1_proposal_single_contract, vote_delegation_contract,
         },
         execute::{
             add_proposal_hook, add_proposal_hook_should_fail, add_vote_hook,
-            add_vote_hook_should_fail, close_proposal, close_proposal_should_fail,
-            execute_proposal, execute_proposal_should_fail, instantiate_cw20_base_default,
-            make_proposal, mint_cw20s, mint_natives, remove_proposal_hook,
-            remove_proposal_hook_should_fail, remove_vote_hook, remove_vote_hook_should_fail,
-            update_rationale, vote_on_proposal, vote_on_proposal_should_fail,
+            add_vote_hook_should_fail, amend_proposal, amend_proposal_should_fail,
+            close_expired_proposals, close_proposal, close_proposal_should_fail, execute_proposal,
+            execute_proposal_should_fail, instantiate_cw20_base_default, make_proposal, mint_cw20s,
+            mint_natives, remove_proposal_hook, remove_proposal_hook_should_fail, remove_vote_hook,
+            remove_vote_hook_should_fail, update_rationale, vote_on_proposal,
+            vote_on_proposal_should_fail,
         },
         instantiate::{
             get_default_non_token_dao_proposal_module_instantiate,
@@ -56,9 +59,10 @@ use crate::{
             instantiate_with_staking_active_threshold,
         },
         queries::{
-            query_balance_cw20, query_balance_native, query_creation_policy, query_dao_token,
-            query_deposit_config_and_pre_propose_module, query_list_proposals,
-            query_list_proposals_reverse, query_list_votes, query_pre_proposal_single_config,
+            query_balance_cw20, query_balance_native, query_close_expired_reward,
+            query_creation_policy, query_dao_token, query_deposit_config_and_pre_propose_module,
+            query_list_proposals, query_list_proposals_reverse, query_list_revisions,
+            query_list_votes, query_pre_proposal_single_config,
             query_pre_proposal_single_deposit_info, query_proposal, query_proposal_config,
             query_proposal_hooks, query_single_proposal_module, query_vote_hooks,
             query_voting_module,
@@ -70,7 +74,7 @@ use crate::{
 use super::{
     do_votes::do_votes_staked_balances,
     execute::vote_on_proposal_with_rationale,
-    queries::{query_next_proposal_id, query_vote},
+    queries::{query_effective_quorum, query_next_proposal_id, query_vote},
     CREATOR_ADDR,
 };
 
@@ -126,12 +130,18 @@ fn test_simple_propose_staked_balances() {
         threshold: Threshold::ThresholdQuorum {
             quorum: PercentageThreshold::Percent(Decimal::percent(15)),
             threshold: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         allow_revoting: false,
         total_power: Uint128::new(100_000_000),
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        veto: None,
+        best_effort: false,
+        execution_error: None,
+        failed_message_index: None,
+        message_results: vec![],
     };
 
     assert_eq!(created.proposal, expected);
@@ -175,12 +185,18 @@ fn test_simple_proposal_cw4_voting() {
         threshold: Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         allow_revoting: false,
         total_power: Uint128::new(1),
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        veto: None,
+        best_effort: false,
+        execution_error: None,
+        failed_message_index: None,
+        message_results: vec![],
     };
 
     assert_eq!(created.proposal, expected);
@@ -279,12 +295,18 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         threshold: Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         allow_revoting: false,
         total_power: Uint128::new(1),
         msgs: vec![],
         status: Status::Open,
         votes: Votes::zero(),
+        veto: None,
+        best_effort: false,
+        execution_error: None,
+        failed_message_index: None,
+        message_results: vec![],
     };
 
     assert_eq!(created.proposal, expected);
@@ -589,6 +611,7 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                veto: None,
             })
             .unwrap(),
             funds: vec![],
@@ -617,6 +640,7 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            veto: None,
         }
     );
 
@@ -635,6 +659,7 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                veto: None,
             },
             &[],
         )
@@ -710,6 +735,7 @@ fn test_anyone_may_propose_and_proposal_listing() {
                 threshold: Threshold::ThresholdQuorum {
                     quorum: PercentageThreshold::Percent(Decimal::percent(15)),
                     threshold: PercentageThreshold::Majority {},
+                    quorum_decay: None,
                 },
                 allow_revoting: false,
                 total_power: Uint128::new(100_000_000),
@@ -720,6 +746,11 @@ fn test_anyone_may_propose_and_proposal_listing() {
                     no: Uint128::zero(),
                     abstain: Uint128::zero()
                 },
+                veto: None,
+                best_effort: false,
+                execution_error: None,
+                failed_message_index: None,
+                message_results: vec![],
             }
         }
     )
@@ -880,6 +911,7 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                best_effort: false,
             }),
             &[],
         )
@@ -918,6 +950,7 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                best_effort: false,
             }),
             &[],
         )
@@ -961,6 +994,7 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                best_effort: false,
             }),
             &[],
         )
@@ -1000,6 +1034,7 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                best_effort: false,
             }),
             &[],
         )
@@ -1189,6 +1224,7 @@ fn test_allow_revoting_config_changes() {
             threshold: Threshold::ThresholdQuorum {
                 quorum: PercentageThreshold::Percent(Decimal::percent(15)),
                 threshold: PercentageThreshold::Majority {},
+                quorum_decay: None,
             },
             max_voting_period: Duration::Height(10),
             min_voting_period: None,
@@ -1197,6 +1233,7 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            veto: None,
         },
         &[],
     )
@@ -1492,6 +1529,7 @@ fn test_proposal_count_initialized_to_zero() {
             threshold: Threshold::ThresholdQuorum {
                 threshold: PercentageThreshold::Majority {},
                 quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+                quorum_decay: None,
             },
             max_voting_period: Duration::Height(10),
             min_voting_period: None,
@@ -1499,6 +1537,7 @@ fn test_proposal_count_initialized_to_zero() {
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            veto: None,
         },
         Some(vec![
             Cw20Coin {
@@ -1726,6 +1765,7 @@ fn test_migrate_from_v1() {
     // proposal.
     let migrate_msg = MigrateMsg::FromV1 {
         close_proposal_on_execution_failure: true,
+        veto: None,
         pre_propose_info: PreProposeInfo::ModuleMayPropose {
             info: ModuleInstantiateInfo {
                 code_id: pre_propose_single,
@@ -1786,6 +1826,7 @@ fn test_migrate_from_v1() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: true,
+            veto: None,
         }
     );
 
@@ -1812,6 +1853,7 @@ fn test_migrate_from_v1() {
         VoteInfo {
             voter: Addr::unchecked(CREATOR_ADDR),
             vote: Vote::No,
+            weights: None,
             power: Uint128::new(100),
             rationale: None
         }
@@ -1884,6 +1926,7 @@ fn test_execution_failed() {
 
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
     assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+    assert!(proposal.proposal.execution_error.is_some());
 
     // Make sure the deposit was returned.
     let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
@@ -1919,6 +1962,7 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            veto: None,
         },
         &[],
     )
@@ -1983,6 +2027,11 @@ fn test_reply_proposal_mock() {
                 msgs: vec![],
                 status: Status::Open,
                 votes: Votes::zero(),
+                veto: None,
+                best_effort: false,
+                execution_error: None,
+                failed_message_index: None,
+                message_results: vec![],
             },
         )
         .unwrap();
@@ -2003,6 +2052,7 @@ fn test_reply_proposal_mock() {
 
     let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
     assert_eq!(prop.status, Status::ExecutionFailed);
+    assert_eq!(prop.execution_error, Some("error_msg".to_string()));
 }
 
 #[test]
@@ -2022,6 +2072,7 @@ fn test_proposal_too_large() {
                 description: "a".repeat(MAX_PROPOSAL_SIZE as usize),
                 msgs: vec![],
                 proposer: None,
+                best_effort: false,
             }),
             &[],
         )
@@ -2073,6 +2124,7 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                best_effort: false,
             }),
             &[],
         )
@@ -2098,6 +2150,7 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                best_effort: false,
             }),
             &[],
         )
@@ -2128,6 +2181,7 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: Some("ekez".to_string()),
+                best_effort: false,
             }),
             &[],
         )
@@ -2310,30 +2364,35 @@ fn test_query_list_votes() {
         vec![
             VoteInfo {
                 rationale: None,
+                weights: None,
                 voter: Addr::unchecked("five"),
                 vote: Vote::Yes,
                 power: Uint128::new(1)
             },
             VoteInfo {
                 rationale: None,
+                weights: None,
                 voter: Addr::unchecked("four"),
                 vote: Vote::Yes,
                 power: Uint128::new(1)
             },
             VoteInfo {
                 rationale: None,
+                weights: None,
                 voter: Addr::unchecked("one"),
                 vote: Vote::Yes,
                 power: Uint128::new(1)
             },
             VoteInfo {
                 rationale: None,
+                weights: None,
                 voter: Addr::unchecked("three"),
                 vote: Vote::No,
                 power: Uint128::new(1)
             },
             VoteInfo {
                 rationale: None,
+                weights: None,
                 voter: Addr::unchecked("two"),
                 vote: Vote::No,
                 power: Uint128::new(1)
@@ -2353,12 +2412,14 @@ fn test_query_list_votes() {
         vec![
             VoteInfo {
                 rationale: None,
+                weights: None,
                 voter: Addr::unchecked("one"),
                 vote: Vote::Yes,
                 power: Uint128::new(1)
             },
             VoteInfo {
                 rationale: None,
+                weights: None,
                 voter: Addr::unchecked("three"),
                 vote: Vote::No,
                 power: Uint128::new(1)
@@ -2684,3 +2745,1024 @@ fn test_proposal_count_goes_up() {
     let next = query_next_proposal_id(&app, &proposal_module);
     assert_eq!(next, 3);
 }
+
+#[test]
+fn test_veto() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.veto = Some(VetoConfig {
+        timelock_duration: Duration::Time(100),
+        vetoer: "vetoer".to_string(),
+        early_execute: false,
+    });
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    // Passed proposals enter their timelock once voting ends.
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    let timelock_expiration = (proposal.proposal.expiration + Duration::Time(100)).unwrap();
+    assert_eq!(
+        proposal.proposal.status,
+        Status::VetoTimelock {
+            expiration: timelock_expiration
+        }
+    );
+
+    // Can't execute while in the timelock.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(
+        err,
+        ContractError::VetoTimelock { expiration } if expiration == timelock_expiration
+    ));
+
+    // Only the vetoer may veto.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VetoError(VetoError::NotVetoer {})
+    ));
+
+    app.execute_contract(
+        Addr::unchecked("vetoer"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto { proposal_id },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Vetoed);
+
+    // Vetoed proposals may not be vetoed again or executed.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("vetoer"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VetoError(VetoError::NotVetoable {})
+    ));
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::NotPassed {}));
+
+    // Once the timelock expires the proposal is passed and may be
+    // executed as normal.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    app.update_block(|block| block.time = block.time.plus_seconds(604800 + 100));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("vetoer"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VetoError(VetoError::NotVetoable {})
+    ));
+
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+}
+
+#[test]
+fn test_veto_early_execute() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.veto = Some(VetoConfig {
+        timelock_duration: Duration::Time(100),
+        vetoer: CREATOR_ADDR.to_string(),
+        early_execute: true,
+    });
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10_000_000),
+            },
+            Cw20Coin {
+                address: "member".to_string(),
+                amount: Uint128::new(90_000_000),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, "member", 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "member", vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "member", proposal_id, Vote::Yes);
+
+    // Members other than the vetoer must wait out the timelock.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, "member", proposal_id);
+    assert!(matches!(err, ContractError::VetoTimelock { .. }));
+
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+}
+
+#[test]
+fn test_weighted_vote() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.allow_revoting = true;
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "custodian".to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    let vote_weighted = |app: &mut App, yes: u64, no: u64, abstain: u64| {
+        app.execute_contract(
+            Addr::unchecked("custodian"),
+            proposal_module.clone(),
+            &ExecuteMsg::VoteWeighted {
+                proposal_id,
+                weights: WeightedVote {
+                    yes: Decimal::percent(yes),
+                    no: Decimal::percent(no),
+                    abstain: Decimal::percent(abstain),
+                },
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    // Weights must sum to one.
+    let err: ContractError = vote_weighted(&mut app, 50, 60, 0)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::VotingError(VotingError::InvalidVoteWeights {})
+    ));
+
+    vote_weighted(&mut app, 50, 25, 25).unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes,
+        Votes {
+            yes: Uint128::new(30),
+            no: Uint128::new(15),
+            abstain: Uint128::new(15),
+        }
+    );
+
+    let err: ContractError = vote_weighted(&mut app, 50, 25, 25)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::AlreadyCast {}));
+
+    // Revoting replaces the weighted vote.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "custodian",
+        proposal_id,
+        Vote::No,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes,
+        Votes {
+            yes: Uint128::zero(),
+            no: Uint128::new(60),
+            abstain: Uint128::zero(),
+        }
+    );
+
+    // The split is reported to vote hooks and in the response.
+    let res = vote_weighted(&mut app, 75, 25, 0).unwrap();
+    assert!(res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .any(|attr| attr.key == "position" && attr.value == "yes:0.75,no:0.25,abstain:0"));
+
+    let vote = query_vote(&app, &proposal_module, "custodian", proposal_id);
+    assert_eq!(
+        vote.vote,
+        Some(VoteInfo {
+            voter: Addr::unchecked("custodian"),
+            vote: Vote::Yes,
+            weights: Some(WeightedVote {
+                yes: Decimal::percent(75),
+                no: Decimal::percent(25),
+                abstain: Decimal::zero(),
+            }),
+            power: Uint128::new(60),
+            rationale: None,
+        })
+    );
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Abstain,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes,
+        Votes {
+            yes: Uint128::new(45),
+            no: Uint128::new(15),
+            abstain: Uint128::new(40),
+        }
+    );
+
+    // 45 of the 60 non-abstaining votes are yes.
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_vote_delegation() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.allow_revoting = true;
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "alice".to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "bob".to_string(),
+                amount: Uint128::new(20),
+            },
+            Cw20Coin {
+                address: "carol".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "dave".to_string(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let delegation_id = app.store_code(vote_delegation_contract());
+    let delegation = app
+        .instantiate_contract(
+            delegation_id,
+            core_addr.clone(),
+            &dao_vote_delegation::msg::InstantiateMsg {},
+            &[],
+            "vote delegation",
+            None,
+        )
+        .unwrap();
+
+    // Only the DAO may set the delegation module.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("dave"),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateDelegationModule {
+                delegation_module: Some(delegation.to_string()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateDelegationModule {
+            delegation_module: Some(delegation.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    let delegation_module: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::DelegationModule {})
+        .unwrap();
+    assert_eq!(delegation_module, Some(delegation.clone()));
+
+    let delegate = |app: &mut App, delegator: &str| {
+        app.execute_contract(
+            Addr::unchecked(delegator),
+            delegation.clone(),
+            &dao_vote_delegation::msg::ExecuteMsg::Delegate {
+                delegate: "bob".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    app.execute_contract(
+        Addr::unchecked("bob"),
+        delegation.clone(),
+        &dao_vote_delegation::msg::ExecuteMsg::Register {},
+        &[],
+    )
+    .unwrap();
+    delegate(&mut app, "alice");
+    delegate(&mut app, "carol");
+    app.update_block(next_block);
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, "dave", vec![]);
+
+    // Carol votes before her delegate, so her voting power is not
+    // counted in bob's vote.
+    vote_on_proposal(&mut app, &proposal_module, "carol", proposal_id, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "bob", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes,
+        Votes {
+            yes: Uint128::new(30),
+            no: Uint128::new(30),
+            abstain: Uint128::zero(),
+        }
+    );
+
+    // Alice votes after her delegate, overriding bob's vote with her
+    // own voting power.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "alice",
+        proposal_id,
+        Vote::Abstain,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes,
+        Votes {
+            yes: Uint128::new(20),
+            no: Uint128::new(30),
+            abstain: Uint128::new(10),
+        }
+    );
+    let vote = query_vote(&app, &proposal_module, "bob", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(20));
+
+    // Delegations are counted at the proposal's start height, so
+    // delegating after the proposal was created has no effect on it.
+    delegate(&mut app, "dave");
+    app.update_block(next_block);
+    vote_on_proposal(&mut app, &proposal_module, "bob", proposal_id, Vote::No);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes,
+        Votes {
+            yes: Uint128::zero(),
+            no: Uint128::new(50),
+            abstain: Uint128::new(10),
+        }
+    );
+}
+
+#[test]
+fn test_amend_proposal() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // Only the proposer may amend a proposal.
+    let err = amend_proposal_should_fail(&mut app, &proposal_module, "ekez", proposal_id);
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    let created_height = app.block_info().height;
+    amend_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        "first amendment",
+        msgs.clone(),
+    );
+    app.update_block(next_block);
+    amend_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        "second amendment",
+        vec![],
+    );
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.title, "second amendment".to_string());
+    assert_eq!(proposal.proposal.msgs, vec![]);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    let revisions = query_list_revisions(&app, &proposal_module, proposal_id, None, None);
+    assert_eq!(
+        revisions.revisions,
+        vec![
+            ProposalRevision {
+                revision: 0,
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                amended_at_height: created_height,
+            },
+            ProposalRevision {
+                revision: 1,
+                title: "first amendment".to_string(),
+                description: "description".to_string(),
+                msgs,
+                amended_at_height: created_height + 1,
+            },
+        ]
+    );
+    let revisions = query_list_revisions(&app, &proposal_module, proposal_id, Some(0), None);
+    assert_eq!(revisions.revisions.len(), 1);
+    assert_eq!(revisions.revisions[0].revision, 1);
+
+    // Proposals may not be amended once votes have been cast.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let err = amend_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::AmendAfterVotes {}));
+
+    // Proposals may not be amended once they are no longer open.
+    let gov_token = query_dao_token(&app, &core_addr);
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let err = amend_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::AmendNotOpen {}));
+
+    let revisions = query_list_revisions(&app, &proposal_module, proposal_id, None, None);
+    assert_eq!(revisions.revisions, vec![]);
+}
+
+// The messages of proposals created through a pre-propose module were
+// checked by it, so only their title and description may be amended.
+#[test]
+fn test_amend_proposal_with_pre_propose_module() {
+    let CommonTest {
+        mut app,
+        core_addr: _,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![]);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id,
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![BankMsg::Send {
+                    to_address: CREATOR_ADDR.to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::AmendMsgsWithPreProposeModule {}
+    ));
+
+    amend_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        "fixed typo",
+        vec![],
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.title, "fixed typo".to_string());
+}
+
+// Messages of best effort proposals are executed independently, and
+// their results are recorded on the proposal.
+#[test]
+fn test_best_effort_execution() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+
+    // The DAO can afford to send the first and last messages, but
+    // not the second.
+    let msgs: Vec<CosmosMsg> = [6, 6, 4]
+        .into_iter()
+        .map(|amount| {
+            BankMsg::Send {
+                to_address: "ekez".to_string(),
+                amount: coins(amount, "ujuno"),
+            }
+            .into()
+        })
+        .collect();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs,
+            proposer: None,
+            best_effort: true,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 1, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, 1);
+
+    let proposal = query_proposal(&app, &proposal_module, 1);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(proposal.proposal.execution_error, None);
+    assert_eq!(proposal.proposal.message_results.len(), 3);
+    assert_eq!(
+        proposal.proposal.message_results[0],
+        MessageResult::Succeeded
+    );
+    assert!(matches!(
+        proposal.proposal.message_results[1],
+        MessageResult::Failed { .. }
+    ));
+    assert_eq!(
+        proposal.proposal.message_results[2],
+        MessageResult::Succeeded
+    );
+
+    let balance = query_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+    let balance = query_balance_native(&app, core_addr.as_str(), "ujuno");
+    assert_eq!(balance, Uint128::zero());
+}
+
+// Only best effort proposals record the index of the message that
+// failed. The messages of other proposals are executed atomically.
+#[test]
+fn test_execution_failed_message_index() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+
+    let msgs: Vec<CosmosMsg> = [6, 6, 4]
+        .into_iter()
+        .map(|amount| {
+            BankMsg::Send {
+                to_address: "ekez".to_string(),
+                amount: coins(amount, "ujuno"),
+            }
+            .into()
+        })
+        .collect();
+    for best_effort in [false, true] {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: msgs.clone(),
+                proposer: None,
+                best_effort,
+            }),
+            &[],
+        )
+        .unwrap();
+    }
+
+    // The messages of proposals that are not best effort are executed
+    // together, so none of them take effect when one fails.
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 1, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, 1);
+
+    let proposal = query_proposal(&app, &proposal_module, 1);
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+    assert_eq!(proposal.proposal.failed_message_index, None);
+    let error = proposal.proposal.execution_error.unwrap();
+    assert!(!error.is_empty());
+    assert!(error.len() <= MAX_EXECUTION_ERROR_LENGTH);
+    assert!(proposal.proposal.message_results.is_empty());
+
+    let balance = query_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::zero());
+    let balance = query_balance_native(&app, core_addr.as_str(), "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+
+    // Best effort proposals record the first message that failed.
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 2, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, 2);
+
+    let proposal = query_proposal(&app, &proposal_module, 2);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(proposal.proposal.failed_message_index, Some(1));
+    assert_eq!(proposal.proposal.execution_error, None);
+    assert!(matches!(
+        proposal.proposal.message_results[1],
+        MessageResult::Failed { .. }
+    ));
+
+    let balance = query_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+    let balance = query_balance_native(&app, core_addr.as_str(), "ujuno");
+    assert_eq!(balance, Uint128::zero());
+}
+
+#[test]
+fn test_quorum_decay() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let quorum_decay = QuorumDecay {
+        floor: Decimal::percent(10),
+        period: Duration::Time(604800),
+        steps: None,
+    };
+    instantiate.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+        quorum_decay: Some(quorum_decay),
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(20),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(80),
+            },
+        ]),
+    );
+    let gov_token = query_dao_token(&app, &core_addr);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    // The quorum decays linearly over the voting period.
+    let start = app.block_info().time;
+    let quorum = query_effective_quorum(&app, &proposal_module, proposal_id, None);
+    assert_eq!(
+        quorum,
+        Some(PercentageThreshold::Percent(Decimal::percent(30)))
+    );
+    let quorum = query_effective_quorum(
+        &app,
+        &proposal_module,
+        proposal_id,
+        Some(start.plus_seconds(302400)),
+    );
+    assert_eq!(
+        quorum,
+        Some(PercentageThreshold::Percent(Decimal::percent(20)))
+    );
+
+    // Halfway through voting the quorum is met, but too few votes
+    // have been cast to pass the proposal before expiration.
+    app.update_block(|block| block.time = block.time.plus_seconds(302400));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // At expiration the quorum has decayed to its floor and the
+    // proposal passes.
+    app.update_block(|block| block.time = block.time.plus_seconds(302400));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    let quorum = query_effective_quorum(&app, &proposal_module, proposal_id, None);
+    assert_eq!(
+        quorum,
+        Some(PercentageThreshold::Percent(Decimal::percent(10)))
+    );
+
+    // Decay periods must be in the same units as the voting period.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module,
+            &ExecuteMsg::UpdateConfig {
+                threshold: Threshold::ThresholdQuorum {
+                    threshold: PercentageThreshold::Majority {},
+                    quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+                    quorum_decay: Some(QuorumDecay {
+                        period: Duration::Height(10),
+                        ..quorum_decay
+                    }),
+                },
+                max_voting_period: Duration::Time(604800),
+                min_voting_period: None,
+                only_members_execute: true,
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                close_proposal_on_execution_failure: true,
+                veto: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::ThresholdError(ThresholdError::QuorumDecayUnitsConflict {})
+    ));
+}
+
+#[test]
+fn test_close_expired() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = get_pre_propose_info(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
+            amount: Uint128::new(10_000_000),
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+        }),
+        false,
+    );
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let gov_token = query_dao_token(&app, &core_addr);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    for _ in 0..3 {
+        mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+        make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    }
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 2, Vote::Yes);
+
+    // Nothing to close before the proposals expire.
+    close_expired_proposals(&mut app, &proposal_module, "ekez", None);
+    let proposal = query_proposal(&app, &proposal_module, 1);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // Only the DAO may set the reward.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateCloseExpiredReward {
+                reward: Some(Coin::new(5, "ujuno")),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module.clone(),
+            &ExecuteMsg::UpdateCloseExpiredReward {
+                reward: Some(Coin::new(0, "ujuno")),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ZeroCloseExpiredReward {}));
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateCloseExpiredReward {
+            reward: Some(Coin::new(5, "ujuno")),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_close_expired_reward(&app, &proposal_module),
+        Some(Coin::new(5, "ujuno"))
+    );
+    // Rewards are paid from the proposal module's balance, so they are
+    // paid immediately even though the DAO has a timelock.
+    mint_natives(&mut app, proposal_module.as_str(), coins(10, "ujuno"));
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &dao_interface::msg::ExecuteMsg::UpdateTimelock {
+            delay: Some(Duration::Time(86400)),
+            guardian: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+
+    // Closes the first rejected proposal and pays the reward.
+    close_expired_proposals(&mut app, &proposal_module, "ekez", Some(1));
+    let proposal = query_proposal(&app, &proposal_module, 1);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+    let proposal = query_proposal(&app, &proposal_module, 3);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    assert_eq!(query_balance_native(&app, "ekez", "ujuno"), Uint128::new(5));
+
+    // Passed proposals are skipped.
+    close_expired_proposals(&mut app, &proposal_module, "ekez", None);
+    let proposal = query_proposal(&app, &proposal_module, 2);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    let proposal = query_proposal(&app, &proposal_module, 3);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+
+    // Deposits of rejected proposals have been forfeited.
+    assert_eq!(
+        query_balance_cw20(&app, &gov_token, CREATOR_ADDR),
+        Uint128::zero()
+    );
+
+    // Proposals are still closed once the module can no longer pay
+    // the reward.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    close_expired_proposals(&mut app, &proposal_module, "ekez", None);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+
+    // Nothing was queued by the DAO's timelock.
+    let operations: Vec<dao_interface::state::TimelockedOperation> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_interface::msg::QueryMsg::ListTimelockedOperations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(operations.is_empty());
+
+    // Removing the reward returns the module's remaining balance to
+    // the DAO.
+    mint_natives(&mut app, proposal_module.as_str(), coins(3, "ujuno"));
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateCloseExpiredReward { reward: None },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_balance_native(&app, proposal_module.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+    assert_eq!(
+        query_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(3)
+    );
+}
+
+// `CloseExpired` checks at most `limit` proposals, and pays no reward
+// for proposals that were created without a deposit.
+#[test]
+fn test_close_expired_limit_and_reward_without_deposit() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateCloseExpiredReward {
+            reward: Some(Coin::new(5, "ujuno")),
+        },
+        &[],
+    )
+    .unwrap();
+    mint_natives(&mut app, proposal_module.as_str(), coins(10, "ujuno"));
+
+    for _ in 0..2 {
+        make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    }
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 1, Vote::Yes);
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+
+    // Only the passed proposal is checked.
+    close_expired_proposals(&mut app, &proposal_module, "ekez", Some(1));
+    let proposal = query_proposal(&app, &proposal_module, 2);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+
+    // The rejected proposal is closed, but as it has no deposit no
+    // reward is paid.
+    close_expired_proposals(&mut app, &proposal_module, "ekez", Some(1));
+    let proposal = query_proposal(&app, &proposal_module, 2);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+    assert_eq!(query_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(
+        query_balance_native(&app, proposal_module.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+}
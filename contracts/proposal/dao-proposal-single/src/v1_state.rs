@@ -23,6 +23,7 @@ pub fn v1_threshold_to_v2(v1: voting_v1::Threshold) -> Threshold {
         voting_v1::Threshold::ThresholdQuorum { threshold, quorum } => Threshold::ThresholdQuorum {
             threshold: v1_percentage_threshold_to_v2(threshold),
             quorum: v1_percentage_threshold_to_v2(quorum),
+            quorum_decay: None,
         },
         voting_v1::Threshold::AbsoluteCount { threshold } => Threshold::AbsoluteCount { threshold },
     }
@@ -136,7 +137,8 @@ mod tests {
             }),
             Threshold::ThresholdQuorum {
                 threshold: PercentageThreshold::Majority {},
-                quorum: PercentageThreshold::Percent(Decimal::percent(20))
+                quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+                quorum_decay: None,
             }
         );
     }
@@ -0,0 +1,240 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    Uint128, Uint256,
+};
+use cw2::set_contract_version;
+use dao_interface::voting::{
+    InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+use dao_voting::voting::{get_total_power, get_voting_power};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, PowerTransform, QueryMsg};
+use crate::state::{Config, Stake, CONFIG, DAO, POWERS, STAKES, TOTAL_POWER};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-power-transform";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    match msg.transform {
+        PowerTransform::SquareRoot {} => (),
+        PowerTransform::Cap { max_power } => {
+            if max_power.is_zero() {
+                return Err(ContractError::ZeroCap {});
+            }
+        }
+        PowerTransform::Conviction {
+            max_multiplier,
+            period,
+        } => {
+            if max_multiplier < Decimal::one() || period == 0 {
+                return Err(ContractError::InvalidConviction {});
+            }
+        }
+    }
+
+    // Make sure the underlying module is a voting module.
+    let voting_module = deps.api.addr_validate(&msg.voting_module)?;
+    get_total_power(deps.as_ref(), &voting_module, None)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            voting_module: voting_module.clone(),
+            transform: msg.transform,
+        },
+    )?;
+    DAO.save(deps.storage, &info.sender)?;
+    TOTAL_POWER.save(deps.storage, &Uint128::zero(), env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate")
+        .add_attribute("voting_module", voting_module))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Sync { addresses } => execute_sync(deps, env, addresses),
+    }
+}
+
+pub fn execute_sync(
+    deps: DepsMut,
+    env: Env,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut total = TOTAL_POWER.load(deps.storage)?;
+
+    for address in addresses {
+        let address = deps.api.addr_validate(&address)?;
+        // Query at the current height so that changes made to the
+        // underlying voting power in this block are not counted until
+        // the next one, matching how the voting modules snapshot
+        // voting power.
+        let underlying = get_voting_power(
+            deps.as_ref(),
+            address.clone(),
+            &config.voting_module,
+            Some(env.block.height),
+        )?;
+
+        let power = match config.transform {
+            PowerTransform::SquareRoot {} => isqrt(underlying),
+            PowerTransform::Cap { max_power } => underlying.min(max_power),
+            PowerTransform::Conviction {
+                max_multiplier,
+                period,
+            } => {
+                let since = match STAKES.may_load(deps.storage, &address)? {
+                    Some(stake) if underlying > stake.power => {
+                        weighted_since(&stake, underlying, env.block.height)?
+                    }
+                    Some(stake) => stake.since,
+                    None => env.block.height,
+                };
+                if underlying.is_zero() {
+                    STAKES.remove(deps.storage, &address);
+                } else {
+                    STAKES.save(
+                        deps.storage,
+                        &address,
+                        &Stake {
+                            power: underlying,
+                            since,
+                        },
+                    )?;
+                }
+                let held = (env.block.height - since).min(period);
+                let multiplier = Decimal::one()
+                    + (max_multiplier - Decimal::one()) * Decimal::from_ratio(held, period);
+                underlying * multiplier
+            }
+        };
+
+        let old_power = POWERS.may_load(deps.storage, &address)?.unwrap_or_default();
+        total = total
+            .checked_sub(old_power)
+            .map_err(StdError::overflow)?
+            .checked_add(power)
+            .map_err(StdError::overflow)?;
+        if power.is_zero() {
+            POWERS.remove(deps.storage, &address, env.block.height)?;
+        } else {
+            POWERS.save(deps.storage, &address, &power, env.block.height)?;
+        }
+    }
+
+    TOTAL_POWER.save(deps.storage, &total, env.block.height)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "sync")
+        .add_attribute("total_power", total))
+}
+
+/// Computes the height from which a stake is considered held after
+/// power is added to it: the power weighted average of the old
+/// stake's height and the current height.
+fn weighted_since(stake: &Stake, power: Uint128, height: u64) -> StdResult<u64> {
+    let added = power - stake.power;
+    let weighted = Uint256::from(stake.power) * Uint256::from(stake.since)
+        + Uint256::from(added) * Uint256::from(height);
+    let since = Uint128::try_from(weighted / Uint256::from(power))?;
+    // A weighted average of heights is at most the current height, so
+    // this does not truncate.
+    Ok(since.u128() as u64)
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: Uint128) -> Uint128 {
+    let n = n.u128();
+    if n < 2 {
+        return Uint128::new(n);
+    }
+    // Newton's method, starting from an estimate that is at least the
+    // square root.
+    let mut x = 1u128 << ((128 - n.leading_zeros() + 1) / 2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return Uint128::new(x);
+        }
+        x = y;
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::VotingPowerAtHeight { address, height } => {
+            query_voting_power_at_height(deps, env, address, height)
+        }
+        QueryMsg::TotalPowerAtHeight { height } => query_total_power_at_height(deps, env, height),
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::Dao {} => to_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+    }
+}
+
+pub fn query_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = deps.api.addr_validate(&address)?;
+    let height = height.unwrap_or(env.block.height);
+
+    let synced = POWERS
+        .may_load_at_height(deps.storage, &address, height)?
+        .unwrap_or_default();
+    // Bound the synced voting power by the transform of the current
+    // underlying voting power so that underlying voting power that
+    // has been moved elsewhere since the last sync may not be used to
+    // vote twice.
+    let underlying = get_voting_power(deps, address, &config.voting_module, Some(height))?;
+    let bound = match config.transform {
+        PowerTransform::SquareRoot {} => isqrt(underlying),
+        PowerTransform::Cap { max_power } => underlying.min(max_power),
+        PowerTransform::Conviction { max_multiplier, .. } => underlying * max_multiplier,
+    };
+    let power = synced.min(bound);
+
+    to_binary(&VotingPowerAtHeightResponse { power, height })
+}
+
+pub fn query_total_power_at_height(deps: Deps, env: Env, height: Option<u64>) -> StdResult<Binary> {
+    let height = height.unwrap_or(env.block.height);
+    let power = TOTAL_POWER
+        .may_load_at_height(deps.storage, height)?
+        .unwrap_or_default();
+    to_binary(&TotalPowerAtHeightResponse { power, height })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_binary(&InfoResponse { info })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("voting power cap must be non-zero")]
+    ZeroCap {},
+
+    #[error("conviction max_multiplier must be at least one and period must be non-zero")]
+    InvalidConviction {},
+}
@@ -0,0 +1,52 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
+use dao_dao_macros::voting_module_query;
+
+/// A transform applied to the voting power of the underlying voting
+/// module.
+#[cw_serde]
+pub enum PowerTransform {
+    /// Voting power is the square root of the underlying voting
+    /// power, rounded down. This is commonly known as quadratic
+    /// voting.
+    SquareRoot {},
+    /// Voting power is the underlying voting power, capped at
+    /// `max_power` per address.
+    Cap { max_power: Uint128 },
+    /// Voting power grows linearly with the number of blocks the
+    /// underlying voting power has been held, from the underlying
+    /// voting power to `max_multiplier` times it after `period`
+    /// blocks.
+    Conviction {
+        max_multiplier: Decimal,
+        period: u64,
+    },
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The voting module whose voting power is transformed.
+    pub voting_module: String,
+    /// The transform to apply.
+    pub transform: PowerTransform,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Updates the transformed voting power of `addresses` from the
+    /// underlying voting module. May be called by anyone. Addresses
+    /// have no voting power until they are synced, and the total
+    /// voting power only reflects synced addresses.
+    Sync { addresses: Vec<String> },
+}
+
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
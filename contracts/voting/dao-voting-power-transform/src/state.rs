@@ -0,0 +1,46 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
+
+use crate::msg::PowerTransform;
+
+#[cw_serde]
+pub struct Config {
+    /// The voting module whose voting power is transformed.
+    pub voting_module: Addr,
+    /// The transform applied to its voting power.
+    pub transform: PowerTransform,
+}
+
+/// Underlying voting power held by an address, used to compute
+/// conviction.
+#[cw_serde]
+pub struct Stake {
+    /// The underlying voting power when the address was last synced.
+    pub power: Uint128,
+    /// The height from which `power` is considered held. When power
+    /// is added this moves forward to the power weighted average of
+    /// the heights it was added at.
+    pub since: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const DAO: Item<Addr> = Item::new("dao_address");
+
+/// Transformed voting power as of each address' last sync.
+pub const POWERS: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
+    "powers",
+    "powers__checkpoints",
+    "powers__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The sum of `POWERS`.
+pub const TOTAL_POWER: SnapshotItem<Uint128> = SnapshotItem::new(
+    "total_power",
+    "total_power__checkpoints",
+    "total_power__changelog",
+    Strategy::EveryBlock,
+);
+
+pub const STAKES: Map<&Addr, Stake> = Map::new("stakes");
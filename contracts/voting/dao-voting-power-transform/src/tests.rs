@@ -0,0 +1,265 @@
+use cosmwasm_std::{Addr, Decimal, Empty, Uint128};
+use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
+use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, PowerTransform, QueryMsg},
+    ContractError,
+};
+
+const DAO_ADDR: &str = "dao";
+
+fn transform_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn cw4_group_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw4_group::contract::execute,
+        cw4_group::contract::instantiate,
+        cw4_group::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn cw4_voting_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_voting_cw4::contract::execute,
+        dao_voting_cw4::contract::instantiate,
+        dao_voting_cw4::contract::query,
+    )
+    .with_reply(dao_voting_cw4::contract::reply);
+    Box::new(contract)
+}
+
+struct TestSetup {
+    app: App,
+    group: Addr,
+    transform: Addr,
+}
+
+/// Instantiates a cw4 voting module with `members` and a transform
+/// module wrapping it.
+fn setup_test(members: Vec<(&str, u64)>, transform: PowerTransform) -> TestSetup {
+    let mut app = App::default();
+    let cw4_id = app.store_code(cw4_group_contract());
+    let voting_id = app.store_code(cw4_voting_contract());
+    let transform_id = app.store_code(transform_contract());
+
+    let voting_module = app
+        .instantiate_contract(
+            voting_id,
+            Addr::unchecked(DAO_ADDR),
+            &dao_voting_cw4::msg::InstantiateMsg {
+                cw4_group_code_id: cw4_id,
+                initial_members: members
+                    .into_iter()
+                    .map(|(addr, weight)| cw4::Member {
+                        addr: addr.to_string(),
+                        weight,
+                    })
+                    .collect(),
+            },
+            &[],
+            "voting module",
+            None,
+        )
+        .unwrap();
+    let group: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            &voting_module,
+            &dao_voting_cw4::msg::QueryMsg::GroupContract {},
+        )
+        .unwrap();
+    let transform = app
+        .instantiate_contract(
+            transform_id,
+            Addr::unchecked(DAO_ADDR),
+            &InstantiateMsg {
+                voting_module: voting_module.to_string(),
+                transform,
+            },
+            &[],
+            "transform",
+            None,
+        )
+        .unwrap();
+    app.update_block(next_block);
+
+    TestSetup {
+        app,
+        group,
+        transform,
+    }
+}
+
+fn sync(app: &mut App, transform: &Addr, addresses: &[&str]) {
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        transform.clone(),
+        &ExecuteMsg::Sync {
+            addresses: addresses.iter().map(|a| a.to_string()).collect(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn set_weight(app: &mut App, group: &Addr, addr: &str, weight: u64) {
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        group.clone(),
+        &cw4_group::msg::ExecuteMsg::UpdateMembers {
+            remove: vec![],
+            add: vec![cw4::Member {
+                addr: addr.to_string(),
+                weight,
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn query_power(app: &App, transform: &Addr, address: &str) -> u128 {
+    let res: VotingPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(
+            transform,
+            &QueryMsg::VotingPowerAtHeight {
+                address: address.to_string(),
+                height: None,
+            },
+        )
+        .unwrap();
+    res.power.u128()
+}
+
+fn query_total_power(app: &App, transform: &Addr) -> u128 {
+    let res: TotalPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(transform, &QueryMsg::TotalPowerAtHeight { height: None })
+        .unwrap();
+    res.power.u128()
+}
+
+#[test]
+fn test_square_root() {
+    let TestSetup {
+        mut app, transform, ..
+    } = setup_test(
+        vec![("alice", 100), ("bob", 16)],
+        PowerTransform::SquareRoot {},
+    );
+
+    // Addresses have no voting power until synced.
+    assert_eq!(query_power(&app, &transform, "alice"), 0);
+
+    sync(&mut app, &transform, &["alice", "bob"]);
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, &transform, "alice"), 10);
+    assert_eq!(query_power(&app, &transform, "bob"), 4);
+    assert_eq!(query_total_power(&app, &transform), 14);
+}
+
+#[test]
+fn test_cap() {
+    let TestSetup {
+        mut app,
+        group,
+        transform,
+    } = setup_test(
+        vec![("alice", 100), ("bob", 16)],
+        PowerTransform::Cap {
+            max_power: Uint128::new(50),
+        },
+    );
+
+    sync(&mut app, &transform, &["alice", "bob"]);
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, &transform, "alice"), 50);
+    assert_eq!(query_power(&app, &transform, "bob"), 16);
+    assert_eq!(query_total_power(&app, &transform), 66);
+
+    // Voting power is bounded by the underlying voting power, even
+    // before the address is synced again.
+    set_weight(&mut app, &group, "alice", 20);
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, &transform, "alice"), 20);
+    assert_eq!(query_total_power(&app, &transform), 66);
+
+    sync(&mut app, &transform, &["alice"]);
+    app.update_block(next_block);
+    assert_eq!(query_total_power(&app, &transform), 36);
+}
+
+#[test]
+fn test_conviction() {
+    let TestSetup {
+        mut app,
+        group,
+        transform,
+    } = setup_test(
+        vec![("alice", 100)],
+        PowerTransform::Conviction {
+            max_multiplier: Decimal::percent(200),
+            period: 10,
+        },
+    );
+
+    sync(&mut app, &transform, &["alice"]);
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, &transform, "alice"), 100);
+
+    // Conviction grows linearly over the period.
+    app.update_block(|block| block.height += 4);
+    sync(&mut app, &transform, &["alice"]);
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, &transform, "alice"), 150);
+
+    app.update_block(|block| block.height += 10);
+    sync(&mut app, &transform, &["alice"]);
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, &transform, "alice"), 200);
+    assert_eq!(query_total_power(&app, &transform), 200);
+
+    // Newly added power has not been held, so the stake is considered
+    // held from the power weighted average of the heights the old and
+    // new power were added at: nine blocks ago.
+    set_weight(&mut app, &group, "alice", 200);
+    app.update_block(next_block);
+    sync(&mut app, &transform, &["alice"]);
+    app.update_block(next_block);
+    assert_eq!(query_power(&app, &transform, "alice"), 380);
+}
+
+#[test]
+fn test_invalid_transform() {
+    let mut app = App::default();
+    let transform_id = app.store_code(transform_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            transform_id,
+            Addr::unchecked(DAO_ADDR),
+            &InstantiateMsg {
+                voting_module: "voting".to_string(),
+                transform: PowerTransform::Conviction {
+                    max_multiplier: Decimal::percent(50),
+                    period: 10,
+                },
+            },
+            &[],
+            "transform",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidConviction {});
+}
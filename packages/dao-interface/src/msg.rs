@@ -1,9 +1,13 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 
 use crate::state::Config;
-use crate::{migrate_msg::MigrateParams, query::SubDao, state::ModuleInstantiateInfo};
+use crate::{
+    migrate_msg::MigrateParams,
+    query::SubDao,
+    state::{ItemValueType, ModuleInstantiateInfo, ModulePermissions, SubDaoAction},
+};
 
 /// Information about an item to be stored in the items list.
 #[cw_serde]
@@ -59,10 +63,30 @@ pub enum ExecuteMsg {
     ExecuteAdminMsgs { msgs: Vec<CosmosMsg<Empty>> },
     /// Callable by proposal modules. The DAO will execute the
     /// messages in the hook in order.
+    ///
+    /// If the DAO has a timelock the messages are instead queued as a
+    /// `TimelockedOperation`, and the ID of the operation is returned
+    /// in the `timelocked_operation_id` attribute. Proposal modules
+    /// consider a proposal executed once its messages are queued.
+    /// Messages that only cancel timelocked operations are never
+    /// queued.
     ExecuteProposalHook { msgs: Vec<CosmosMsg<Empty>> },
-    /// Pauses the DAO for a set duration.
+    /// Pauses the DAO for a set duration. Callable by the core
+    /// contract or the pause guardian.
     /// When paused the DAO is unable to execute proposals
+    ///
+    /// The pause guardian may pause for at most roughly a week, and
+    /// must wait as long as it paused for after a pause ends before
+    /// pausing again.
     Pause { duration: Duration },
+    /// Lifts the current pause. Callable by the core contract, the
+    /// DAO's admin or the pause guardian.
+    ///
+    /// While the DAO is paused the only messages that may be executed
+    /// are this one and an `UpdateConfig` that removes the pause
+    /// guardian. Proposal modules may execute these messages while the
+    /// DAO is paused, and they are not subject to the timelock.
+    Unpause {},
     /// Executed when the contract receives a cw20 token. Depending on
     /// the contract's configuration the contract will automatically
     /// add the token to its treasury.
@@ -71,12 +95,58 @@ pub enum ExecuteMsg {
     /// on the contract's configuration the contract will
     /// automatically add the token to its treasury.
     ReceiveNft(cw721::Cw721ReceiveMsg),
+    /// Callable by the core contract. Allows `grantee` to execute
+    /// messages on `contract` via `ExecuteDelegated` without a
+    /// proposal. If `msg_type` is set only messages of that type may
+    /// be executed, where the type of a message is its top level JSON
+    /// key. If `max_uses` is set the grant is removed after being
+    /// used that many times.
+    ///
+    /// Grants on the core contract itself must set `msg_type` to one
+    /// of `set_item`, `set_typed_item`, `remove_item`,
+    /// `update_cw20_list`, `update_cw721_list` or
+    /// `update_native_list`, so that governance may not be delegated.
+    CreateGrant {
+        grantee: String,
+        contract: String,
+        msg_type: Option<String>,
+        max_uses: Option<u64>,
+        expiration: Expiration,
+    },
+    /// Callable by the core contract. Removes a grant.
+    RevokeGrant { grantee: String, id: u64 },
+    /// Executes messages on behalf of the DAO using the sender's
+    /// grants. Each message must be a `WasmMsg::Execute` without
+    /// funds that is permitted by one of the sender's grants. Using
+    /// a grant consumes one of its uses.
+    ExecuteDelegated { msgs: Vec<CosmosMsg<Empty>> },
     /// Removes an item from the governance contract's item map.
     RemoveItem { key: String },
     /// Adds an item to the governance contract's item map. If the
     /// item already exists the existing value is overridden. If the
     /// item does not exist a new item is added.
+    ///
+    /// Keys may be namespaced with `/`, for example `profile/name`.
+    /// If the item has a type its value must be valid for that type.
     SetItem { key: String, value: String },
+    /// Adds an item whose value has a type to the governance
+    /// contract's item map. The value is validated against
+    /// `value_type`.
+    SetTypedItem {
+        key: String,
+        value: String,
+        value_type: ItemValueType,
+    },
+    /// Callable by the core contract. Allows a proposal module to set
+    /// and remove items whose keys match one of the given
+    /// patterns. A pattern is either an exact key or a namespace
+    /// ending in `/*`, for example `widgets/*`, which matches every
+    /// key in that namespace.
+    UpdateItemPermissions {
+        module: String,
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
     /// Callable by the admin of the contract. If ADMIN is None the
     /// admin is set as the contract itself so that it may be updated
     /// later by vote. If ADMIN is Some a new admin is proposed and
@@ -111,6 +181,13 @@ pub enum ExecuteMsg {
         to_add: Vec<String>,
         to_remove: Vec<String>,
     },
+    /// Updates the list of native denoms this contract has
+    /// registered. Denoms sent to the contract along with an execute
+    /// message are registered automatically.
+    UpdateNativeList {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
     /// Updates the governance contract's governance modules. Module
     /// instantiate info in `to_add` is used to create new modules and
     /// install them.
@@ -124,11 +201,71 @@ pub enum ExecuteMsg {
     /// voting module with a new one instantiated by the governance
     /// contract.
     UpdateVotingModule { module: ModuleInstantiateInfo },
-    /// Update the core module to add/remove SubDAOs and their charters
+    /// Update the core module to add/remove SubDAOs and their charters.
+    ///
+    /// Added SubDAOs must be DAO DAO core contracts. If this DAO is
+    /// the admin of an added or removed SubDAO, the SubDAO's parent
+    /// is updated accordingly. Removed SubDAOs that reject the update
+    /// are removed regardless.
     UpdateSubDaos {
         to_add: Vec<SubDao>,
         to_remove: Vec<String>,
     },
+    /// Callable by the core contract or the admin. Sets the DAO that
+    /// this DAO is a SubDAO of. If `parent` is `None` the parent is
+    /// removed.
+    UpdateParentDao { parent: Option<String> },
+    /// Callable by the core contract. Takes an action on one of this
+    /// DAO's SubDAOs.
+    ExecuteSubDaoAction {
+        address: String,
+        action: SubDaoAction,
+    },
+    /// Callable by the core contract. Sets the timelock applied to
+    /// messages executed by proposal modules. If `delay` is `None`
+    /// the timelock is removed and proposal module messages are
+    /// executed immediately.
+    ///
+    /// NOTE: while a timelock is set this message is itself subject
+    /// to the timelock when executed by a proposal.
+    UpdateTimelock {
+        delay: Option<Duration>,
+        guardian: Option<String>,
+    },
+    /// Executes a queued operation whose timelock delay has
+    /// passed. Callable by anyone.
+    ExecuteTimelockedOperation { id: u64 },
+    /// Callable by the timelock guardian or the core contract. Removes
+    /// a queued operation without executing it. Proposals that only
+    /// cancel operations are executed immediately rather than being
+    /// queued themselves.
+    CancelTimelockedOperation { id: u64 },
+    /// Callable by the core contract. Updates the list of Stargate
+    /// message type URLs that proposal modules and the admin may
+    /// execute, and, if `enforce` is set, whether the list is
+    /// enforced. While the list is enforced, messages containing a
+    /// `CosmosMsg::Stargate` whose type URL is not in it are
+    /// rejected, and an empty list rejects all Stargate messages.
+    /// While it is not enforced any Stargate message may be
+    /// executed. This allows DAOs to execute chain specific messages,
+    /// for example token factory mints, without opening up every
+    /// module of the chain to proposals.
+    ///
+    /// `CosmosMsg::Custom` messages are not supported, chain specific
+    /// messages must be sent as Stargate messages.
+    UpdateStargateAllowlist {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+        enforce: Option<bool>,
+    },
+    /// Callable by the core contract. Sets the permissions of a
+    /// proposal module, limiting the messages it may execute. If
+    /// `permissions` is `None` the module's permissions are removed
+    /// and it may execute any message.
+    UpdateProposalModulePermissions {
+        address: String,
+        permissions: Option<ModulePermissions>,
+    },
 }
 
 #[cw_serde]
@@ -164,6 +301,27 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Lists the token IDs of the NFTs in `collection` owned by this
+    /// contract.
+    #[returns(Vec<String>)]
+    Cw721Holdings {
+        collection: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists the native denoms in this contract's treasury.
+    #[returns(Vec<String>)]
+    NativeTokenList {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the balance of each native denom registered with the
+    /// contract.
+    #[returns(Vec<cosmwasm_std::Coin>)]
+    NativeBalances {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// Dumps all of the core contract's state in a single
     /// query. Useful for frontends as performance for queries is more
     /// limited by network times than compute times.
@@ -181,6 +339,30 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Lists the grants of `grantee` that have not expired in
+    /// ascending order of ID.
+    #[returns(Vec<crate::state::Grant>)]
+    ListGrants {
+        grantee: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets an item along with the type of its value.
+    #[returns(crate::query::GetTypedItemResponse)]
+    GetTypedItem { key: String },
+    /// Lists the items whose keys start with `prefix` in ascending
+    /// order. For example, a prefix of `profile/` lists every item in
+    /// the `profile` namespace.
+    #[returns(Vec<(String, String)>)]
+    ListItemsByPrefix {
+        prefix: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists the item key patterns a proposal module may set and
+    /// remove items for.
+    #[returns(Vec<String>)]
+    ItemPermissions { module: String },
     /// Returns contract version info
     #[returns(crate::voting::InfoResponse)]
     Info {},
@@ -205,6 +387,12 @@ pub enum QueryMsg {
     /// Returns information about if the contract is currently paused.
     #[returns(crate::query::PauseInfoResponse)]
     PauseInfo {},
+    /// Lists the times the DAO has been paused, most recent first.
+    #[returns(Vec<crate::state::PauseRecord>)]
+    PauseHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     /// Gets the contract's voting module.
     #[returns(cosmwasm_std::Addr)]
     VotingModule {},
@@ -215,6 +403,20 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Gets the DAO that this DAO is a SubDAO of, if any. Returns
+    /// `Option<Addr>`.
+    #[returns(Option<cosmwasm_std::Addr>)]
+    ParentDao {},
+    /// Walks up the DAO tree, returning this DAO's parent, its
+    /// parent's parent, and so on until a DAO without a parent is
+    /// reached.
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    DaoAncestors {},
+    /// Walks down the DAO tree, returning this DAO's SubDAOs, their
+    /// SubDAOs, and so on up to `depth` levels down. Defaults to, and
+    /// may not exceed, the maximum tree depth.
+    #[returns(Vec<crate::query::SubDaoTreeNode>)]
+    SubDaoTree { depth: Option<u32> },
     /// Implements the DAO Star standard: <https://daostar.one/EIP>
     #[returns(crate::query::DaoURIResponse)]
     DaoURI {},
@@ -227,6 +429,38 @@ pub enum QueryMsg {
     /// Returns the total voting power at a given block height.
     #[returns(crate::voting::TotalPowerAtHeightResponse)]
     TotalPowerAtHeight { height: Option<u64> },
+    /// Gets the timelock applied to proposal module messages, if any.
+    #[returns(Option<crate::state::TimelockConfig>)]
+    TimelockConfig {},
+    /// Gets a queued operation by ID.
+    #[returns(crate::state::TimelockedOperation)]
+    TimelockedOperation { id: u64 },
+    /// Lists operations that are waiting to be executed in order of
+    /// ascending ID.
+    #[returns(Vec<crate::state::TimelockedOperation>)]
+    ListTimelockedOperations {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lists the Stargate message type URLs proposal modules may
+    /// execute.
+    #[returns(Vec<String>)]
+    StargateAllowlist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns whether Stargate messages are restricted to the
+    /// Stargate allowlist.
+    #[returns(bool)]
+    StargateAllowlistEnforced {},
+    /// Gets the permissions of a proposal module. Returns `None` if
+    /// the module may execute any message.
+    #[returns(Option<crate::state::ModulePermissions>)]
+    ProposalModulePermissions { address: String },
+    /// Lists how much of each denom a proposal module has spent in
+    /// the current period.
+    #[returns(Vec<crate::state::ModuleSpending>)]
+    ProposalModuleSpending { address: String },
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -3,7 +3,7 @@ use cosmwasm_std::{Addr, Uint128};
 use cw2::ContractVersion;
 use cw_utils::Expiration;
 
-use crate::state::{Config, ProposalModule};
+use crate::state::{Config, ItemValueType, ProposalModule};
 
 /// Relevant state for the governance module. Returned by the
 /// `DumpState` query.
@@ -43,6 +43,17 @@ pub struct GetItemResponse {
     pub item: Option<String>,
 }
 
+/// Returned by the `GetTypedItem` query.
+#[cw_serde]
+pub struct GetTypedItemResponse {
+    /// `None` if no item with the provided key was found, `Some`
+    /// otherwise.
+    pub item: Option<String>,
+    /// The type of the item's value. `None` if the item was set with
+    /// `SetItem` and is an untyped string.
+    pub value_type: Option<ItemValueType>,
+}
+
 /// Returned by the `Cw20Balances` query.
 #[cw_serde]
 pub struct Cw20BalanceResponse {
@@ -68,6 +79,19 @@ pub struct SubDao {
     pub charter: Option<String>,
 }
 
+#[cw_serde]
+pub struct SubDaoTreeNode {
+    /// The contract address of the SubDAO.
+    pub addr: Addr,
+    /// The purpose/constitution for the SubDAO.
+    pub charter: Option<String>,
+    /// The DAO that lists this SubDAO.
+    pub parent: Addr,
+    /// How many levels below the queried DAO this SubDAO is. The
+    /// queried DAO's own SubDAOs have a depth of 1.
+    pub depth: u32,
+}
+
 #[cw_serde]
 pub struct DaoURIResponse {
     pub dao_uri: Option<String>,
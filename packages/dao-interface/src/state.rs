@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, CosmosMsg, WasmMsg};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Uint128, WasmMsg};
+use cw_utils::{Duration, Expiration};
 
 /// Top level config type for core module.
 #[cw_serde]
@@ -16,9 +17,30 @@ pub struct Config {
     /// If true the contract will automatically add received cw721
     /// tokens to its treasury.
     pub automatically_add_cw721s: bool,
+    /// If true the contract will automatically add native tokens
+    /// sent to it to its treasury.
+    #[serde(default)]
+    pub automatically_add_native_denoms: bool,
     /// The URI for the DAO as defined by the DAOstar standard
     /// <https://daostar.one/EIP>
     pub dao_uri: Option<String>,
+    /// An optional address that may pause the DAO without a proposal
+    /// and lift a pause early, for example a security multisig.
+    pub pause_guardian: Option<Addr>,
+}
+
+/// The type of an item's value. Values of typed items are validated
+/// against their type whenever they are set.
+#[cw_serde]
+pub enum ItemValueType {
+    /// Any string.
+    String,
+    /// A valid address on the chain.
+    Address,
+    /// A URL with an `http`, `https` or `ipfs` scheme.
+    Url,
+    /// A valid JSON document.
+    Json,
 }
 
 /// Top level type describing a proposal module.
@@ -40,6 +62,121 @@ pub enum ProposalModuleStatus {
     Disabled,
 }
 
+/// Configuration for the core module's timelock. When a timelock is
+/// configured, messages sent by proposal modules via
+/// `ExecuteProposalHook` are queued rather than executed and may only
+/// be executed once `delay` has passed.
+#[cw_serde]
+pub struct TimelockConfig {
+    /// How long queued messages must wait before they may be
+    /// executed.
+    pub delay: Duration,
+    /// An optional address that may cancel queued operations. The
+    /// DAO itself may always cancel queued operations.
+    pub guardian: Option<Addr>,
+}
+
+/// A set of messages sent by a proposal module that are waiting for
+/// the timelock delay to pass before being executed.
+#[cw_serde]
+pub struct TimelockedOperation {
+    /// The ID of the operation.
+    pub id: u64,
+    /// The proposal module that queued the messages.
+    pub proposal_module: Addr,
+    /// The messages that will be executed.
+    pub msgs: Vec<CosmosMsg>,
+    /// The time after which the messages may be executed.
+    pub executable_at: Expiration,
+}
+
+/// Limits the messages a proposal module may execute via
+/// `ExecuteProposalHook`. Proposal modules without permissions may
+/// execute any message.
+///
+/// A module with permissions may only execute `BankMsg::Send`
+/// messages and `WasmMsg::Execute` messages targeting
+/// `allowed_contracts`. All native tokens sent by the module,
+/// including funds attached to contract executions, count towards
+/// its spend limits. cw20 `Transfer`, `Send`, `IncreaseAllowance`
+/// and `Burn` messages count towards the spend limit whose denom is
+/// the address of the token contract. Denoms without a spend limit
+/// may not be spent.
+///
+/// When the DAO has a timelock, permissions are checked both when
+/// messages are queued and when they are executed, and spending is
+/// charged when they are executed.
+#[cw_serde]
+pub struct ModulePermissions {
+    /// Contracts the module may execute messages on.
+    pub allowed_contracts: Vec<String>,
+    /// Per-token limits on how many native or cw20 tokens the module
+    /// may spend.
+    pub spend_limits: Vec<SpendLimit>,
+}
+
+/// The maximum amount of a native or cw20 token a proposal module may
+/// spend per period.
+#[cw_serde]
+pub struct SpendLimit {
+    /// The native denom, or the address of the cw20 token contract,
+    /// being limited.
+    pub denom: String,
+    /// The amount that may be spent each period.
+    pub amount: Uint128,
+    /// How long a period lasts. A period starts when the module first
+    /// spends the denom after the previous period has ended.
+    pub period: Duration,
+}
+
+/// The amount of a native or cw20 token a proposal module has spent
+/// in the current period.
+#[cw_serde]
+pub struct ModuleSpending {
+    pub denom: String,
+    /// The amount spent in the current period.
+    pub spent: Uint128,
+    /// When the current period ends and `spent` resets.
+    pub resets_at: Expiration,
+}
+
+/// Permission for an address to execute messages on a contract on
+/// behalf of the DAO without a proposal.
+#[cw_serde]
+pub struct Grant {
+    /// The ID of the grant.
+    pub id: u64,
+    /// The address that may use the grant.
+    pub grantee: Addr,
+    /// The contract the grantee may execute messages on.
+    pub contract: Addr,
+    /// If set, the grantee may only execute messages of this type on
+    /// `contract`. The type of a message is its top level JSON key,
+    /// for example `claim` for `{"claim":{}}`.
+    pub msg_type: Option<String>,
+    /// How many more messages the grantee may execute with this
+    /// grant. `None` if the grant may be used any number of times.
+    pub remaining_uses: Option<u64>,
+    /// When the grant expires.
+    pub expiration: Expiration,
+}
+
+/// A record of the DAO being paused.
+#[cw_serde]
+pub struct PauseRecord {
+    /// The ID of the record. Records are numbered in the order the
+    /// pauses occurred, starting at one.
+    pub id: u64,
+    /// The address that paused the DAO.
+    pub paused_by: Addr,
+    /// The block height at which the DAO was paused.
+    pub paused_at: u64,
+    /// When the pause was set to expire.
+    pub until: Expiration,
+    /// The address that lifted the pause early, if any.
+    pub unpaused_by: Option<Addr>,
+}
+
 /// Information about the CosmWasm level admin of a contract. Used in
 /// conjunction with `ModuleInstantiateInfo` to instantiate modules.
 #[cw_serde]
@@ -85,6 +222,20 @@ pub struct ModuleInstantiateCallback {
     pub msgs: Vec<CosmosMsg>,
 }
 
+/// An action a DAO may take on one of its SubDAOs.
+#[cw_serde]
+pub enum SubDaoAction {
+    /// Sends native tokens to the SubDAO.
+    Fund { funds: Vec<Coin> },
+    /// Pauses the SubDAO. The parent DAO must be the SubDAO's admin
+    /// or pause guardian.
+    Pause { duration: Duration },
+    /// Nominates a new admin for the SubDAO. The parent DAO must be
+    /// the SubDAO's admin. If `admin` is `None` the SubDAO becomes
+    /// its own admin.
+    NominateAdmin { admin: Option<String> },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,6 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use cw_denom::DenomError;
-use cw_utils::ParseReplyError;
+use cw_utils::{Expiration, ParseReplyError};
 use thiserror::Error;
 
 use cw_hooks::HookError;
@@ -32,6 +32,24 @@ pub enum PreProposeError {
     #[error("You must be a member of this DAO (have voting power) to create a proposal")]
     NotMember {},
 
+    #[error("This address may not create proposals")]
+    NotAllowedProposer {},
+
+    #[error("You must have at least ({required}) voting power to create a proposal")]
+    InsufficientVotingPower { required: Uint128 },
+
+    #[error("You may not have more than ({max}) open proposals")]
+    TooManyOpenProposals { max: u64 },
+
+    #[error("You may not create another proposal until ({next})")]
+    SubmissionRateLimited { next: Expiration },
+
+    #[error("Minimum voting power percentage must be greater than zero and at most one")]
+    InvalidMinVotingPower {},
+
+    #[error("Proposal submission rate limit must be non-zero")]
+    ZeroRateLimit {},
+
     #[error("No denomination for withdrawal. specify a denomination to withdraw")]
     NoWithdrawalDenom {},
 
@@ -41,12 +59,39 @@ pub enum PreProposeError {
     #[error("Proposal status ({status}) not closed or executed")]
     NotClosedOrExecuted { status: Status },
 
+    #[error("No deposit was forfeited to the voters on proposal ({id})")]
+    NoForfeitedDeposit { id: u64 },
+
+    #[error("Only addresses that voted on proposal ({id}) may claim its forfeited deposit")]
+    NotVoter { id: u64 },
+
+    #[error("Forfeited deposit for proposal ({id}) has already been claimed")]
+    ForfeitAlreadyClaimed { id: u64 },
+
     #[error("Proposal not found")]
     ProposalNotFound {},
 
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Approver has already voted on this proposal")]
+    AlreadyVoted {},
+
+    #[error("Pending proposal has expired")]
+    PendingProposalExpired {},
+
+    #[error("Pending proposal has not expired")]
+    PendingProposalNotExpired {},
+
+    #[error("Pending proposals may not be updated while proposal submitted hooks are registered")]
+    PendingProposalUpdateWithHooks {},
+
+    #[error("Approval threshold must be non-zero and no more than the committee's total weight")]
+    InvalidApprovalThreshold {},
+
+    #[error("Comments may be at most ({max}) bytes long")]
+    CommentTooLong { max: usize },
+
     #[error("An unknown reply ID was received.")]
     UnknownReplyID {},
 }
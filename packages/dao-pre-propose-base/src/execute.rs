@@ -1,22 +1,32 @@
 use cosmwasm_schema::schemars::JsonSchema;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg, WasmMsg,
+    to_binary, Addr, Binary, BlockInfo, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
 
 use cw_denom::UncheckedDenom;
-use dao_interface::voting::{Query as CwCoreQuery, VotingPowerAtHeightResponse};
+use dao_interface::voting::{
+    Query as CwCoreQuery, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
 use dao_voting::{
-    deposit::{DepositRefundPolicy, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, CheckedForfeitDestination, DepositRefundPolicy, UncheckedDepositInfo,
+        UncheckedDepositOptions,
+    },
     status::Status,
+    threshold::PercentageThreshold,
+    voting::does_vote_count_pass,
 };
 use serde::Serialize;
 
 use crate::{
     error::PreProposeError,
-    msg::{DepositInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg},
-    state::{Config, PreProposeContract},
+    msg::{DepositInfoResponse, ExecuteMsg, InstantiateMsg, ProposalMessages, QueryMsg},
+    proposal_module::{query_power_cast, query_reached_quorum, query_vote_power},
+    state::{Config, ForfeitedDeposit, PreProposeContract},
+    submission::{MinVotingPower, SubmissionRateLimit, UncheckedSubmissionPolicy},
 };
 
 const CONTRACT_NAME: &str = "crates.io::dao-pre-propose-base";
@@ -25,7 +35,7 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
     PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
 where
-    ProposalMessage: Serialize,
+    ProposalMessage: Serialize + ProposalMessages,
     QueryExt: JsonSchema,
 {
     pub fn instantiate(
@@ -86,6 +96,12 @@ where
                 deposit_info,
                 open_proposal_submission,
             } => self.execute_update_config(deps, info, deposit_info, open_proposal_submission),
+            ExecuteMsg::UpdateSubmissionPolicy { policy } => {
+                self.execute_update_submission_policy(deps, info, policy)
+            }
+            ExecuteMsg::UpdateDepositOptions { options } => {
+                self.execute_update_deposit_options(deps, info, options)
+            }
             ExecuteMsg::Withdraw { denom } => {
                 self.execute_withdraw(deps.as_ref(), env, info, denom)
             }
@@ -98,8 +114,11 @@ where
             ExecuteMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
-            } => self.execute_proposal_completed_hook(deps.as_ref(), info, proposal_id, new_status),
+            } => self.execute_proposal_completed_hook(deps, info, proposal_id, new_status),
 
+            ExecuteMsg::ClaimForfeitedDeposit { proposal_id } => {
+                self.execute_claim_forfeited_deposit(deps, info, proposal_id)
+            }
             ExecuteMsg::Extension { .. } => Ok(Response::default()),
         }
     }
@@ -112,15 +131,10 @@ where
         msg: ProposalMessage,
     ) -> Result<Response, PreProposeError> {
         self.check_can_submit(deps.as_ref(), info.sender.clone())?;
+        self.record_submission(deps.storage, &env.block, &info.sender)?;
 
-        let config = self.config.load(deps.storage)?;
-
-        let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
-            deposit_info.check_native_deposit_paid(&info)?;
-            deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
-        } else {
-            vec![]
-        };
+        let (deposit_info, deposit_messages) =
+            self.take_deposit(deps.as_ref(), &env, &info, &msg.cosmos_msgs())?;
 
         let proposal_module = self.proposal_module.load(deps.storage)?;
 
@@ -130,11 +144,8 @@ where
             &proposal_module,
             &dao_interface::proposal::Query::NextProposalId {},
         )?;
-        self.deposits.save(
-            deps.storage,
-            next_id,
-            &(config.deposit_info, info.sender.clone()),
-        )?;
+        self.deposits
+            .save(deps.storage, next_id, &(deposit_info, info.sender.clone()))?;
 
         let propose_messsage = WasmMsg::Execute {
             contract_addr: proposal_module.into_string(),
@@ -193,6 +204,44 @@ where
         }
     }
 
+    pub fn execute_update_submission_policy(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        policy: UncheckedSubmissionPolicy,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let policy = policy.into_checked(deps.as_ref())?;
+        self.submission_policy.save(deps.storage, &policy)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "update_submission_policy")
+            .add_attribute("sender", info.sender))
+    }
+
+    pub fn execute_update_deposit_options(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        options: UncheckedDepositOptions,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let options = options.into_checked(deps.as_ref(), dao)?;
+        self.deposit_options.save(deps.storage, &options)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "update_deposit_options")
+            .add_attribute("sender", info.sender))
+    }
+
     pub fn execute_withdraw(
         &self,
         deps: Deps,
@@ -270,7 +319,7 @@ where
 
     pub fn execute_proposal_completed_hook(
         &self,
-        deps: Deps,
+        mut deps: DepsMut,
         info: MessageInfo,
         id: u64,
         new_status: Status,
@@ -285,26 +334,39 @@ where
         // bizare has happened. In that event, this message errors
         // which ought to cause the proposal module to remove this
         // module and open proposal submission to anyone.
-        if new_status != Status::Closed && new_status != Status::Executed {
+        if new_status != Status::Closed
+            && new_status != Status::Executed
+            && new_status != Status::Vetoed
+        {
             return Err(PreProposeError::NotClosedOrExecuted { status: new_status });
         }
 
         match self.deposits.may_load(deps.storage, id)? {
             Some((deposit_info, proposer)) => {
+                self.record_completion(deps.storage, &proposer)?;
+
                 let messages = if let Some(ref deposit_info) = deposit_info {
-                    // Refund can be issued if proposal if it is going to
-                    // closed or executed.
-                    let should_refund_to_proposer = (new_status == Status::Closed
-                        && deposit_info.refund_policy == DepositRefundPolicy::Always)
-                        || (new_status == Status::Executed
-                            && deposit_info.refund_policy != DepositRefundPolicy::Never);
+                    // Whether the deposit is refunded depends on the
+                    // refund policy. Vetoed proposals are treated like
+                    // closed ones.
+                    let should_refund_to_proposer = match deposit_info.refund_policy {
+                        DepositRefundPolicy::Always => true,
+                        DepositRefundPolicy::OnlyPassed => new_status == Status::Executed,
+                        DepositRefundPolicy::Never => false,
+                        DepositRefundPolicy::OnlyQuorum => {
+                            new_status == Status::Executed
+                                || query_reached_quorum(deps.querier, &proposal_module, id)?
+                        }
+                    };
 
                     if should_refund_to_proposer {
                         deposit_info.get_return_deposit_message(&proposer)?
                     } else {
-                        // If the proposer doesn't get the deposit, the DAO does.
-                        let dao = self.dao.load(deps.storage)?;
-                        deposit_info.get_return_deposit_message(&dao)?
+                        self.get_forfeit_deposit_messages(
+                            deps.branch(),
+                            deposit_info,
+                            Some((&proposal_module, id)),
+                        )?
                     }
                 } else {
                     // No deposit info for this proposal. Nothing to do.
@@ -330,23 +392,256 @@ where
 
     pub fn check_can_submit(&self, deps: Deps, who: Addr) -> Result<(), PreProposeError> {
         let config = self.config.load(deps.storage)?;
+        let policy = self
+            .submission_policy
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+
+        if !policy.allows_proposer(&who) {
+            return Err(PreProposeError::NotAllowedProposer {});
+        }
 
-        if !config.open_proposal_submission {
+        if !config.open_proposal_submission || policy.min_voting_power.is_some() {
             let dao = self.dao.load(deps.storage)?;
             let voting_power: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
-                dao.into_string(),
+                dao.as_str(),
                 &CwCoreQuery::VotingPowerAtHeight {
                     address: who.into_string(),
                     height: None,
                 },
             )?;
-            if voting_power.power.is_zero() {
+            if !config.open_proposal_submission && voting_power.power.is_zero() {
                 return Err(PreProposeError::NotMember {});
             }
+
+            match policy.min_voting_power {
+                Some(MinVotingPower::Absolute { power }) => {
+                    if voting_power.power < power {
+                        return Err(PreProposeError::InsufficientVotingPower { required: power });
+                    }
+                }
+                Some(MinVotingPower::Percentage { percent }) => {
+                    let total_power: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+                        dao.as_str(),
+                        &CwCoreQuery::TotalPowerAtHeight { height: None },
+                    )?;
+                    if !does_vote_count_pass(
+                        voting_power.power,
+                        total_power.power,
+                        PercentageThreshold::Percent(percent),
+                    ) {
+                        return Err(PreProposeError::InsufficientVotingPower {
+                            required: total_power.power * percent,
+                        });
+                    }
+                }
+                None => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the messages needed to send a deposit that is not being
+    /// refunded to the configured forfeit destination. If
+    /// `proposal` is the proposal module and ID of the proposal the
+    /// deposit was paid for, deposits may be forfeited to its voters,
+    /// who claim their shares with `ClaimForfeitedDeposit`. Otherwise,
+    /// or if nobody voted, deposits that would be forfeited to voters
+    /// are sent to the DAO.
+    pub fn get_forfeit_deposit_messages(
+        &self,
+        deps: DepsMut,
+        deposit_info: &CheckedDepositInfo,
+        proposal: Option<(&Addr, u64)>,
+    ) -> Result<Vec<CosmosMsg>, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        let options = self
+            .deposit_options
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        Ok(match (options.forfeit_destination, proposal) {
+            (CheckedForfeitDestination::Dao, _) | (CheckedForfeitDestination::Voters, None) => {
+                deposit_info.get_return_deposit_message(&dao)?
+            }
+            (
+                CheckedForfeitDestination::Burn {
+                    native_burn_address,
+                },
+                _,
+            ) => deposit_info.get_burn_deposit_message(native_burn_address.as_ref())?,
+            (CheckedForfeitDestination::Voters, Some((proposal_module, id))) => {
+                // Voters claim their shares themselves so that the
+                // cost of completing a proposal does not grow with
+                // its number of voters.
+                let power_cast = query_power_cast(deps.querier, proposal_module, id)?;
+                if power_cast.is_zero() {
+                    deposit_info.get_return_deposit_message(&dao)?
+                } else {
+                    self.forfeited_deposits.save(
+                        deps.storage,
+                        id,
+                        &ForfeitedDeposit {
+                            deposit: deposit_info.clone(),
+                            power_cast,
+                            claimed: Uint128::zero(),
+                        },
+                    )?;
+                    vec![]
+                }
+            }
+        })
+    }
+
+    pub fn execute_claim_forfeited_deposit(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let mut forfeited = self
+            .forfeited_deposits
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::NoForfeitedDeposit { id })?;
+        if self
+            .forfeit_claims
+            .has(deps.storage, (id, info.sender.clone()))
+        {
+            return Err(PreProposeError::ForfeitAlreadyClaimed { id });
+        }
+
+        let proposal_module = self.proposal_module.load(deps.storage)?;
+        let power = query_vote_power(deps.querier, &proposal_module, id, &info.sender)?
+            .filter(|power| !power.is_zero())
+            .ok_or(PreProposeError::NotVoter { id })?;
+
+        // Shares are rounded down, and capped at what remains of the
+        // deposit. Amounts lost to rounding stay in this contract.
+        let share = forfeited
+            .deposit
+            .amount
+            .multiply_ratio(power, forfeited.power_cast)
+            .min(forfeited.deposit.amount - forfeited.claimed);
+        forfeited.claimed += share;
+        self.forfeited_deposits.save(deps.storage, id, &forfeited)?;
+        self.forfeit_claims
+            .save(deps.storage, (id, info.sender.clone()), &Empty {})?;
+
+        let messages = if share.is_zero() {
+            vec![]
+        } else {
+            vec![forfeited
+                .deposit
+                .denom
+                .get_transfer_to_message(&info.sender, share)?]
+        };
+
+        Ok(Response::default()
+            .add_attribute("method", "claim_forfeited_deposit")
+            .add_attribute("proposal", id.to_string())
+            .add_attribute("sender", info.sender)
+            .add_attribute("amount", share.to_string())
+            .add_messages(messages))
+    }
+
+    /// Checks that the deposit for a proposal that will execute
+    /// `msgs` has been paid, returning the deposit that was paid and
+    /// the messages needed to take it. The returned deposit is in the
+    /// token the sender paid in and should be snapshotted to refund
+    /// it once the proposal is completed.
+    pub fn take_deposit(
+        &self,
+        deps: Deps,
+        env: &Env,
+        info: &MessageInfo,
+        msgs: &[CosmosMsg],
+    ) -> Result<(Option<CheckedDepositInfo>, Vec<CosmosMsg>), PreProposeError> {
+        let config = self.config.load(deps.storage)?;
+        match config.deposit_info {
+            Some(ref deposit_info) => {
+                let options = self
+                    .deposit_options
+                    .may_load(deps.storage)?
+                    .unwrap_or_default();
+                let multiplier = options.get_multiplier(deps.querier, &info.sender, msgs)?;
+                let deposit_info = options.select_deposit(
+                    deps.querier,
+                    deposit_info,
+                    info,
+                    &env.contract.address,
+                    multiplier,
+                )?;
+                let messages =
+                    deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?;
+                Ok((Some(deposit_info), messages))
+            }
+            None => Ok((None, vec![])),
+        }
+    }
+
+    /// Enforces the submission policy's rate limit on `who` creating
+    /// a proposal, and records that `who` has created one. Must be
+    /// called whenever a proposal is created, along with
+    /// `record_completion` once the proposal is completed.
+    pub fn record_submission(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        who: &Addr,
+    ) -> Result<(), PreProposeError> {
+        let policy = self
+            .submission_policy
+            .may_load(storage)?
+            .unwrap_or_default();
+        let open_proposals = self
+            .open_proposals
+            .may_load(storage, who.clone())?
+            .unwrap_or_default();
+
+        match policy.rate_limit {
+            Some(SubmissionRateLimit::MaxOpenProposals { count }) => {
+                if open_proposals >= count {
+                    return Err(PreProposeError::TooManyOpenProposals { max: count });
+                }
+            }
+            Some(SubmissionRateLimit::OnePerPeriod { period }) => {
+                if let Some(next) = self.next_submission.may_load(storage, who.clone())? {
+                    if !next.is_expired(block) {
+                        return Err(PreProposeError::SubmissionRateLimited { next });
+                    }
+                }
+                self.next_submission
+                    .save(storage, who.clone(), &period.after(block))?;
+            }
+            None => (),
         }
+
+        self.open_proposals
+            .save(storage, who.clone(), &(open_proposals + 1))?;
         Ok(())
     }
 
+    /// Records that a proposal created by `who` is no longer open.
+    ///
+    /// Proposals that were already open when the contract was
+    /// migrated to a version that counts open proposals were never
+    /// counted. Completing one of them decrements its proposer's
+    /// count anyway, saturating at zero, so until those proposals are
+    /// completed their proposers may have more open proposals than
+    /// `MaxOpenProposals` allows.
+    pub fn record_completion(&self, storage: &mut dyn Storage, who: &Addr) -> StdResult<()> {
+        let open_proposals = self
+            .open_proposals
+            .may_load(storage, who.clone())?
+            .unwrap_or_default();
+        if open_proposals > 1 {
+            self.open_proposals
+                .save(storage, who.clone(), &(open_proposals - 1))
+        } else {
+            self.open_proposals.remove(storage, who.clone());
+            Ok(())
+        }
+    }
+
     pub fn query(&self, deps: Deps, _env: Env, msg: QueryMsg<QueryExt>) -> StdResult<Binary> {
         match msg {
             QueryMsg::ProposalModule {} => to_binary(&self.proposal_module.load(deps.storage)?),
@@ -359,9 +654,26 @@ where
                     proposer,
                 })
             }
+            QueryMsg::SubmissionPolicy {} => to_binary(
+                &self
+                    .submission_policy
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+            ),
+            QueryMsg::DepositOptions {} => to_binary(
+                &self
+                    .deposit_options
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+            ),
             QueryMsg::ProposalSubmittedHooks {} => {
                 to_binary(&self.proposal_submitted_hooks.query_hooks(deps)?)
             }
+            QueryMsg::ForfeitedDeposit { proposal_id } => to_binary(
+                &self
+                    .forfeited_deposits
+                    .may_load(deps.storage, proposal_id)?,
+            ),
             QueryMsg::QueryExtension { .. } => Ok(Binary::default()),
         }
     }
@@ -3,7 +3,9 @@
 pub mod error;
 pub mod execute;
 pub mod msg;
+pub mod proposal_module;
 pub mod state;
+pub mod submission;
 
 #[cfg(test)]
 mod tests;
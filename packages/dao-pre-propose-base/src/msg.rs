@@ -1,10 +1,13 @@
 use cosmwasm_schema::{cw_serde, schemars::JsonSchema, QueryResponses};
+use cosmwasm_std::{CosmosMsg, Empty};
 use cw_denom::UncheckedDenom;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, UncheckedDepositInfo},
+    deposit::{CheckedDepositInfo, UncheckedDepositInfo, UncheckedDepositOptions},
     status::Status,
 };
 
+use crate::submission::UncheckedSubmissionPolicy;
+
 #[cw_serde]
 pub struct InstantiateMsg<InstantiateExt> {
     /// Information about the deposit requirements for this
@@ -34,6 +37,16 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
         open_proposal_submission: bool,
     },
 
+    /// Updates the requirements addresses must meet to create
+    /// proposals, in addition to `open_proposal_submission`. Only
+    /// the DAO may execute this message.
+    UpdateSubmissionPolicy { policy: UncheckedSubmissionPolicy },
+
+    /// Updates the additional tokens deposits may be paid in and how
+    /// deposits are priced. Only applies if `deposit_info` is set in
+    /// the config. Only the DAO may execute this message.
+    UpdateDepositOptions { options: UncheckedDepositOptions },
+
     /// Withdraws funds inside of this contract to the message
     /// sender. The contracts entire balance for the specifed DENOM is
     /// withdrawn to the message sender. Only the DAO may call this
@@ -86,6 +99,11 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
         proposal_id: u64,
         new_status: Status,
     },
+
+    /// Claims the sender's share of a deposit forfeited to the voters
+    /// on a proposal. The share is proportional to the voting power
+    /// the sender voted with. Each voter may claim once.
+    ClaimForfeitedDeposit { proposal_id: u64 },
 }
 
 #[cw_serde]
@@ -109,9 +127,21 @@ where
     /// PROPOSAL_ID.
     #[returns(DepositInfoResponse)]
     DepositInfo { proposal_id: u64 },
+    /// Gets the requirements addresses must meet to create
+    /// proposals.
+    #[returns(crate::submission::SubmissionPolicy)]
+    SubmissionPolicy {},
+    /// Gets the additional tokens deposits may be paid in and how
+    /// deposits are priced.
+    #[returns(dao_voting::deposit::CheckedDepositOptions)]
+    DepositOptions {},
     /// Returns list of proposal submitted hooks.
     #[returns(cw_hooks::HooksResponse)]
     ProposalSubmittedHooks {},
+    /// Gets the deposit forfeited to the voters on the proposal
+    /// identified by PROPOSAL_ID, if any.
+    #[returns(Option<crate::state::ForfeitedDeposit>)]
+    ForfeitedDeposit { proposal_id: u64 },
     /// Extension for queries. The default implementation will do
     /// nothing if queried for will return `Binary::default()`.
     #[returns(cosmwasm_std::Binary)]
@@ -125,3 +155,19 @@ pub struct DepositInfoResponse {
     /// The address that created the proposal.
     pub proposer: cosmwasm_std::Addr,
 }
+
+/// Implemented by the proposal messages of pre-propose modules so
+/// that deposits may be priced on the messages a proposal will
+/// execute.
+pub trait ProposalMessages {
+    /// The messages the proposal will execute if it passes. For
+    /// proposals with multiple choices, this is the messages of all
+    /// choices.
+    fn cosmos_msgs(&self) -> Vec<CosmosMsg>;
+}
+
+impl ProposalMessages for Empty {
+    fn cosmos_msgs(&self) -> Vec<CosmosMsg> {
+        vec![]
+    }
+}
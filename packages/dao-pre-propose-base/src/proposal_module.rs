@@ -0,0 +1,154 @@
+//! Queries made to the proposal module when deciding what to do with
+//! a completed proposal's deposit. These are the subset of the
+//! `dao-proposal-single` and `dao-proposal-multiple` query messages,
+//! and responses, that this module relies on.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Timestamp, Uint128};
+use dao_voting::{threshold::PercentageThreshold, voting::does_vote_count_pass};
+use serde::Deserialize;
+
+#[cw_serde]
+enum ProposalModuleQuery {
+    Proposal {
+        proposal_id: u64,
+    },
+    GetVote {
+        proposal_id: u64,
+        voter: String,
+    },
+    EffectiveQuorum {
+        proposal_id: u64,
+        height: Option<u64>,
+        time: Option<Timestamp>,
+    },
+}
+
+// Responses are deserialized with `serde` directly, rather than
+// `cw_serde`, so that fields we do not use are ignored.
+
+#[derive(Deserialize)]
+struct ProposalResponse {
+    proposal: Proposal,
+}
+
+#[derive(Deserialize)]
+struct Proposal {
+    total_power: Uint128,
+    votes: ProposalVotes,
+    /// Only present on `dao-proposal-multiple` proposals.
+    #[serde(default)]
+    power_cast: Uint128,
+}
+
+/// The vote tally of either a `dao-proposal-single` proposal (`yes`,
+/// `no`, and `abstain`) or a `dao-proposal-multiple` proposal
+/// (`vote_weights`).
+#[derive(Deserialize)]
+struct ProposalVotes {
+    #[serde(default)]
+    yes: Uint128,
+    #[serde(default)]
+    no: Uint128,
+    #[serde(default)]
+    abstain: Uint128,
+    #[serde(default)]
+    vote_weights: Vec<Uint128>,
+}
+
+#[derive(Deserialize)]
+struct VoteResponse {
+    vote: Option<VoteInfo>,
+}
+
+#[derive(Deserialize)]
+struct VoteInfo {
+    power: Uint128,
+}
+
+impl Proposal {
+    /// The voting power that has been cast on the proposal. For
+    /// approval and score voting, where a vote may count towards
+    /// several options, this is `power_cast` rather than the sum of
+    /// the vote tally.
+    fn power_cast(&self) -> StdResult<Uint128> {
+        if !self.power_cast.is_zero() {
+            return Ok(self.power_cast);
+        }
+        let ProposalVotes {
+            yes,
+            no,
+            abstain,
+            vote_weights,
+        } = &self.votes;
+        vote_weights
+            .iter()
+            .chain([yes, no, abstain])
+            .try_fold(Uint128::zero(), |cast, power| cast.checked_add(*power))
+            .map_err(Into::into)
+    }
+}
+
+/// Returns the total voting power cast on a proposal.
+pub fn query_power_cast(
+    querier: QuerierWrapper,
+    proposal_module: &Addr,
+    proposal_id: u64,
+) -> StdResult<Uint128> {
+    let proposal: ProposalResponse = querier.query_wasm_smart(
+        proposal_module,
+        &ProposalModuleQuery::Proposal { proposal_id },
+    )?;
+    proposal.proposal.power_cast()
+}
+
+/// Returns the voting power `voter` voted on a proposal with, if they
+/// voted.
+pub fn query_vote_power(
+    querier: QuerierWrapper,
+    proposal_module: &Addr,
+    proposal_id: u64,
+    voter: &Addr,
+) -> StdResult<Option<Uint128>> {
+    let vote: VoteResponse = querier.query_wasm_smart(
+        proposal_module,
+        &ProposalModuleQuery::GetVote {
+            proposal_id,
+            voter: voter.to_string(),
+        },
+    )?;
+    Ok(vote.vote.map(|vote| vote.power))
+}
+
+/// Returns true if the voting power cast on a proposal meets its
+/// quorum. Proposals whose threshold has no quorum are considered to
+/// have reached it.
+pub fn query_reached_quorum(
+    querier: QuerierWrapper,
+    proposal_module: &Addr,
+    proposal_id: u64,
+) -> StdResult<bool> {
+    let quorum: Option<PercentageThreshold> = querier.query_wasm_smart(
+        proposal_module,
+        &ProposalModuleQuery::EffectiveQuorum {
+            proposal_id,
+            height: None,
+            time: None,
+        },
+    )?;
+    let quorum = match quorum {
+        Some(quorum) => quorum,
+        None => return Ok(true),
+    };
+
+    let proposal: ProposalResponse = querier.query_wasm_smart(
+        proposal_module,
+        &ProposalModuleQuery::Proposal { proposal_id },
+    )?;
+
+    Ok(does_vote_count_pass(
+        proposal.proposal.power_cast()?,
+        proposal.proposal.total_power,
+        quorum,
+    ))
+}
@@ -1,11 +1,14 @@
 use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 
-use dao_voting::deposit::CheckedDepositInfo;
+use dao_voting::deposit::{CheckedDepositInfo, CheckedDepositOptions};
+
+use crate::submission::SubmissionPolicy;
 
 #[cw_serde]
 pub struct Config {
@@ -18,6 +21,19 @@ pub struct Config {
     pub open_proposal_submission: bool,
 }
 
+/// A deposit forfeited to the voters on a proposal. Voters claim
+/// their share with `ClaimForfeitedDeposit`.
+#[cw_serde]
+pub struct ForfeitedDeposit {
+    /// The forfeited deposit. Voters are paid in its denom, in
+    /// proportion to their share of `power_cast`.
+    pub deposit: CheckedDepositInfo,
+    /// The total voting power cast on the proposal.
+    pub power_cast: Uint128,
+    /// The amount of the deposit that has been claimed.
+    pub claimed: Uint128,
+}
+
 pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> {
     /// The proposal module that this module is associated with.
     pub proposal_module: Item<'static, Addr>,
@@ -30,6 +46,27 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
     pub deposits: Map<'static, u64, (Option<CheckedDepositInfo>, Addr)>,
     /// Consumers of proposal submitted hooks.
     pub proposal_submitted_hooks: Hooks<'static>,
+    /// Requirements addresses must meet to create proposals. If
+    /// unset, the default (empty) policy applies.
+    pub submission_policy: Item<'static, SubmissionPolicy>,
+    /// The number of open proposals created by each address. Only
+    /// proposals created after this count was introduced are
+    /// counted; it is not backfilled for proposals that were already
+    /// open when the contract was migrated.
+    pub open_proposals: Map<'static, Addr, u64>,
+    /// When each address may next create a proposal, if limited to
+    /// one proposal per period.
+    pub next_submission: Map<'static, Addr, Expiration>,
+    /// Additional tokens deposits may be paid in and how deposits
+    /// are priced. If unset, deposits are paid as configured in
+    /// `deposit_info`.
+    pub deposit_options: Item<'static, CheckedDepositOptions>,
+    /// Deposits forfeited to the voters on a proposal, by proposal
+    /// ID.
+    pub forfeited_deposits: Map<'static, u64, ForfeitedDeposit>,
+    /// The voters that have claimed their share of a proposal's
+    /// forfeited deposit.
+    pub forfeit_claims: Map<'static, (u64, Addr), Empty>,
 
     // These types are used in associated functions, but not
     // assocaited data. To stop the compiler complaining about unused
@@ -43,12 +80,19 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
 impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
     PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
 {
+    #[allow(clippy::too_many_arguments)]
     const fn new(
         proposal_key: &'static str,
         dao_key: &'static str,
         config_key: &'static str,
         deposits_key: &'static str,
         proposal_submitted_hooks_key: &'static str,
+        submission_policy_key: &'static str,
+        open_proposals_key: &'static str,
+        next_submission_key: &'static str,
+        deposit_options_key: &'static str,
+        forfeited_deposits_key: &'static str,
+        forfeit_claims_key: &'static str,
     ) -> Self {
         Self {
             proposal_module: Item::new(proposal_key),
@@ -56,6 +100,12 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
             config: Item::new(config_key),
             deposits: Map::new(deposits_key),
             proposal_submitted_hooks: Hooks::new(proposal_submitted_hooks_key),
+            submission_policy: Item::new(submission_policy_key),
+            open_proposals: Map::new(open_proposals_key),
+            next_submission: Map::new(next_submission_key),
+            deposit_options: Item::new(deposit_options_key),
+            forfeited_deposits: Map::new(forfeited_deposits_key),
+            forfeit_claims: Map::new(forfeit_claims_key),
             execute_type: PhantomData,
             instantiate_type: PhantomData,
             query_type: PhantomData,
@@ -77,6 +127,12 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> Default
             "config",
             "deposits",
             "proposal_submitted_hooks",
+            "submission_policy",
+            "open_proposals",
+            "next_submission",
+            "deposit_options",
+            "forfeited_deposits",
+            "forfeit_claims",
         )
     }
 }
@@ -0,0 +1,104 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Deps, Uint128};
+use cw_utils::Duration;
+
+use crate::error::PreProposeError;
+
+/// The minimum voting power an address must have to create a
+/// proposal.
+#[cw_serde]
+pub enum MinVotingPower {
+    /// An absolute amount of voting power.
+    Absolute { power: Uint128 },
+    /// A percentage of the DAO's total voting power.
+    Percentage { percent: Decimal },
+}
+
+/// Limits how often an address may create proposals.
+#[cw_serde]
+pub enum SubmissionRateLimit {
+    /// An address may have at most `count` proposals open at
+    /// once. A proposal is open until it is closed, executed, or
+    /// vetoed. Proposals that were open when the contract was
+    /// migrated to a version supporting this limit are not counted.
+    MaxOpenProposals { count: u64 },
+    /// An address may create at most one proposal per `period`.
+    OnePerPeriod { period: Duration },
+}
+
+/// Requirements, in addition to `open_proposal_submission`, that an
+/// address must meet to create a proposal.
+#[cw_serde]
+#[derive(Default)]
+pub struct UncheckedSubmissionPolicy {
+    /// If set, the minimum voting power needed to create a
+    /// proposal.
+    pub min_voting_power: Option<MinVotingPower>,
+    /// If set, only these addresses may create proposals.
+    pub allowlist: Option<Vec<String>>,
+    /// Addresses that may not create proposals.
+    #[serde(default)]
+    pub denylist: Vec<String>,
+    /// If set, limits how often an address may create proposals.
+    pub rate_limit: Option<SubmissionRateLimit>,
+}
+
+/// A validated `UncheckedSubmissionPolicy`.
+#[cw_serde]
+#[derive(Default)]
+pub struct SubmissionPolicy {
+    /// If set, the minimum voting power needed to create a
+    /// proposal.
+    pub min_voting_power: Option<MinVotingPower>,
+    /// If set, only these addresses may create proposals.
+    pub allowlist: Option<Vec<Addr>>,
+    /// Addresses that may not create proposals.
+    pub denylist: Vec<Addr>,
+    /// If set, limits how often an address may create proposals.
+    pub rate_limit: Option<SubmissionRateLimit>,
+}
+
+impl UncheckedSubmissionPolicy {
+    pub fn into_checked(self, deps: Deps) -> Result<SubmissionPolicy, PreProposeError> {
+        match self.min_voting_power {
+            Some(MinVotingPower::Percentage { percent })
+                if percent.is_zero() || percent > Decimal::one() =>
+            {
+                return Err(PreProposeError::InvalidMinVotingPower {});
+            }
+            _ => (),
+        }
+        match self.rate_limit {
+            Some(SubmissionRateLimit::MaxOpenProposals { count: 0 })
+            | Some(SubmissionRateLimit::OnePerPeriod {
+                period: Duration::Height(0) | Duration::Time(0),
+            }) => return Err(PreProposeError::ZeroRateLimit {}),
+            _ => (),
+        }
+
+        let validate = |addrs: Vec<String>| {
+            addrs
+                .into_iter()
+                .map(|addr| deps.api.addr_validate(&addr))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(SubmissionPolicy {
+            min_voting_power: self.min_voting_power,
+            allowlist: self.allowlist.map(validate).transpose()?,
+            denylist: validate(self.denylist)?,
+            rate_limit: self.rate_limit,
+        })
+    }
+}
+
+impl SubmissionPolicy {
+    /// Returns true if `who` is not excluded from creating proposals
+    /// by the allowlist or denylist.
+    pub fn allows_proposer(&self, who: &Addr) -> bool {
+        !self.denylist.contains(who)
+            && self
+                .allowlist
+                .as_ref()
+                .map_or(true, |allowlist| allowlist.contains(who))
+    }
+}
@@ -4,12 +4,14 @@ use cosmwasm_std::{
     to_binary, Addr, Binary, ContractResult, Empty, Response, SubMsg, WasmMsg,
 };
 use cw_hooks::HooksResponse;
+use cw_utils::{Duration, Expiration};
 use dao_voting::status::Status;
 
 use crate::{
     error::PreProposeError,
     msg::{ExecuteMsg, QueryMsg},
     state::{Config, PreProposeContract},
+    submission::{SubmissionPolicy, SubmissionRateLimit, UncheckedSubmissionPolicy},
 };
 
 type Contract = PreProposeContract<Empty, Empty, Empty, Empty>;
@@ -200,3 +202,133 @@ fn test_execute_ext_does_nothing() {
         .unwrap();
     assert_eq!(res, Response::default())
 }
+
+#[test]
+fn test_update_submission_policy() {
+    let mut deps = mock_dependencies();
+    let module = Contract::default();
+
+    module
+        .dao
+        .save(&mut deps.storage, &Addr::unchecked("d"))
+        .unwrap();
+
+    let policy = UncheckedSubmissionPolicy {
+        denylist: vec!["spammer".to_string()],
+        ..Default::default()
+    };
+
+    // Only the DAO may update the submission policy.
+    let err = module
+        .execute_update_submission_policy(deps.as_mut(), mock_info("evil", &[]), policy.clone())
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    // Rate limits may not be zero.
+    let err = module
+        .execute_update_submission_policy(
+            deps.as_mut(),
+            mock_info("d", &[]),
+            UncheckedSubmissionPolicy {
+                rate_limit: Some(SubmissionRateLimit::MaxOpenProposals { count: 0 }),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::ZeroRateLimit {});
+
+    module
+        .execute_update_submission_policy(deps.as_mut(), mock_info("d", &[]), policy)
+        .unwrap();
+    let policy: SubmissionPolicy = from_binary(
+        &module
+            .query(deps.as_ref(), mock_env(), QueryMsg::SubmissionPolicy {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert!(!policy.allows_proposer(&Addr::unchecked("spammer")));
+    assert!(policy.allows_proposer(&Addr::unchecked("ekez")));
+
+    // A denylisted address may not submit even if allowlisted.
+    let policy = SubmissionPolicy {
+        allowlist: Some(vec![Addr::unchecked("ekez"), Addr::unchecked("spammer")]),
+        ..policy
+    };
+    assert!(!policy.allows_proposer(&Addr::unchecked("spammer")));
+    assert!(policy.allows_proposer(&Addr::unchecked("ekez")));
+    assert!(!policy.allows_proposer(&Addr::unchecked("other")));
+}
+
+#[test]
+fn test_submission_rate_limits() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let module = Contract::default();
+    let ekez = Addr::unchecked("ekez");
+
+    module
+        .submission_policy
+        .save(
+            &mut deps.storage,
+            &SubmissionPolicy {
+                rate_limit: Some(SubmissionRateLimit::MaxOpenProposals { count: 1 }),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    module
+        .record_submission(&mut deps.storage, &env.block, &ekez)
+        .unwrap();
+    let err = module
+        .record_submission(&mut deps.storage, &env.block, &ekez)
+        .unwrap_err();
+    assert_eq!(err, PreProposeError::TooManyOpenProposals { max: 1 });
+
+    // Once the open proposal is completed another may be created.
+    module.record_completion(&mut deps.storage, &ekez).unwrap();
+    module
+        .record_submission(&mut deps.storage, &env.block, &ekez)
+        .unwrap();
+    module.record_completion(&mut deps.storage, &ekez).unwrap();
+
+    // Completing a proposal that was never counted, such as one
+    // created before migrating, saturates at zero.
+    module.record_completion(&mut deps.storage, &ekez).unwrap();
+    assert_eq!(
+        module
+            .open_proposals
+            .may_load(&deps.storage, ekez.clone())
+            .unwrap(),
+        None
+    );
+
+    module
+        .submission_policy
+        .save(
+            &mut deps.storage,
+            &SubmissionPolicy {
+                rate_limit: Some(SubmissionRateLimit::OnePerPeriod {
+                    period: Duration::Height(10),
+                }),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    module
+        .record_submission(&mut deps.storage, &env.block, &ekez)
+        .unwrap();
+    env.block.height += 9;
+    let err = module
+        .record_submission(&mut deps.storage, &env.block, &ekez)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::SubmissionRateLimited {
+            next: Expiration::AtHeight(env.block.height + 1)
+        }
+    );
+    env.block.height += 1;
+    module
+        .record_submission(&mut deps.storage, &env.block, &ekez)
+        .unwrap();
+}
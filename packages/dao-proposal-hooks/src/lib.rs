@@ -16,6 +16,10 @@ pub enum ProposalHookMsg {
         old_status: String,
         new_status: String,
     },
+    ProposalAmended {
+        id: u64,
+        revision: u64,
+    },
 }
 
 // This is just a helper to properly serialize the above message
@@ -92,3 +96,31 @@ pub fn proposal_status_changed_hooks(
 
     Ok(messages)
 }
+
+/// Prepares proposal amended hook messages. These messages reply on
+/// error and have even reply IDs.
+/// IDs are set to even numbers to then be interleaved with the vote hooks.
+pub fn proposal_amended_hooks(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    id: u64,
+    revision: u64,
+) -> StdResult<Vec<SubMsg>> {
+    let msg = to_binary(&ProposalHookExecuteMsg::ProposalHook(
+        ProposalHookMsg::ProposalAmended { id, revision },
+    ))?;
+    let mut index: u64 = 0;
+    let messages = hooks.prepare_hooks(storage, |a| {
+        let execute = WasmMsg::Execute {
+            contract_addr: a.to_string(),
+            msg: msg.clone(),
+            funds: vec![],
+        };
+        let masked_index = mask_proposal_hook_index(index);
+        let tmp = SubMsg::reply_on_error(execute, masked_index);
+        index += 1;
+        Ok(tmp)
+    })?;
+
+    Ok(messages)
+}
@@ -183,6 +183,7 @@ where
             Threshold::ThresholdQuorum {
                 threshold: PercentageThreshold::Percent(Decimal::percent(100)),
                 quorum: PercentageThreshold::Percent(Decimal::percent(i)),
+                quorum_decay: None,
             },
             Status::Rejected,
             None,
@@ -396,6 +397,7 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(10)),
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -426,6 +428,7 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(10)),
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -454,6 +457,7 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(50)),
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Passed,
         None,
@@ -478,6 +482,7 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Majority {},
             quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
         },
         Status::Rejected,
         None,
@@ -498,6 +503,7 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Majority {},
             quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+            quorum_decay: None,
         },
         Status::Open,
         Some(Uint128::new(100)),
@@ -512,6 +518,7 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Majority {},
             quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+            quorum_decay: None,
         },
         // As the threshold is 50% and 59% of voters have voted no
         // this is unable to pass.
@@ -534,6 +541,7 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Majority {},
             quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+            quorum_decay: None,
         },
         Status::Passed,
         Some(Uint128::new(100)),
@@ -563,6 +571,7 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Majority {},
             quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+            quorum_decay: None,
         },
         Status::Passed,
         Some(Uint128::new(100)),
@@ -577,12 +586,108 @@ where
         Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Majority {},
             quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+            quorum_decay: None,
         },
         Status::Rejected,
         Some(Uint128::new(100)),
     );
 }
 
+pub fn test_optimistic_votes<F>(do_votes: F)
+where
+    F: Fn(Vec<TestSingleChoiceVote>, Threshold, Status, Option<Uint128>),
+{
+    // Objecting with all of the voting power rejects the proposal
+    // before it expires.
+    do_votes(
+        vec![TestSingleChoiceVote {
+            voter: "ekez".to_string(),
+            position: Vote::No,
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        Threshold::Optimistic {
+            objection: PercentageThreshold::Percent(Decimal::percent(20)),
+        },
+        Status::Rejected,
+        None,
+    );
+    // Yes votes do not pass an optimistic proposal while the
+    // outstanding voting power could still object.
+    do_votes(
+        vec![TestSingleChoiceVote {
+            voter: "ekez".to_string(),
+            position: Vote::Yes,
+            weight: Uint128::new(50),
+            should_execute: ShouldExecute::Yes,
+        }],
+        Threshold::Optimistic {
+            objection: PercentageThreshold::Majority {},
+        },
+        Status::Open,
+        Some(Uint128::new(100)),
+    );
+    // Once the outstanding voting power can no longer object the
+    // proposal passes.
+    do_votes(
+        vec![TestSingleChoiceVote {
+            voter: "ekez".to_string(),
+            position: Vote::Yes,
+            weight: Uint128::new(51),
+            should_execute: ShouldExecute::Yes,
+        }],
+        Threshold::Optimistic {
+            objection: PercentageThreshold::Majority {},
+        },
+        Status::Passed,
+        Some(Uint128::new(100)),
+    );
+    // Objections below the threshold do not reject the proposal, and
+    // abstaining counts towards neither outcome.
+    do_votes(
+        vec![
+            TestSingleChoiceVote {
+                voter: "ekez".to_string(),
+                position: Vote::No,
+                weight: Uint128::new(19),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestSingleChoiceVote {
+                voter: "keze".to_string(),
+                position: Vote::Abstain,
+                weight: Uint128::new(80),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        Threshold::Optimistic {
+            objection: PercentageThreshold::Percent(Decimal::percent(20)),
+        },
+        Status::Open,
+        Some(Uint128::new(100)),
+    );
+    do_votes(
+        vec![
+            TestSingleChoiceVote {
+                voter: "ekez".to_string(),
+                position: Vote::No,
+                weight: Uint128::new(19),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestSingleChoiceVote {
+                voter: "keze".to_string(),
+                position: Vote::Abstain,
+                weight: Uint128::new(81),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        Threshold::Optimistic {
+            objection: PercentageThreshold::Percent(Decimal::percent(20)),
+        },
+        Status::Passed,
+        None,
+    );
+}
+
 pub fn fuzz_voting<F>(do_votes: F)
 where
     F: Fn(Vec<TestSingleChoiceVote>, Threshold, Status, Option<Uint128>),
@@ -0,0 +1,88 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+
+use crate::voting::get_voting_power;
+
+/// The number of delegators loaded per query when summing the voting
+/// power delegated to a delegate.
+const DELEGATORS_PAGE_SIZE: u32 = 30;
+
+/// Queries that a vote delegation contract must respond to in order
+/// to be used by a proposal module. Delegation is snapshotted by
+/// height so that it can be evaluated at a proposal's start height.
+#[cw_serde]
+pub enum Query {
+    /// Returns the registered delegate that `delegator` had
+    /// delegated their voting power to at `height`, if any. Returns
+    /// `Option<Addr>`.
+    Delegation {
+        delegator: String,
+        height: Option<u64>,
+    },
+    /// Returns the addresses that had delegated their voting power
+    /// to `delegate` at `height`. Returns `Vec<Addr>`.
+    Delegators {
+        delegate: String,
+        height: Option<u64>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+/// Gets the delegate that `delegator` had delegated to at `height`.
+pub fn get_delegate(
+    deps: Deps,
+    delegation_module: &Addr,
+    delegator: &Addr,
+    height: u64,
+) -> StdResult<Option<Addr>> {
+    deps.querier.query_wasm_smart(
+        delegation_module,
+        &Query::Delegation {
+            delegator: delegator.to_string(),
+            height: Some(height),
+        },
+    )
+}
+
+/// Gets the voting power delegated to `delegate` at `height`,
+/// skipping delegators for which `overridden` returns true. Proposal
+/// modules use this to skip delegators that have cast their own vote
+/// on a proposal.
+pub fn get_delegated_voting_power(
+    deps: Deps,
+    delegation_module: &Addr,
+    dao: &Addr,
+    delegate: &Addr,
+    height: u64,
+    overridden: impl Fn(&Addr) -> StdResult<bool>,
+) -> StdResult<Uint128> {
+    let mut power = Uint128::zero();
+    let mut start_after: Option<String> = None;
+    loop {
+        let delegators: Vec<Addr> = deps.querier.query_wasm_smart(
+            delegation_module,
+            &Query::Delegators {
+                delegate: delegate.to_string(),
+                height: Some(height),
+                start_after: start_after.clone(),
+                limit: Some(DELEGATORS_PAGE_SIZE),
+            },
+        )?;
+        for delegator in delegators.iter() {
+            if !overridden(delegator)? {
+                power = power.checked_add(get_voting_power(
+                    deps,
+                    delegator.clone(),
+                    dao,
+                    Some(height),
+                )?)?;
+            }
+        }
+        match delegators.last() {
+            Some(last) => start_after = Some(last.to_string()),
+            None => break,
+        }
+    }
+    Ok(power)
+}
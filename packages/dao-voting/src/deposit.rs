@@ -1,6 +1,7 @@
-use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
-    to_binary, Addr, CosmosMsg, Deps, MessageInfo, StdError, StdResult, Uint128, WasmMsg,
+    to_binary, Addr, BankMsg, CosmosMsg, Decimal, Deps, MessageInfo, QuerierWrapper, StdError,
+    StdResult, Uint128, WasmMsg,
 };
 use cw_utils::{must_pay, PaymentError};
 
@@ -25,6 +26,15 @@ pub enum DepositError {
 
     #[error("invalid deposit amount. got ({actual}), expected ({expected})")]
     InvalidDeposit { actual: Uint128, expected: Uint128 },
+
+    #[error("deposit multipliers must be at least one. got ({multiplier})")]
+    InvalidDepositMultiplier { multiplier: Decimal },
+
+    #[error("deposit pricing tiers must not be empty")]
+    NoDepositTiers,
+
+    #[error("deposits may not be paid in ({denom})")]
+    UnacceptedDepositDenom { denom: String },
 }
 
 /// Information about the token to use for proposal deposits.
@@ -61,6 +71,10 @@ pub enum DepositRefundPolicy {
     OnlyPassed,
     /// Deposits should never be refunded.
     Never,
+    /// Deposits should only be refunded for proposals that reached
+    /// quorum, or were executed. Proposals whose threshold has no
+    /// quorum are treated as having reached it.
+    OnlyQuorum,
 }
 
 /// Counterpart to the `DepositInfo` struct which has been
@@ -96,7 +110,20 @@ impl UncheckedDepositInfo {
             return Err(DepositError::ZeroDeposit);
         }
 
-        let denom = match denom {
+        let denom = denom.into_checked(deps, dao)?;
+
+        Ok(CheckedDepositInfo {
+            denom,
+            amount,
+            refund_policy,
+        })
+    }
+}
+
+impl DepositToken {
+    /// Converts a deposit token into the denom it refers to.
+    pub fn into_checked(self, deps: Deps, dao: Addr) -> Result<CheckedDenom, DepositError> {
+        Ok(match self {
             DepositToken::Token { denom } => denom.into_checked(deps),
             DepositToken::VotingModuleToken {} => {
                 let voting_module: Addr = deps
@@ -114,13 +141,7 @@ impl UncheckedDepositInfo {
                 // query.
                 UncheckedDenom::Cw20(token_addr.into_string()).into_checked(deps)
             }
-        }?;
-
-        Ok(CheckedDepositInfo {
-            denom,
-            amount,
-            refund_policy,
-        })
+        }?)
     }
 }
 
@@ -194,11 +215,364 @@ impl CheckedDepositInfo {
         let message = self.denom.get_transfer_to_message(depositor, self.amount)?;
         Ok(vec![message])
     }
+
+    /// Gets the messages needed to burn the deposit. Native deposits
+    /// are sent to `native_burn_address` if one is provided.
+    pub fn get_burn_deposit_message(
+        &self,
+        native_burn_address: Option<&Addr>,
+    ) -> StdResult<Vec<CosmosMsg>> {
+        if self.amount.is_zero() {
+            return Ok(vec![]);
+        }
+        let message = match (&self.denom, native_burn_address) {
+            (CheckedDenom::Native(_), Some(burn_address)) => self
+                .denom
+                .get_transfer_to_message(burn_address, self.amount)?,
+            (CheckedDenom::Native(denom), None) => BankMsg::Burn {
+                amount: vec![cosmwasm_std::coin(self.amount.u128(), denom)],
+            }
+            .into(),
+            (CheckedDenom::Cw20(address), _) => WasmMsg::Execute {
+                contract_addr: address.to_string(),
+                msg: to_binary(&cw20::Cw20ExecuteMsg::Burn {
+                    amount: self.amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        };
+        Ok(vec![message])
+    }
+}
+
+/// Where deposits that are not refunded to their proposer are sent.
+#[cw_serde]
+#[derive(Default)]
+pub enum UncheckedForfeitDestination {
+    /// Forfeited deposits are sent to the DAO.
+    #[default]
+    Dao,
+    /// Forfeited deposits are burned. cw20 deposits are burned with
+    /// `Cw20ExecuteMsg::Burn`. Native deposits are sent to
+    /// `native_burn_address` if set, and burned with `BankMsg::Burn`
+    /// otherwise.
+    Burn { native_burn_address: Option<String> },
+    /// Forfeited deposits are split among the addresses that voted on
+    /// the proposal in proportion to their voting power. Voters claim
+    /// their share from the pre-propose module with
+    /// `ClaimForfeitedDeposit`. Amounts lost to rounding stay in the
+    /// pre-propose module, and deposits for proposals with no votes
+    /// are sent to the DAO.
+    Voters,
+}
+
+/// Counterpart to `UncheckedForfeitDestination` which has been
+/// processed.
+#[cw_serde]
+#[derive(Default)]
+pub enum CheckedForfeitDestination {
+    #[default]
+    Dao,
+    Burn {
+        native_burn_address: Option<Addr>,
+    },
+    Voters,
+}
+
+/// A token, in addition to the one in a module's deposit info, that
+/// proposal deposits may be paid in.
+#[cw_serde]
+pub struct UncheckedDepositOption {
+    /// The token the deposit may be paid in.
+    pub denom: DepositToken,
+    /// The number of tokens that must be deposited when paying in
+    /// this token. Must be a positive, non-zero number.
+    pub amount: Uint128,
+}
+
+/// Counterpart to `UncheckedDepositOption` which has been processed.
+#[cw_serde]
+pub struct CheckedDepositOption {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+}
+
+/// A multiplier applied to the deposit for proposals which send at
+/// least `min_sent` tokens.
+#[cw_serde]
+pub struct DepositTier {
+    pub min_sent: Uint128,
+    /// Must be at least one.
+    pub multiplier: Decimal,
+}
+
+/// A rule for scaling the required deposit based on the proposal
+/// being created.
+#[cw_serde]
+pub enum UncheckedDepositPricing {
+    /// The deposit is multiplied by the multiplier of the largest
+    /// tier whose `min_sent` is less than or equal to the total
+    /// amount of native `denom` sent by the proposal's messages.
+    ///
+    /// Only tokens sent by `BankMsg::Send` messages and attached as
+    /// `funds` to `WasmMsg::Execute` and `WasmMsg::Instantiate`
+    /// messages are counted. Tokens moved in other ways, for example
+    /// by staking, IBC and Stargate messages, cw20 transfers, or
+    /// messages nested inside other messages, are not. DAOs that
+    /// need to price those should use `Contract`.
+    BankSendTiers {
+        denom: String,
+        tiers: Vec<DepositTier>,
+    },
+    /// The deposit is multiplied by the result of a
+    /// `DepositPricingQuery::DepositMultiplier` query to `address`.
+    Contract { address: String },
+}
+
+/// Counterpart to `UncheckedDepositPricing` which has been processed.
+#[cw_serde]
+pub enum CheckedDepositPricing {
+    BankSendTiers {
+        denom: String,
+        tiers: Vec<DepositTier>,
+    },
+    Contract {
+        address: Addr,
+    },
+}
+
+/// The query that contracts used for
+/// `UncheckedDepositPricing::Contract` must implement.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum DepositPricingQuery {
+    /// Returns the multiplier to apply to the deposit for a proposal
+    /// created by `proposer` that will execute `msgs`. Must be at
+    /// least one.
+    #[returns(Decimal)]
+    DepositMultiplier {
+        proposer: String,
+        msgs: Vec<CosmosMsg>,
+    },
+}
+
+/// Additional ways proposal deposits may be paid, and how they are
+/// priced.
+#[cw_serde]
+#[derive(Default)]
+pub struct UncheckedDepositOptions {
+    /// Tokens, in addition to the deposit info's, that deposits may
+    /// be paid in.
+    #[serde(default)]
+    pub alternatives: Vec<UncheckedDepositOption>,
+    /// If set, scales the required deposit based on the proposal
+    /// being created. Applies to all tokens deposits may be paid in.
+    pub pricing: Option<UncheckedDepositPricing>,
+    /// Where deposits that are not refunded are sent. Defaults to
+    /// the DAO.
+    #[serde(default)]
+    pub forfeit_destination: UncheckedForfeitDestination,
+}
+
+/// Counterpart to `UncheckedDepositOptions` which has been processed.
+#[cw_serde]
+#[derive(Default)]
+pub struct CheckedDepositOptions {
+    pub alternatives: Vec<CheckedDepositOption>,
+    pub pricing: Option<CheckedDepositPricing>,
+    pub forfeit_destination: CheckedForfeitDestination,
+}
+
+impl UncheckedDepositOptions {
+    /// Converts deposit options into checked deposit options.
+    pub fn into_checked(
+        self,
+        deps: Deps,
+        dao: Addr,
+    ) -> Result<CheckedDepositOptions, DepositError> {
+        let alternatives = self
+            .alternatives
+            .into_iter()
+            .map(|UncheckedDepositOption { denom, amount }| {
+                if amount.is_zero() {
+                    return Err(DepositError::ZeroDeposit);
+                }
+                Ok(CheckedDepositOption {
+                    denom: denom.into_checked(deps, dao.clone())?,
+                    amount,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let pricing = match self.pricing {
+            Some(UncheckedDepositPricing::BankSendTiers { denom, tiers }) => {
+                if tiers.is_empty() {
+                    return Err(DepositError::NoDepositTiers);
+                }
+                if let Some(tier) = tiers.iter().find(|t| t.multiplier < Decimal::one()) {
+                    return Err(DepositError::InvalidDepositMultiplier {
+                        multiplier: tier.multiplier,
+                    });
+                }
+                Some(CheckedDepositPricing::BankSendTiers { denom, tiers })
+            }
+            Some(UncheckedDepositPricing::Contract { address }) => {
+                Some(CheckedDepositPricing::Contract {
+                    address: deps.api.addr_validate(&address)?,
+                })
+            }
+            None => None,
+        };
+
+        let forfeit_destination = match self.forfeit_destination {
+            UncheckedForfeitDestination::Dao => CheckedForfeitDestination::Dao,
+            UncheckedForfeitDestination::Burn {
+                native_burn_address,
+            } => CheckedForfeitDestination::Burn {
+                native_burn_address: native_burn_address
+                    .map(|addr| deps.api.addr_validate(&addr))
+                    .transpose()?,
+            },
+            UncheckedForfeitDestination::Voters => CheckedForfeitDestination::Voters,
+        };
+
+        Ok(CheckedDepositOptions {
+            alternatives,
+            pricing,
+            forfeit_destination,
+        })
+    }
+}
+
+impl CheckedDepositOptions {
+    /// Gets the multiplier to apply to deposits for a proposal
+    /// created by `proposer` that will execute `msgs`.
+    pub fn get_multiplier(
+        &self,
+        querier: QuerierWrapper,
+        proposer: &Addr,
+        msgs: &[CosmosMsg],
+    ) -> Result<Decimal, DepositError> {
+        match &self.pricing {
+            None => Ok(Decimal::one()),
+            Some(CheckedDepositPricing::BankSendTiers { denom, tiers }) => {
+                let sent = msgs
+                    .iter()
+                    .filter_map(|msg| match msg {
+                        CosmosMsg::Bank(BankMsg::Send { amount, .. }) => Some(amount),
+                        CosmosMsg::Wasm(WasmMsg::Execute { funds, .. })
+                        | CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => Some(funds),
+                        _ => None,
+                    })
+                    .flatten()
+                    .filter(|coin| coin.denom == *denom)
+                    .try_fold(Uint128::zero(), |sent, coin| sent.checked_add(coin.amount))
+                    .map_err(StdError::from)?;
+                Ok(tiers
+                    .iter()
+                    .filter(|tier| tier.min_sent <= sent)
+                    .max_by_key(|tier| tier.min_sent)
+                    .map_or(Decimal::one(), |tier| tier.multiplier))
+            }
+            Some(CheckedDepositPricing::Contract { address }) => {
+                let multiplier: Decimal = querier.query_wasm_smart(
+                    address,
+                    &DepositPricingQuery::DepositMultiplier {
+                        proposer: proposer.to_string(),
+                        msgs: msgs.to_vec(),
+                    },
+                )?;
+                if multiplier < Decimal::one() {
+                    Err(DepositError::InvalidDepositMultiplier { multiplier })
+                } else {
+                    Ok(multiplier)
+                }
+            }
+        }
+    }
+
+    /// Selects which of `deposit_info` and the alternatives the
+    /// sender of `info` is paying their deposit in, scaling the amount
+    /// by `multiplier`. Native deposits are selected by the denom sent
+    /// with `info`. If no funds are sent, the first cw20 option that
+    /// the sender has approved `contract` to spend is selected, or the
+    /// first cw20 option if there is no such option. Calls that send
+    /// more than one coin are rejected.
+    ///
+    /// The returned deposit info has the denom and amount the deposit
+    /// was paid in and `deposit_info`'s refund policy, and should be
+    /// used to take and return the deposit.
+    pub fn select_deposit(
+        &self,
+        querier: QuerierWrapper,
+        deposit_info: &CheckedDepositInfo,
+        info: &MessageInfo,
+        contract: &Addr,
+        multiplier: Decimal,
+    ) -> Result<CheckedDepositInfo, DepositError> {
+        if info.funds.len() > 1 {
+            return Err(PaymentError::MultipleDenoms {}.into());
+        }
+
+        let options = std::iter::once((&deposit_info.denom, deposit_info.amount))
+            .chain(self.alternatives.iter().map(|o| (&o.denom, o.amount)))
+            .map(|(denom, amount)| CheckedDepositInfo {
+                denom: denom.clone(),
+                amount: amount * multiplier,
+                refund_policy: deposit_info.refund_policy.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let selected = if options.len() == 1 {
+            options.into_iter().next()
+        } else if let Some(coin) = info.funds.first() {
+            let selected = options
+                .into_iter()
+                .find(|o| matches!(&o.denom, CheckedDenom::Native(d) if *d == coin.denom))
+                .ok_or_else(|| DepositError::UnacceptedDepositDenom {
+                    denom: coin.denom.clone(),
+                })?;
+            Some(selected)
+        } else {
+            let cw20s = options
+                .into_iter()
+                .filter(|o| matches!(o.denom, CheckedDenom::Cw20(_)))
+                .collect::<Vec<_>>();
+            let mut approved = None;
+            for option in cw20s.iter() {
+                if let CheckedDenom::Cw20(token) = &option.denom {
+                    let allowance: cw20::AllowanceResponse = querier.query_wasm_smart(
+                        token,
+                        &cw20::Cw20QueryMsg::Allowance {
+                            owner: info.sender.to_string(),
+                            spender: contract.to_string(),
+                        },
+                    )?;
+                    if allowance.allowance >= option.amount {
+                        approved = Some(option.clone());
+                        break;
+                    }
+                }
+            }
+            approved.or_else(|| cw20s.into_iter().next())
+        };
+
+        // If nothing was selected, no funds were sent and there are
+        // no cw20 options.
+        let selected = selected.ok_or(PaymentError::NoFunds {})?;
+        selected.check_native_deposit_paid(info)?;
+        Ok(selected)
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use cosmwasm_std::{coin, coins, testing::mock_info, BankMsg};
+    use cosmwasm_std::{
+        coin, coins,
+        testing::{mock_dependencies, mock_info},
+        BankMsg,
+    };
 
     use super::*;
 
@@ -387,4 +761,224 @@ pub mod tests {
             .unwrap();
         assert_eq!(messages, vec![]);
     }
+
+    #[test]
+    fn test_bank_send_tier_multiplier() {
+        let deps = mock_dependencies();
+        let options = CheckedDepositOptions {
+            alternatives: vec![],
+            pricing: Some(CheckedDepositPricing::BankSendTiers {
+                denom: NATIVE_DENOM.to_string(),
+                tiers: vec![
+                    DepositTier {
+                        min_sent: Uint128::new(100),
+                        multiplier: Decimal::percent(200),
+                    },
+                    DepositTier {
+                        min_sent: Uint128::new(1000),
+                        multiplier: Decimal::percent(500),
+                    },
+                ],
+            }),
+            ..Default::default()
+        };
+        let send = |amount: Vec<cosmwasm_std::Coin>| -> CosmosMsg {
+            BankMsg::Send {
+                to_address: "ekez".to_string(),
+                amount,
+            }
+            .into()
+        };
+        let multiplier = |msgs: &[CosmosMsg]| {
+            options
+                .get_multiplier(deps.as_ref().querier, &Addr::unchecked("ekez"), msgs)
+                .unwrap()
+        };
+
+        assert_eq!(multiplier(&[]), Decimal::one());
+        assert_eq!(multiplier(&[send(coins(99, NATIVE_DENOM))]), Decimal::one());
+        // Other denoms are not counted.
+        assert_eq!(multiplier(&[send(coins(1000, "uother"))]), Decimal::one());
+        assert_eq!(
+            multiplier(&[send(coins(50, NATIVE_DENOM)), send(coins(50, NATIVE_DENOM))]),
+            Decimal::percent(200)
+        );
+        assert_eq!(
+            multiplier(&[send(coins(1000, NATIVE_DENOM))]),
+            Decimal::percent(500)
+        );
+
+        // Funds attached to wasm messages are counted.
+        let execute: CosmosMsg = WasmMsg::Execute {
+            contract_addr: "contract".to_string(),
+            msg: to_binary(&"").unwrap(),
+            funds: coins(60, NATIVE_DENOM),
+        }
+        .into();
+        let instantiate: CosmosMsg = WasmMsg::Instantiate {
+            admin: None,
+            code_id: 1,
+            msg: to_binary(&"").unwrap(),
+            funds: coins(40, NATIVE_DENOM),
+            label: "label".to_string(),
+        }
+        .into();
+        assert_eq!(multiplier(&[execute.clone()]), Decimal::one());
+        assert_eq!(multiplier(&[execute, instantiate]), Decimal::percent(200));
+    }
+
+    #[test]
+    fn test_invalid_deposit_options() {
+        let deps = mock_dependencies();
+        let err = UncheckedDepositOptions {
+            alternatives: vec![],
+            pricing: Some(UncheckedDepositPricing::BankSendTiers {
+                denom: NATIVE_DENOM.to_string(),
+                tiers: vec![],
+            }),
+            ..Default::default()
+        }
+        .into_checked(deps.as_ref(), Addr::unchecked("dao"))
+        .unwrap_err();
+        assert_eq!(err, DepositError::NoDepositTiers);
+
+        let err = UncheckedDepositOptions {
+            alternatives: vec![],
+            pricing: Some(UncheckedDepositPricing::BankSendTiers {
+                denom: NATIVE_DENOM.to_string(),
+                tiers: vec![DepositTier {
+                    min_sent: Uint128::new(1),
+                    multiplier: Decimal::percent(50),
+                }],
+            }),
+            ..Default::default()
+        }
+        .into_checked(deps.as_ref(), Addr::unchecked("dao"))
+        .unwrap_err();
+        assert_eq!(
+            err,
+            DepositError::InvalidDepositMultiplier {
+                multiplier: Decimal::percent(50)
+            }
+        );
+
+        let err = UncheckedDepositOptions {
+            alternatives: vec![UncheckedDepositOption {
+                denom: DepositToken::Token {
+                    denom: UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+                },
+                amount: Uint128::zero(),
+            }],
+            pricing: None,
+            ..Default::default()
+        }
+        .into_checked(deps.as_ref(), Addr::unchecked("dao"))
+        .unwrap_err();
+        assert_eq!(err, DepositError::ZeroDeposit);
+    }
+
+    #[test]
+    fn test_select_native_deposit_option() {
+        let deps = mock_dependencies();
+        let deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        };
+        let options = CheckedDepositOptions {
+            alternatives: vec![CheckedDepositOption {
+                denom: CheckedDenom::Native("uother".to_string()),
+                amount: Uint128::new(30),
+            }],
+            pricing: None,
+            ..Default::default()
+        };
+        let contract = Addr::unchecked("contract");
+        let select = |funds: &[cosmwasm_std::Coin], multiplier: Decimal| {
+            options.select_deposit(
+                deps.as_ref().querier,
+                &deposit_info,
+                &mock_info("ekez", funds),
+                &contract,
+                multiplier,
+            )
+        };
+
+        assert_eq!(
+            select(&coins(10, NATIVE_DENOM), Decimal::one()).unwrap(),
+            deposit_info
+        );
+        assert_eq!(
+            select(&coins(60, "uother"), Decimal::percent(200)).unwrap(),
+            CheckedDepositInfo {
+                denom: CheckedDenom::Native("uother".to_string()),
+                amount: Uint128::new(60),
+                refund_policy: DepositRefundPolicy::OnlyPassed,
+            }
+        );
+        assert_eq!(
+            select(&coins(30, "uother"), Decimal::percent(200)).unwrap_err(),
+            DepositError::InvalidDeposit {
+                actual: Uint128::new(30),
+                expected: Uint128::new(60)
+            }
+        );
+        assert_eq!(
+            select(&coins(10, "unotekez"), Decimal::one()).unwrap_err(),
+            DepositError::UnacceptedDepositDenom {
+                denom: "unotekez".to_string()
+            }
+        );
+        assert_eq!(
+            select(&[], Decimal::one()).unwrap_err(),
+            DepositError::Payment(PaymentError::NoFunds {})
+        );
+        assert_eq!(
+            select(
+                &[coin(10, NATIVE_DENOM), coin(30, "uother")],
+                Decimal::one()
+            )
+            .unwrap_err(),
+            DepositError::Payment(PaymentError::MultipleDenoms {})
+        );
+    }
+
+    #[test]
+    fn test_get_burn_deposit_message() {
+        let mut deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Never,
+        };
+        assert_eq!(
+            deposit_info.get_burn_deposit_message(None).unwrap(),
+            vec![CosmosMsg::Bank(BankMsg::Burn {
+                amount: coins(10, NATIVE_DENOM)
+            })]
+        );
+        assert_eq!(
+            deposit_info
+                .get_burn_deposit_message(Some(&Addr::unchecked("burn")))
+                .unwrap(),
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: "burn".to_string(),
+                amount: coins(10, NATIVE_DENOM)
+            })]
+        );
+
+        deposit_info.denom = CheckedDenom::Cw20(Addr::unchecked(CW20));
+        assert_eq!(
+            deposit_info
+                .get_burn_deposit_message(Some(&Addr::unchecked("burn")))
+                .unwrap(),
+            vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: CW20.to_string(),
+                msg: to_binary(&cw20::Cw20ExecuteMsg::Burn {
+                    amount: Uint128::new(10)
+                })
+                .unwrap(),
+                funds: vec![]
+            })]
+        );
+    }
 }
@@ -11,4 +11,13 @@ pub enum VotingError {
 
     #[error("Min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
+
+    #[error("Vote weights must sum to one")]
+    InvalidVoteWeights {},
+
+    #[error("Vote type does not match the proposal's voting strategy")]
+    WrongVoteType {},
+
+    #[error("Invalid approval or score vote")]
+    InvalidMultiOptionVote {},
 }
@@ -1,5 +1,6 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
+pub mod delegation;
 pub mod deposit;
 pub mod error;
 pub mod multiple_choice;
@@ -8,4 +9,5 @@ pub mod proposal;
 pub mod reply;
 pub mod status;
 pub mod threshold;
+pub mod veto;
 pub mod voting;
@@ -1,7 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, Empty, StdError, StdResult, Uint128};
+use cosmwasm_std::{BlockInfo, CosmosMsg, Empty, StdError, StdResult, Uint128};
+use cw_utils::Expiration;
 
-use crate::threshold::{validate_quorum, PercentageThreshold, ThresholdError};
+use crate::{
+    error::VotingError,
+    threshold::{validate_quorum, PercentageThreshold, QuorumDecay, ThresholdError},
+};
 
 /// Maximum number of choices for multiple choice votes. Chosen
 /// in order to impose a bound on state / queries.
@@ -9,21 +13,76 @@ pub const MAX_NUM_CHOICES: u32 = 20;
 const NONE_OPTION_DESCRIPTION: &str = "None of the above";
 
 /// Determines how many choices may be selected.
+///
+/// With every strategy, the option with the most votes wins. If two
+/// or more options tie for the most votes the proposal does not pass.
 #[cw_serde]
 pub enum VotingStrategy {
-    SingleChoice { quorum: PercentageThreshold },
+    /// Voters select a single option, which receives their voting
+    /// power.
+    SingleChoice {
+        quorum: PercentageThreshold,
+        /// If set, the quorum decays towards a floor as the proposal
+        /// nears its expiration.
+        #[serde(default)]
+        quorum_decay: Option<QuorumDecay>,
+    },
+    /// Voters approve of any number of options. Each approved option
+    /// receives the voter's full voting power.
+    Approval {
+        quorum: PercentageThreshold,
+        #[serde(default)]
+        quorum_decay: Option<QuorumDecay>,
+    },
+    /// Voters give every option a score from zero to `max_score`.
+    /// Each option receives the voter's voting power in proportion to
+    /// its score, so an option scored `max_score` receives the
+    /// voter's full voting power.
+    Score {
+        quorum: PercentageThreshold,
+        max_score: u32,
+        #[serde(default)]
+        quorum_decay: Option<QuorumDecay>,
+    },
 }
 
 impl VotingStrategy {
     pub fn validate(&self) -> Result<(), ThresholdError> {
-        match self {
-            VotingStrategy::SingleChoice { quorum } => validate_quorum(quorum),
+        if let VotingStrategy::Score { max_score: 0, .. } = self {
+            return Err(ThresholdError::ZeroMaxScore {});
+        }
+        let quorum = self.get_quorum();
+        validate_quorum(&quorum)?;
+        match self.quorum_decay() {
+            Some(decay) => decay.validate(&quorum),
+            None => Ok(()),
         }
     }
 
+    /// The quorum before any decay.
     pub fn get_quorum(&self) -> PercentageThreshold {
         match self {
-            VotingStrategy::SingleChoice { quorum } => *quorum,
+            VotingStrategy::SingleChoice { quorum, .. }
+            | VotingStrategy::Approval { quorum, .. }
+            | VotingStrategy::Score { quorum, .. } => *quorum,
+        }
+    }
+
+    /// The quorum decay of this strategy, if any.
+    pub fn quorum_decay(&self) -> Option<&QuorumDecay> {
+        match self {
+            VotingStrategy::SingleChoice { quorum_decay, .. }
+            | VotingStrategy::Approval { quorum_decay, .. }
+            | VotingStrategy::Score { quorum_decay, .. } => quorum_decay.as_ref(),
+        }
+    }
+
+    /// The quorum at `block` for a proposal that expires at
+    /// `expiration`, accounting for any quorum decay.
+    pub fn quorum_at(&self, expiration: &Expiration, block: &BlockInfo) -> PercentageThreshold {
+        match self.quorum_decay() {
+            Some(decay) => decay.quorum_at(self.get_quorum(), expiration, block),
+            None => self.get_quorum(),
         }
     }
 }
@@ -42,6 +101,102 @@ impl std::fmt::Display for MultipleChoiceVote {
     }
 }
 
+/// A vote that may support several options. Used by proposals with
+/// the approval and score voting strategies.
+#[cw_serde]
+pub enum MultiOptionVote {
+    /// Approves of each of the options in `option_ids`.
+    Approval { option_ids: Vec<u32> },
+    /// Scores every option. `scores[i]` is the score given to the
+    /// option with index `i`.
+    Score { scores: Vec<u32> },
+}
+
+impl MultiOptionVote {
+    /// Checks that the vote is valid for a proposal with
+    /// `num_choices` options using `strategy`. Approval votes must
+    /// approve of at least one option and may not list an option more
+    /// than once. Score votes must score every option, and no score
+    /// may exceed the strategy's max score.
+    pub fn validate(&self, strategy: &VotingStrategy, num_choices: u32) -> Result<(), VotingError> {
+        match (self, strategy) {
+            (MultiOptionVote::Approval { option_ids }, VotingStrategy::Approval { .. }) => {
+                let mut seen = vec![];
+                for option_id in option_ids {
+                    if *option_id >= num_choices || seen.contains(option_id) {
+                        return Err(VotingError::InvalidMultiOptionVote {});
+                    }
+                    seen.push(*option_id);
+                }
+                if seen.is_empty() {
+                    return Err(VotingError::InvalidMultiOptionVote {});
+                }
+                Ok(())
+            }
+            (MultiOptionVote::Score { scores }, VotingStrategy::Score { max_score, .. }) => {
+                if scores.len() != num_choices as usize
+                    || scores.iter().any(|score| score > max_score)
+                {
+                    return Err(VotingError::InvalidMultiOptionVote {});
+                }
+                Ok(())
+            }
+            _ => Err(VotingError::WrongVoteType {}),
+        }
+    }
+
+    /// Splits `power` across options according to the vote, returning
+    /// the voting power given to each option by index. The vote must
+    /// have been validated for `strategy`.
+    pub fn split(&self, strategy: &VotingStrategy, power: Uint128) -> Vec<(u32, Uint128)> {
+        match (self, strategy) {
+            (MultiOptionVote::Approval { option_ids }, _) => option_ids
+                .iter()
+                .map(|option_id| (*option_id, power))
+                .collect(),
+            (MultiOptionVote::Score { scores }, VotingStrategy::Score { max_score, .. }) => scores
+                .iter()
+                .enumerate()
+                .map(|(option_id, score)| {
+                    (option_id as u32, power.multiply_ratio(*score, *max_score))
+                })
+                .collect(),
+            (MultiOptionVote::Score { .. }, _) => vec![],
+        }
+    }
+
+    /// The option most supported by the vote. Used where a single
+    /// option is expected. For approval votes this is the first
+    /// approved option, and for score votes the highest scored option
+    /// with ties broken in favor of the lowest index.
+    pub fn position(&self) -> MultipleChoiceVote {
+        let option_id = match self {
+            MultiOptionVote::Approval { option_ids } => {
+                option_ids.first().copied().unwrap_or_default()
+            }
+            MultiOptionVote::Score { scores } => scores
+                .iter()
+                .enumerate()
+                .rev()
+                .max_by_key(|(_, score)| **score)
+                .map(|(option_id, _)| option_id as u32)
+                .unwrap_or_default(),
+        };
+        MultipleChoiceVote { option_id }
+    }
+}
+
+impl std::fmt::Display for MultiOptionVote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (kind, values) = match self {
+            MultiOptionVote::Approval { option_ids } => ("approval", option_ids),
+            MultiOptionVote::Score { scores } => ("score", scores),
+        };
+        let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        write!(f, "{}:{}", kind, values.join(","))
+    }
+}
+
 // Holds the vote weights for each option
 #[cw_serde]
 pub struct MultipleChoiceVotes {
@@ -72,6 +227,38 @@ impl MultipleChoiceVotes {
         Ok(())
     }
 
+    /// Adds an approval or score vote to the tally. The vote must have
+    /// been validated for `strategy`.
+    pub fn add_multi_option_vote(
+        &mut self,
+        vote: &MultiOptionVote,
+        strategy: &VotingStrategy,
+        power: Uint128,
+    ) -> StdResult<()> {
+        for (option_id, weight) in vote.split(strategy, power) {
+            self.vote_weights[option_id as usize] = self.vote_weights[option_id as usize]
+                .checked_add(weight)
+                .map_err(StdError::overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Removes an approval or score vote from the tally. The vote must
+    /// have been previously added with the same power.
+    pub fn remove_multi_option_vote(
+        &mut self,
+        vote: &MultiOptionVote,
+        strategy: &VotingStrategy,
+        power: Uint128,
+    ) -> StdResult<()> {
+        for (option_id, weight) in vote.split(strategy, power) {
+            self.vote_weights[option_id as usize] = self.vote_weights[option_id as usize]
+                .checked_sub(weight)
+                .map_err(StdError::overflow)?;
+        }
+        Ok(())
+    }
+
     // Default tally of zero for all multiple choice options
     pub fn zero(num_choices: usize) -> Self {
         Self {
@@ -206,6 +393,77 @@ mod test {
         assert_eq!(votes, MultipleChoiceVotes::zero(2))
     }
 
+    #[test]
+    fn test_multi_option_votes() {
+        let approval = VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            quorum_decay: None,
+        };
+        let score = VotingStrategy::Score {
+            quorum: PercentageThreshold::Majority {},
+            max_score: 4,
+            quorum_decay: None,
+        };
+        let mut votes = MultipleChoiceVotes::zero(3);
+
+        let vote = MultiOptionVote::Approval {
+            option_ids: vec![0, 2],
+        };
+        vote.validate(&approval, 3).unwrap();
+        assert_eq!(vote.validate(&score, 3), Err(VotingError::WrongVoteType {}));
+        votes
+            .add_multi_option_vote(&vote, &approval, Uint128::new(10))
+            .unwrap();
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::new(10), Uint128::zero(), Uint128::new(10)]
+        );
+        votes
+            .remove_multi_option_vote(&vote, &approval, Uint128::new(10))
+            .unwrap();
+        assert_eq!(votes, MultipleChoiceVotes::zero(3));
+
+        let vote = MultiOptionVote::Score {
+            scores: vec![1, 4, 0],
+        };
+        vote.validate(&score, 3).unwrap();
+        votes
+            .add_multi_option_vote(&vote, &score, Uint128::new(10))
+            .unwrap();
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::new(2), Uint128::new(10), Uint128::zero()]
+        );
+        assert_eq!(vote.position(), MultipleChoiceVote { option_id: 1 });
+        assert_eq!(vote.to_string(), "score:1,4,0");
+
+        for invalid in [
+            MultiOptionVote::Score {
+                scores: vec![1, 5, 0],
+            },
+            MultiOptionVote::Score { scores: vec![1, 4] },
+        ] {
+            assert_eq!(
+                invalid.validate(&score, 3),
+                Err(VotingError::InvalidMultiOptionVote {})
+            );
+        }
+        for invalid in [
+            MultiOptionVote::Approval { option_ids: vec![] },
+            MultiOptionVote::Approval {
+                option_ids: vec![1, 1],
+            },
+            MultiOptionVote::Approval {
+                option_ids: vec![3],
+            },
+        ] {
+            assert_eq!(
+                invalid.validate(&approval, 3),
+                Err(VotingError::InvalidMultiOptionVote {})
+            );
+        }
+    }
+
     #[test]
     fn test_into_checked() {
         let options = vec![
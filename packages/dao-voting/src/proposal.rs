@@ -31,4 +31,10 @@ pub struct SingleChoiceProposeMsg {
     /// pre-propose module is attached, this must be Some and will
     /// set the proposer of the proposal it creates.
     pub proposer: Option<String>,
+    /// If true, each of the proposal's messages is executed on its
+    /// own when the proposal is executed, and messages that fail do
+    /// not prevent the others from executing. The result of each
+    /// message is recorded on the proposal.
+    #[serde(default)]
+    pub best_effort: bool,
 }
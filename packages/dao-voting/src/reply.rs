@@ -10,6 +10,13 @@ const FAILED_VOTE_HOOK_MASK: u64 = 0b010;
 const PRE_PROPOSE_MODULE_INSTANTIATION_ID: u64 = 0b011;
 const FAILED_PRE_PROPOSE_MODULE_HOOK_ID: u64 = 0b100;
 
+const PROPOSAL_MESSAGE_EXECUTION_MASK: u64 = 0b101;
+/// The number of bits of a proposal message execution reply ID that
+/// hold the index of the message. The remaining bits hold the
+/// proposal ID.
+const BITS_RESERVED_FOR_MESSAGE_INDEX: u8 = 16;
+const MESSAGE_INDEX_MASK: u64 = (1 << BITS_RESERVED_FOR_MESSAGE_INDEX) - 1;
+
 const BITS_RESERVED_FOR_REPLY_TYPE: u8 = 3;
 const REPLY_TYPE_MASK: u64 = (1 << BITS_RESERVED_FOR_REPLY_TYPE) - 1;
 
@@ -32,6 +39,10 @@ pub enum TaggedReplyId {
     FailedPreProposeModuleHook,
     /// Fired when a pre-propose module is successfully instantiated.
     PreProposeModuleInstantiation,
+    /// Fired when one of the messages of a proposal that is executed
+    /// message by message has been executed, successfully or
+    /// not. Holds the proposal ID and the index of the message.
+    ProposalMessageExecution(u64, u64),
 }
 
 impl TaggedReplyId {
@@ -50,6 +61,10 @@ impl TaggedReplyId {
             FAILED_VOTE_HOOK_MASK => Ok(TaggedReplyId::FailedVoteHook(id_after_shift)),
             PRE_PROPOSE_MODULE_INSTANTIATION_ID => Ok(TaggedReplyId::PreProposeModuleInstantiation),
             FAILED_PRE_PROPOSE_MODULE_HOOK_ID => Ok(TaggedReplyId::FailedPreProposeModuleHook),
+            PROPOSAL_MESSAGE_EXECUTION_MASK => Ok(TaggedReplyId::ProposalMessageExecution(
+                id_after_shift >> BITS_RESERVED_FOR_MESSAGE_INDEX,
+                id_after_shift & MESSAGE_INDEX_MASK,
+            )),
             _ => Err(error::TagError::UnknownReplyId { id }),
         }
     }
@@ -60,6 +75,15 @@ pub const fn mask_proposal_execution_proposal_id(proposal_id: u64) -> u64 {
     FAILED_PROPOSAL_EXECUTION_MASK | (proposal_id << BITS_RESERVED_FOR_REPLY_TYPE)
 }
 
+/// This function can drop bits, if you have more than
+/// `u(64-[`BITS_RESERVED_FOR_REPLY_TYPE`]-[`BITS_RESERVED_FOR_MESSAGE_INDEX`])`
+/// proposals or more than `u16` messages in a proposal.
+pub const fn mask_proposal_message_execution(proposal_id: u64, index: u64) -> u64 {
+    PROPOSAL_MESSAGE_EXECUTION_MASK
+        | (((proposal_id << BITS_RESERVED_FOR_MESSAGE_INDEX) | (index & MESSAGE_INDEX_MASK))
+            << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
 pub const fn mask_proposal_hook_index(index: u64) -> u64 {
     FAILED_PROPOSAL_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
 }
@@ -113,6 +137,21 @@ mod test {
             TaggedReplyId::new(m_vote_hook_idx).unwrap(),
             TaggedReplyId::FailedVoteHook(vote_hook_idx)
         );
+        // max u45
+        let message_proposal_id: u64 = 2_u64.pow(45) - 1;
+        let message_idx = 2_u64.pow(16) - 1;
+        assert_eq!(
+            TaggedReplyId::new(mask_proposal_message_execution(
+                message_proposal_id,
+                message_idx
+            ))
+            .unwrap(),
+            TaggedReplyId::ProposalMessageExecution(message_proposal_id, message_idx)
+        );
+        assert_eq!(
+            TaggedReplyId::new(mask_proposal_message_execution(1, 0)).unwrap(),
+            TaggedReplyId::ProposalMessageExecution(1, 0)
+        );
         assert_eq!(
             TaggedReplyId::new(0b110).unwrap_err(),
             error::TagError::UnknownReplyId { id: 0b110 }
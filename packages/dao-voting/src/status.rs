@@ -1,4 +1,5 @@
 use cosmwasm_schema::cw_serde;
+use cw_utils::Expiration;
 
 #[cw_serde]
 #[derive(Copy)]
@@ -16,6 +17,12 @@ pub enum Status {
     Closed,
     /// The proposal's execution failed.
     ExecutionFailed,
+    /// The proposal has passed and may be vetoed by the proposal
+    /// module's vetoer until `expiration`. After that time the
+    /// proposal is considered passed.
+    VetoTimelock { expiration: Expiration },
+    /// The proposal was vetoed during its veto timelock.
+    Vetoed,
 }
 
 impl std::fmt::Display for Status {
@@ -27,6 +34,8 @@ impl std::fmt::Display for Status {
             Status::Executed => write!(f, "executed"),
             Status::Closed => write!(f, "closed"),
             Status::ExecutionFailed => write!(f, "execution_failed"),
+            Status::VetoTimelock { .. } => write!(f, "veto_timelock"),
+            Status::Vetoed => write!(f, "vetoed"),
         }
     }
 }
@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Decimal, Uint128};
+use cosmwasm_std::{BlockInfo, Decimal, Uint128};
+use cw_utils::{Duration, Expiration};
 
 use thiserror::Error;
 
@@ -10,6 +11,18 @@ pub enum ThresholdError {
 
     #[error("Not possible to reach required (passing) threshold")]
     UnreachableThreshold {},
+
+    #[error("Max score cannot be zero")]
+    ZeroMaxScore {},
+
+    #[error("Quorum decay floor must not be greater than the quorum")]
+    InvalidQuorumDecayFloor {},
+
+    #[error("Quorum decay must have a non-zero period and number of steps")]
+    ZeroQuorumDecay {},
+
+    #[error("Quorum decay period and max voting period must have the same units (height or time)")]
+    QuorumDecayUnitsConflict {},
 }
 
 /// A percentage of voting power that must vote yes for a proposal to
@@ -61,11 +74,119 @@ pub enum Threshold {
     ThresholdQuorum {
         threshold: PercentageThreshold,
         quorum: PercentageThreshold,
+        /// If set, the quorum decays towards a floor as the proposal
+        /// nears its expiration.
+        #[serde(default)]
+        quorum_decay: Option<QuorumDecay>,
     },
 
     /// An absolute number of votes needed for something to cross the
     /// threshold. Useful for multisig style voting.
     AbsoluteCount { threshold: Uint128 },
+
+    /// Declares a percentage of the total weight that must cast No
+    /// votes in order for a proposal to be rejected. If No votes do
+    /// not reach `objection` before voting ends the proposal passes,
+    /// regardless of how many Yes votes were cast. Useful for routine
+    /// operations that should proceed unless members object.
+    Optimistic { objection: PercentageThreshold },
+}
+
+/// Decays a quorum towards a floor as a proposal nears its
+/// expiration, so that low turnout proposals may still be decided.
+///
+/// The quorum is unchanged until `period` before the proposal's
+/// expiration, after which it decays linearly (or in `steps` equal
+/// steps) to `floor` at expiration. Set `period` to the max voting
+/// period to decay over the entire voting period.
+///
+/// As the quorum only ever decreases, a proposal that has met quorum
+/// continues to meet it.
+#[cw_serde]
+#[derive(Copy)]
+pub struct QuorumDecay {
+    /// The quorum once the proposal has expired.
+    pub floor: Decimal,
+    /// How long before the proposal's expiration the quorum begins
+    /// decaying.
+    pub period: Duration,
+    /// If set, the quorum decays in this many equal steps instead of
+    /// linearly.
+    pub steps: Option<u64>,
+}
+
+impl QuorumDecay {
+    /// Validates that the decay does not increase `quorum` and that
+    /// its period and steps are non-zero.
+    pub fn validate(&self, quorum: &PercentageThreshold) -> Result<(), ThresholdError> {
+        let period = match self.period {
+            Duration::Height(period) | Duration::Time(period) => period,
+        };
+        if period == 0 || self.steps == Some(0) {
+            return Err(ThresholdError::ZeroQuorumDecay {});
+        }
+        if self.floor > quorum_percentage(quorum) {
+            return Err(ThresholdError::InvalidQuorumDecayFloor {});
+        }
+        Ok(())
+    }
+
+    /// Validates that the decay's period is measured in the same
+    /// units as proposals' expirations.
+    pub fn validate_units(&self, max_voting_period: &Duration) -> Result<(), ThresholdError> {
+        match (self.period, max_voting_period) {
+            (Duration::Height(_), Duration::Height(_)) | (Duration::Time(_), Duration::Time(_)) => {
+                Ok(())
+            }
+            _ => Err(ThresholdError::QuorumDecayUnitsConflict {}),
+        }
+    }
+
+    /// The quorum at `block` for a proposal that expires at
+    /// `expiration` with a starting quorum of `quorum`.
+    pub fn quorum_at(
+        &self,
+        quorum: PercentageThreshold,
+        expiration: &Expiration,
+        block: &BlockInfo,
+    ) -> PercentageThreshold {
+        let (remaining, period) = match (expiration, self.period) {
+            (Expiration::AtHeight(height), Duration::Height(period)) => {
+                (height.saturating_sub(block.height), period)
+            }
+            (Expiration::AtTime(time), Duration::Time(period)) => {
+                (time.seconds().saturating_sub(block.time.seconds()), period)
+            }
+            // Validation prevents periods in different units than
+            // expirations, and proposals that never expire never
+            // decay.
+            _ => return quorum,
+        };
+        if period == 0 || remaining >= period {
+            return quorum;
+        }
+
+        let elapsed = period - remaining;
+        let decayed = match self.steps {
+            Some(steps) if steps > 0 => {
+                let step = (elapsed as u128 * steps as u128) / period as u128;
+                Decimal::from_ratio(step, steps)
+            }
+            _ => Decimal::from_ratio(elapsed, period),
+        };
+        let start = quorum_percentage(&quorum);
+        let decay = start.checked_sub(self.floor).unwrap_or_default() * decayed;
+        PercentageThreshold::Percent(start - decay)
+    }
+}
+
+/// The percentage of voting power needed to meet a quorum. A majority
+/// quorum is treated as 50% once it starts decaying.
+fn quorum_percentage(quorum: &PercentageThreshold) -> Decimal {
+    match quorum {
+        PercentageThreshold::Majority {} => Decimal::percent(50),
+        PercentageThreshold::Percent(percent) => *percent,
+    }
 }
 
 /// Asserts that the 0.0 < percent <= 1.0
@@ -103,14 +224,23 @@ impl Threshold {
     /// - Quorums must never be over 100%.
     /// - Passing thresholds must never be over 100%, nor be 0%.
     /// - Absolute count thresholds must be non-zero.
+    /// - Objection thresholds must never be over 100%, nor be 0%.
     pub fn validate(&self) -> Result<(), ThresholdError> {
         match self {
             Threshold::AbsolutePercentage {
                 percentage: percentage_needed,
             } => validate_percentage(percentage_needed),
-            Threshold::ThresholdQuorum { threshold, quorum } => {
+            Threshold::ThresholdQuorum {
+                threshold,
+                quorum,
+                quorum_decay,
+            } => {
                 validate_percentage(threshold)?;
-                validate_quorum(quorum)
+                validate_quorum(quorum)?;
+                match quorum_decay {
+                    Some(decay) => decay.validate(quorum),
+                    None => Ok(()),
+                }
             }
             Threshold::AbsoluteCount { threshold } => {
                 if threshold.is_zero() {
@@ -119,6 +249,36 @@ impl Threshold {
                     Ok(())
                 }
             }
+            Threshold::Optimistic { objection } => validate_percentage(objection),
+        }
+    }
+
+    /// The quorum of this threshold at `block` for a proposal that
+    /// expires at `expiration`, accounting for any quorum decay.
+    /// `None` if this threshold has no quorum.
+    pub fn quorum_at(
+        &self,
+        expiration: &Expiration,
+        block: &BlockInfo,
+    ) -> Option<PercentageThreshold> {
+        match self {
+            Threshold::ThresholdQuorum {
+                quorum,
+                quorum_decay,
+                ..
+            } => Some(match quorum_decay {
+                Some(decay) => decay.quorum_at(*quorum, expiration, block),
+                None => *quorum,
+            }),
+            _ => None,
+        }
+    }
+
+    /// The quorum decay of this threshold, if any.
+    pub fn quorum_decay(&self) -> Option<&QuorumDecay> {
+        match self {
+            Threshold::ThresholdQuorum { quorum_decay, .. } => quorum_decay.as_ref(),
+            _ => None,
         }
     }
 }
@@ -159,6 +319,7 @@ mod tests {
         let t = Threshold::ThresholdQuorum {
             threshold: p!(101),
             quorum: p!(0),
+            quorum_decay: None,
         };
         assert_eq!(
             t.validate().unwrap_err(),
@@ -168,16 +329,140 @@ mod tests {
         let t = Threshold::ThresholdQuorum {
             threshold: p!(100),
             quorum: p!(0),
+            quorum_decay: None,
         };
         t.validate().unwrap();
 
         let t = Threshold::ThresholdQuorum {
             threshold: p!(100),
             quorum: p!(101),
+            quorum_decay: None,
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::UnreachableThreshold {}
+        );
+
+        let t = Threshold::ThresholdQuorum {
+            threshold: p!(50),
+            quorum: p!(20),
+            quorum_decay: Some(QuorumDecay {
+                floor: Decimal::percent(21),
+                period: Duration::Height(10),
+                steps: None,
+            }),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::InvalidQuorumDecayFloor {}
+        );
+
+        let t = Threshold::ThresholdQuorum {
+            threshold: p!(50),
+            quorum: p!(20),
+            quorum_decay: Some(QuorumDecay {
+                floor: Decimal::percent(10),
+                period: Duration::Height(10),
+                steps: Some(0),
+            }),
         };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::ZeroQuorumDecay {}
+        );
+
+        let t = Threshold::ThresholdQuorum {
+            threshold: p!(50),
+            quorum: PercentageThreshold::Majority {},
+            quorum_decay: Some(QuorumDecay {
+                floor: Decimal::percent(10),
+                period: Duration::Time(10),
+                steps: Some(2),
+            }),
+        };
+        t.validate().unwrap();
+
+        let t = Threshold::Optimistic { objection: p!(0) };
+        assert_eq!(t.validate().unwrap_err(), ThresholdError::ZeroThreshold {});
+
+        let t = Threshold::Optimistic { objection: p!(101) };
         assert_eq!(
             t.validate().unwrap_err(),
             ThresholdError::UnreachableThreshold {}
         );
+
+        let t = Threshold::Optimistic { objection: p!(33) };
+        t.validate().unwrap();
+    }
+
+    #[test]
+    fn test_quorum_decay() {
+        let mut block = cosmwasm_std::testing::mock_env().block;
+        let expiration = Expiration::AtHeight(block.height + 100);
+        let decay = QuorumDecay {
+            floor: Decimal::percent(10),
+            period: Duration::Height(50),
+            steps: None,
+        };
+
+        // No decay until `period` before expiration.
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(30));
+        block.height += 50;
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(30));
+
+        // Linear decay to the floor at expiration.
+        block.height += 25;
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(20));
+        block.height += 25;
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(10));
+        block.height += 25;
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(10));
+
+        // Stepped decay.
+        let decay = QuorumDecay {
+            steps: Some(2),
+            ..decay
+        };
+        block.height -= 51;
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(30));
+        block.height += 1;
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(20));
+        block.height += 24;
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(20));
+        block.height += 1;
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(10));
+
+        // Periods in different units than the expiration do not
+        // decay.
+        let decay = QuorumDecay {
+            period: Duration::Time(50),
+            ..decay
+        };
+        assert_eq!(decay.quorum_at(p!(30), &expiration, &block), p!(30));
+        assert_eq!(
+            decay.validate_units(&Duration::Height(100)).unwrap_err(),
+            ThresholdError::QuorumDecayUnitsConflict {}
+        );
+        decay.validate_units(&Duration::Time(100)).unwrap();
+
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Majority {},
+            quorum_decay: Some(QuorumDecay {
+                floor: Decimal::percent(10),
+                period: Duration::Height(100),
+                steps: None,
+            }),
+        };
+        let block = cosmwasm_std::testing::mock_env().block;
+        assert_eq!(
+            threshold.quorum_at(&expiration, &block),
+            Some(PercentageThreshold::Majority {})
+        );
+        let block = BlockInfo {
+            height: block.height + 50,
+            ..block
+        };
+        assert_eq!(threshold.quorum_at(&expiration, &block), Some(p!(30)));
     }
 }
@@ -0,0 +1,67 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, StdError};
+use cw_utils::Duration;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum VetoError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(
+        "veto timelock duration must have the same units as max_voting_period (height or time)"
+    )]
+    TimelockDurationUnitsConflict {},
+
+    #[error("proposal is not in its veto timelock and may not be vetoed")]
+    NotVetoable {},
+
+    #[error("only the vetoer may veto proposals")]
+    NotVetoer {},
+}
+
+/// Configuration for a vetoer that may veto passed proposals before
+/// they are executed.
+///
+/// When a veto config is set, passed proposals enter a
+/// `VetoTimelock` status which lasts until `timelock_duration` after
+/// the proposal's voting period ends. During the timelock the vetoer
+/// may veto the proposal, and the proposal may not be executed unless
+/// `early_execute` is enabled and the vetoer executes it.
+#[cw_serde]
+pub struct VetoConfig {
+    /// The amount of time after a proposal's voting period ends
+    /// during which it may be vetoed. Must have the same units as
+    /// the proposal module's `max_voting_period`.
+    pub timelock_duration: Duration,
+    /// The address that may veto proposals. For example, a cw4 group
+    /// or a SubDAO acting as a council.
+    pub vetoer: String,
+    /// If true, the vetoer may execute passed proposals before the
+    /// timelock has expired.
+    pub early_execute: bool,
+}
+
+impl VetoConfig {
+    /// Validates the vetoer address and that the timelock duration
+    /// may be added to proposal expirations created with
+    /// `max_voting_period`.
+    pub fn validate(&self, deps: &Deps, max_voting_period: &Duration) -> Result<(), VetoError> {
+        deps.api.addr_validate(&self.vetoer)?;
+        match (self.timelock_duration, max_voting_period) {
+            (Duration::Height(_), Duration::Height(_)) | (Duration::Time(_), Duration::Time(_)) => {
+                Ok(())
+            }
+            _ => Err(VetoError::TimelockDurationUnitsConflict {}),
+        }
+    }
+
+    /// Errors unless `sender` is the vetoer.
+    pub fn check_is_vetoer(&self, sender: &Addr) -> Result<(), VetoError> {
+        if *sender == self.vetoer {
+            Ok(())
+        } else {
+            Err(VetoError::NotVetoer {})
+        }
+    }
+}
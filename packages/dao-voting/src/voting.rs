@@ -3,7 +3,7 @@ use cosmwasm_std::{Addr, Decimal, Deps, StdResult, Uint128, Uint256};
 use cw_utils::Duration;
 use dao_interface::voting;
 
-use crate::threshold::PercentageThreshold;
+use crate::{error::VotingError, threshold::PercentageThreshold};
 
 // We multiply by this when calculating needed_votes in order to round
 // up properly.
@@ -29,6 +29,16 @@ pub enum Vote {
     Abstain,
 }
 
+/// A vote that splits the voter's power across positions. Each weight
+/// is the fraction of the voter's power behind that position, and the
+/// weights must sum to one.
+#[cw_serde]
+pub struct WeightedVote {
+    pub yes: Decimal,
+    pub no: Decimal,
+    pub abstain: Decimal,
+}
+
 pub enum VoteCmp {
     Greater,
     Geq,
@@ -167,6 +177,24 @@ impl Votes {
         }
     }
 
+    /// Adds a weighted vote to the votes.
+    pub fn add_weighted_vote(&mut self, vote: &WeightedVote, power: Uint128) {
+        let split = vote.split(power);
+        self.yes += split.yes;
+        self.no += split.no;
+        self.abstain += split.abstain;
+    }
+
+    /// Removes a weighted vote from the votes. The vote being removed
+    /// must have been previously added with the same power or this
+    /// method will cause an overflow.
+    pub fn remove_weighted_vote(&mut self, vote: &WeightedVote, power: Uint128) {
+        let split = vote.split(power);
+        self.yes -= split.yes;
+        self.no -= split.no;
+        self.abstain -= split.abstain;
+    }
+
     /// Computes the total number of votes cast.
     ///
     /// NOTE: The total number of votes avaliable from a voting module
@@ -189,6 +217,57 @@ impl std::fmt::Display for Vote {
     }
 }
 
+impl WeightedVote {
+    /// Checks that the vote's weights sum to one.
+    pub fn validate(&self) -> Result<(), VotingError> {
+        let total = self
+            .yes
+            .checked_add(self.no)
+            .and_then(|total| total.checked_add(self.abstain))
+            .map_err(|_| VotingError::InvalidVoteWeights {})?;
+        if total != Decimal::one() {
+            return Err(VotingError::InvalidVoteWeights {});
+        }
+        Ok(())
+    }
+
+    /// Splits `power` across positions according to the vote's
+    /// weights. Rounding remainders are counted as abstentions so
+    /// that the split always sums to `power`.
+    pub fn split(&self, power: Uint128) -> Votes {
+        let yes = power * self.yes;
+        let no = power * self.no;
+        Votes {
+            yes,
+            no,
+            abstain: power - yes - no,
+        }
+    }
+
+    /// The position with the largest weight. Used where a single
+    /// position is expected. Ties are broken in favor of abstain, and
+    /// then no.
+    pub fn position(&self) -> Vote {
+        if self.yes > self.no && self.yes > self.abstain {
+            Vote::Yes
+        } else if self.no > self.abstain {
+            Vote::No
+        } else {
+            Vote::Abstain
+        }
+    }
+}
+
+impl std::fmt::Display for WeightedVote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "yes:{},no:{},abstain:{}",
+            self.yes, self.no, self.abstain
+        )
+    }
+}
+
 /// A height of None will query for the current block height.
 pub fn get_voting_power(
     deps: Deps,
@@ -255,6 +334,35 @@ mod test {
         assert_eq!(votes.abstain, Uint128::new(40));
     }
 
+    #[test]
+    fn count_weighted_votes() {
+        let vote = WeightedVote {
+            yes: Decimal::percent(50),
+            no: Decimal::percent(30),
+            abstain: Decimal::percent(20),
+        };
+        vote.validate().unwrap();
+        assert_eq!(vote.position(), Vote::Yes);
+
+        let mut votes = Votes::with_yes(Uint128::new(5));
+        votes.add_weighted_vote(&vote, Uint128::new(11));
+        // 5.5 yes and 3.3 no round down, the remainder abstains.
+        assert_eq!(votes.yes, Uint128::new(10));
+        assert_eq!(votes.no, Uint128::new(3));
+        assert_eq!(votes.abstain, Uint128::new(3));
+        assert_eq!(votes.total(), Uint128::new(16));
+
+        votes.remove_weighted_vote(&vote, Uint128::new(11));
+        assert_eq!(votes, Votes::with_yes(Uint128::new(5)));
+
+        let invalid = WeightedVote {
+            yes: Decimal::percent(50),
+            no: Decimal::percent(60),
+            abstain: Decimal::zero(),
+        };
+        assert_eq!(invalid.validate(), Err(VotingError::InvalidVoteWeights {}));
+    }
+
     #[test]
     fn vote_comparisons() {
         assert!(!compare_vote_count(
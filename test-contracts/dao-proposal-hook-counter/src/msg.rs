@@ -22,6 +22,8 @@ pub enum QueryMsg {
     ProposalCounter {},
     #[returns(u64)]
     StatusChangedCounter {},
+    #[returns(u64)]
+    AmendedCounter {},
 }
 
 #[cw_serde]
@@ -153,6 +153,7 @@ fn test_counters() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         close_proposal_on_execution_failure: true,
+        veto: None,
     };
 
     let governance_addr =
@@ -257,6 +258,7 @@ fn test_counters() {
             description: "This is a simple text proposal".to_string(),
             msgs: vec![],
             proposer: None,
+            best_effort: false,
         }),
         &[],
     )
@@ -283,6 +285,34 @@ fn test_counters() {
         .unwrap();
     assert_eq!(resp.count, 0);
 
+    // Amend the proposal before any votes are cast.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Amend {
+            proposal_id: 1,
+            title: "A simple text proposal".to_string(),
+            description: "This is a simple, amended, text proposal".to_string(),
+            msgs: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Query amended counter, expect 1
+    let resp: CountResponse = app
+        .wrap()
+        .query_wasm_smart(counters.clone(), &QueryMsg::AmendedCounter {})
+        .unwrap();
+    assert_eq!(resp.count, 1);
+
+    // Query status changed counter, expect 0
+    let resp: CountResponse = app
+        .wrap()
+        .query_wasm_smart(counters.clone(), &QueryMsg::StatusChangedCounter {})
+        .unwrap();
+    assert_eq!(resp.count, 0);
+
     // Vote
     app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
@@ -357,6 +387,7 @@ fn test_counters() {
             description: "This is a simple text proposal 2nd".to_string(),
             msgs: vec![],
             proposer: None,
+            best_effort: false,
         }),
         &[],
     )